@@ -1,4 +1,5 @@
 #![allow(
+    async_fn_in_trait,
     dead_code,
     unused_mut,
     unused_variables,
@@ -45,8 +46,35 @@ impl ::ntex_grpc::MethodDef for GreeterSayHelloMethod {
         ::ntex_grpc::ByteString::from_static("/helloworld.Greeter/SayHello");
     type Input = HelloRequest;
     type Output = HelloReply;
+    type Request = HelloRequest;
 }
 
+/// `Greeter` service server
+///
+/// Use `into_service()` for registering implementation in the grpc router.
+pub trait GreeterServer {
+    /// Sends a greeting
+    async fn say_hello(
+        &self,
+        req: ::ntex_grpc::server::Request<HelloRequest>,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::Response<HelloReply>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Convert implementation to grpc service
+    fn into_service(self) -> GreeterServerAdapter<Self>
+    where
+        Self: Sized,
+    {
+        GreeterServerAdapter(self)
+    }
+}
+
+/// Grpc service for `GreeterServer` implementation
+#[derive(Debug, Clone)]
+pub struct GreeterServerAdapter<T>(pub T);
+
 mod _priv_impl_helloworld {
     use super::*;
 
@@ -71,21 +99,30 @@ mod _priv_impl_helloworld {
         fn read(
             src: &mut ::ntex_grpc::Bytes,
         ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
-            const STRUCT_NAME: &str = "HelloRequest";
             let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "HelloRequest";
             while !src.is_empty() {
                 let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
                 match tag {
-                    1 => ::ntex_grpc::NativeType::deserialize(&mut msg.name, tag, wire_type, src)
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.name, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "name"))?,
                     2 => {
-                        ::ntex_grpc::NativeType::deserialize(&mut msg.msg_id, tag, wire_type, src)
+                        ::ntex_grpc::NativeType::deserialize(&mut self.msg_id, tag, wire_type, src)
                             .map_err(|err| err.push(STRUCT_NAME, "msg_id"))?
                     }
                     _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -127,19 +164,31 @@ mod _priv_impl_helloworld {
         fn read(
             src: &mut ::ntex_grpc::Bytes,
         ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
-            const STRUCT_NAME: &str = "HelloReply";
             let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "HelloReply";
             while !src.is_empty() {
                 let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
                 match tag {
-                    1 => {
-                        ::ntex_grpc::NativeType::deserialize(&mut msg.message, tag, wire_type, src)
-                            .map_err(|err| err.push(STRUCT_NAME, "message"))?
-                    }
+                    1 => ::ntex_grpc::NativeType::deserialize(
+                        &mut self.message,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "message"))?,
                     _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -220,4 +269,62 @@ mod _priv_impl_helloworld {
             ::ntex_grpc::client::Request::new(&self.0, req)
         }
     }
+
+    impl<T: GreeterServer> ::ntex_grpc::server::NamedService for GreeterServerAdapter<T> {
+        const NAME: &'static str = <Greeter as ::ntex_grpc::ServiceDef>::NAME;
+    }
+
+    impl<T: GreeterServer> ::ntex_grpc::Service<::ntex_grpc::server::ServerRequest>
+        for GreeterServerAdapter<T>
+    {
+        type Response = ::ntex_grpc::server::ServerResponse;
+        type Error = ::ntex_grpc::server::ServerError;
+
+        async fn call(
+            &self,
+            mut req: ::ntex_grpc::server::ServerRequest,
+            _: ::ntex_grpc::ServiceCtx<'_, Self>,
+        ) -> ::std::result::Result<Self::Response, Self::Error> {
+            use ::ntex_grpc::{MethodDef, ServiceDef};
+
+            match Greeter::method_by_name(&req.name) {
+                Some(GreeterMethods::SayHello(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        GreeterServer::say_hello(&self.0, req).await,
+                        &method,
+                    )
+                }
+                None => Err(::ntex_grpc::server::ServerError::new(
+                    ::ntex_grpc::GrpcStatus::Unimplemented,
+                    ::ntex_grpc::HeaderValue::from_str(&format!(
+                        "Service method is not found: {}",
+                        req.name
+                    ))
+                    .unwrap_or(::ntex_grpc::HeaderValue::from_static(
+                        "Service method is not found",
+                    )),
+                    None,
+                )),
+            }
+        }
+    }
+
+    impl<T: GreeterServer + Clone + 'static>
+        ::ntex_grpc::ServiceFactory<::ntex_grpc::server::ServerRequest, ::ntex_grpc::SharedCfg>
+        for GreeterServerAdapter<T>
+    {
+        type Response = ::ntex_grpc::server::ServerResponse;
+        type Error = ::ntex_grpc::server::ServerError;
+        type InitError = ();
+        type Service = GreeterServerAdapter<T>;
+
+        async fn create(
+            &self,
+            _: ::ntex_grpc::SharedCfg,
+        ) -> ::std::result::Result<Self::Service, Self::InitError> {
+            Ok(self.clone())
+        }
+    }
 }
@@ -1,4 +1,5 @@
 #![allow(
+    async_fn_in_trait,
     dead_code,
     unused_mut,
     unused_variables,
@@ -80,8 +81,12 @@ impl DocumentType {
 pub struct Greeter;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 pub enum GreeterMethods {
     SayHello(GreeterSayHelloMethod),
+    SayHelloStream(GreeterSayHelloStreamMethod),
+    SayHellos(GreeterSayHellosMethod),
+    SayHelloChat(GreeterSayHelloChatMethod),
 }
 
 /// The greeting service definition.
@@ -97,8 +102,98 @@ impl ::ntex_grpc::MethodDef for GreeterSayHelloMethod {
         ::ntex_grpc::ByteString::from_static("/helloworld.Greeter/SayHello");
     type Input = HelloRequest;
     type Output = HelloReply;
+    type Request = HelloRequest;
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GreeterSayHelloStreamMethod;
+
+impl ::ntex_grpc::MethodDef for GreeterSayHelloStreamMethod {
+    const NAME: &'static str = "SayHelloStream";
+    const PATH: ::ntex_grpc::ByteString =
+        ::ntex_grpc::ByteString::from_static("/helloworld.Greeter/SayHelloStream");
+    type Input = HelloRequest;
+    type Output = HelloReply;
+    type Request = HelloRequest;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GreeterSayHellosMethod;
+
+impl ::ntex_grpc::MethodDef for GreeterSayHellosMethod {
+    const NAME: &'static str = "SayHellos";
+    const PATH: ::ntex_grpc::ByteString =
+        ::ntex_grpc::ByteString::from_static("/helloworld.Greeter/SayHellos");
+    type Input = HelloRequest;
+    type Output = HelloReply;
+    type Request = ::ntex_grpc::server::Streaming<HelloRequest>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GreeterSayHelloChatMethod;
+
+impl ::ntex_grpc::MethodDef for GreeterSayHelloChatMethod {
+    const NAME: &'static str = "SayHelloChat";
+    const PATH: ::ntex_grpc::ByteString =
+        ::ntex_grpc::ByteString::from_static("/helloworld.Greeter/SayHelloChat");
+    type Input = HelloRequest;
+    type Output = HelloReply;
+    type Request = ::ntex_grpc::server::Streaming<HelloRequest>;
+}
+
+/// `Greeter` service server
+///
+/// Use `into_service()` for registering implementation in the grpc router.
+pub trait GreeterServer {
+    /// Sends a greeting
+    async fn say_hello(
+        &self,
+        req: ::ntex_grpc::server::Request<HelloRequest>,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::Response<HelloReply>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Sends a stream of greetings
+    async fn say_hello_stream(
+        &self,
+        req: ::ntex_grpc::server::Request<HelloRequest>,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::ResponseStream<HelloReply>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Receives a stream of greetings
+    async fn say_hellos(
+        &self,
+        req: ::ntex_grpc::server::Request<::ntex_grpc::server::Streaming<HelloRequest>>,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::Response<HelloReply>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Exchanges greetings
+    async fn say_hello_chat(
+        &self,
+        req: ::ntex_grpc::server::Request<::ntex_grpc::server::Streaming<HelloRequest>>,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::ResponseStream<HelloReply>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Convert implementation to grpc service
+    fn into_service(self) -> GreeterServerAdapter<Self>
+    where
+        Self: Sized,
+    {
+        GreeterServerAdapter(self)
+    }
+}
+
+/// Grpc service for `GreeterServer` implementation
+#[derive(Debug, Clone)]
+pub struct GreeterServerAdapter<T>(pub T);
+
 mod _priv_impl_helloworld {
     use super::*;
 
@@ -129,21 +224,34 @@ mod _priv_impl_helloworld {
         fn read(
             src: &mut ::ntex_grpc::Bytes,
         ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
-            const STRUCT_NAME: &str = "HelloRequest";
             let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "HelloRequest";
             while !src.is_empty() {
                 let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
                 match tag {
-                    1 => ::ntex_grpc::NativeType::deserialize(&mut msg.name, tag, wire_type, src)
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.name, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "name"))?,
-                    2 => ::ntex_grpc::NativeType::deserialize(&mut msg.data1, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data1"))?,
-                    3 => ::ntex_grpc::NativeType::deserialize(&mut msg.data2, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data2"))?,
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data1, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data1"))?
+                    }
+                    3 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data2, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data2"))?
+                    }
                     _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -232,33 +340,57 @@ mod _priv_impl_helloworld {
         fn read(
             src: &mut ::ntex_grpc::Bytes,
         ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
-            const STRUCT_NAME: &str = "HelloReply";
             let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "HelloReply";
             while !src.is_empty() {
                 let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
                 match tag {
-                    1 => ::ntex_grpc::NativeType::deserialize(&mut msg.data5, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data5"))?,
-                    2 => ::ntex_grpc::NativeType::deserialize(&mut msg.data6, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data6"))?,
-                    3 => {
-                        ::ntex_grpc::NativeType::deserialize(&mut msg.message, tag, wire_type, src)
-                            .map_err(|err| err.push(STRUCT_NAME, "message"))?
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data5, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data5"))?
+                    }
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data6, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data6"))?
                     }
-                    4 => ::ntex_grpc::NativeType::deserialize(&mut msg.tag, tag, wire_type, src)
+                    3 => ::ntex_grpc::NativeType::deserialize(
+                        &mut self.message,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "message"))?,
+                    4 => ::ntex_grpc::NativeType::deserialize(&mut self.tag, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "tag"))?,
-                    5 => ::ntex_grpc::NativeType::deserialize(&mut msg.data1, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data1"))?,
-                    6 => ::ntex_grpc::NativeType::deserialize(&mut msg.data2, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data2"))?,
-                    7 => ::ntex_grpc::NativeType::deserialize(&mut msg.data3, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data3"))?,
-                    8 => ::ntex_grpc::NativeType::deserialize(&mut msg.data4, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, "data4"))?,
+                    5 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data1, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data1"))?
+                    }
+                    6 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data2, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data2"))?
+                    }
+                    7 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data3, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data3"))?
+                    }
+                    8 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.data4, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "data4"))?
+                    }
                     _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -357,6 +489,18 @@ mod _priv_impl_helloworld {
         }
     }
 
+    impl ::ntex_grpc::types::Enumeration for DocumentType {
+        #[inline]
+        fn from_i32(value: i32) -> ::std::option::Option<Self> {
+            DocumentType::from_i32(value)
+        }
+
+        #[inline]
+        fn to_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
     impl ::ntex_grpc::ServiceDef for Greeter {
         const NAME: &'static str = "helloworld.Greeter";
         type Methods = GreeterMethods;
@@ -368,6 +512,15 @@ mod _priv_impl_helloworld {
                 GreeterSayHelloMethod::NAME => {
                     Some(GreeterMethods::SayHello(GreeterSayHelloMethod))
                 }
+                GreeterSayHelloStreamMethod::NAME => {
+                    Some(GreeterMethods::SayHelloStream(GreeterSayHelloStreamMethod))
+                }
+                GreeterSayHellosMethod::NAME => {
+                    Some(GreeterMethods::SayHellos(GreeterSayHellosMethod))
+                }
+                GreeterSayHelloChatMethod::NAME => {
+                    Some(GreeterMethods::SayHelloChat(GreeterSayHelloChatMethod))
+                }
                 _ => None,
             }
         }
@@ -416,4 +569,127 @@ mod _priv_impl_helloworld {
             ::ntex_grpc::client::Request::new(&self.0, req)
         }
     }
+
+    impl<T: ::ntex_grpc::client::StreamingTransport<GreeterSayHelloStreamMethod>> GreeterClient<T> {
+        /// Sends a stream of greetings
+        pub fn say_hello_stream<'a>(
+            &'a self,
+            req: &'a super::HelloRequest,
+        ) -> ::ntex_grpc::client::StreamingRequest<'a, T, GreeterSayHelloStreamMethod> {
+            ::ntex_grpc::client::StreamingRequest::new(&self.0, req)
+        }
+    }
+
+    impl<T: ::ntex_grpc::client::StreamingTransport<GreeterSayHellosMethod>> GreeterClient<T> {
+        /// Receives a stream of greetings
+        pub fn say_hellos<'a>(
+            &'a self,
+        ) -> ::ntex_grpc::client::StreamingRequest<
+            'a,
+            T,
+            GreeterSayHellosMethod,
+            ::ntex_grpc::client::ClientStreaming<GreeterSayHellosMethod>,
+        > {
+            ::ntex_grpc::client::StreamingRequest::client(&self.0)
+        }
+    }
+
+    impl<T: ::ntex_grpc::client::StreamingTransport<GreeterSayHelloChatMethod>> GreeterClient<T> {
+        /// Exchanges greetings
+        pub fn say_hello_chat<'a>(
+            &'a self,
+        ) -> ::ntex_grpc::client::StreamingRequest<
+            'a,
+            T,
+            GreeterSayHelloChatMethod,
+            (
+                ::ntex_grpc::client::RequestSink<GreeterSayHelloChatMethod>,
+                ::ntex_grpc::client::Streaming<GreeterSayHelloChatMethod>,
+            ),
+        > {
+            ::ntex_grpc::client::StreamingRequest::bidi(&self.0)
+        }
+    }
+
+    impl<T: GreeterServer> ::ntex_grpc::server::NamedService for GreeterServerAdapter<T> {
+        const NAME: &'static str = <Greeter as ::ntex_grpc::ServiceDef>::NAME;
+    }
+
+    impl<T: GreeterServer> ::ntex_grpc::Service<::ntex_grpc::server::ServerRequest>
+        for GreeterServerAdapter<T>
+    {
+        type Response = ::ntex_grpc::server::ServerResponse;
+        type Error = ::ntex_grpc::server::ServerError;
+
+        async fn call(
+            &self,
+            mut req: ::ntex_grpc::server::ServerRequest,
+            _: ::ntex_grpc::ServiceCtx<'_, Self>,
+        ) -> ::std::result::Result<Self::Response, Self::Error> {
+            use ::ntex_grpc::{MethodDef, ServiceDef};
+
+            match Greeter::method_by_name(&req.name) {
+                Some(GreeterMethods::SayHello(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        GreeterServer::say_hello(&self.0, req).await,
+                        &method,
+                    )
+                }
+                Some(GreeterMethods::SayHelloStream(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        GreeterServer::say_hello_stream(&self.0, req).await,
+                        &method,
+                    )
+                }
+                Some(GreeterMethods::SayHellos(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        GreeterServer::say_hellos(&self.0, req).await,
+                        &method,
+                    )
+                }
+                Some(GreeterMethods::SayHelloChat(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        GreeterServer::say_hello_chat(&self.0, req).await,
+                        &method,
+                    )
+                }
+                None => Err(::ntex_grpc::server::ServerError::new(
+                    ::ntex_grpc::GrpcStatus::Unimplemented,
+                    ::ntex_grpc::HeaderValue::from_str(&format!(
+                        "Service method is not found: {}",
+                        req.name
+                    ))
+                    .unwrap_or(::ntex_grpc::HeaderValue::from_static(
+                        "Service method is not found",
+                    )),
+                    None,
+                )),
+            }
+        }
+    }
+
+    impl<T: GreeterServer + Clone + 'static>
+        ::ntex_grpc::ServiceFactory<::ntex_grpc::server::ServerRequest, ::ntex_grpc::SharedCfg>
+        for GreeterServerAdapter<T>
+    {
+        type Response = ::ntex_grpc::server::ServerResponse;
+        type Error = ::ntex_grpc::server::ServerError;
+        type InitError = ();
+        type Service = GreeterServerAdapter<T>;
+
+        async fn create(
+            &self,
+            _: ::ntex_grpc::SharedCfg,
+        ) -> ::std::result::Result<Self::Service, Self::InitError> {
+            Ok(self.clone())
+        }
+    }
 }
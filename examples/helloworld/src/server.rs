@@ -1,5 +1,5 @@
 use ntex::{SharedCfg, server::Server, service::ServiceFactory, util::HashMap};
-use ntex_grpc::server;
+use ntex_grpc::{server, server::ResponseStream, server::Streaming};
 
 mod helloworld;
 use crate::helloworld::{HelloReply, HelloRequest};
@@ -20,8 +20,12 @@ impl From<HelloError> for HelloReply {
 #[server(crate::helloworld::Greeter)]
 impl GreeterServer {
     #[method(SayHello)]
-    async fn say_hello(&self, req: HelloRequest) -> Result<HelloReply, HelloError> {
-        log::trace!("Received request: {req:#?}");
+    async fn say_hello(
+        &self,
+        req: HelloRequest,
+        peer: Option<server::PeerAddr>,
+    ) -> Result<HelloReply, HelloError> {
+        log::trace!("Received request from {peer:?}: {req:#?}");
         let mut data3 = HashMap::default();
         data3.insert("1".to_string().into(), 10u32);
         Ok(HelloReply {
@@ -36,6 +40,64 @@ impl GreeterServer {
             data6: vec![-234234234, 123412414, 45456],
         })
     }
+
+    #[method(SayHelloStream)]
+    async fn say_hello_stream(&self, req: HelloRequest) -> ResponseStream<HelloReply> {
+        log::trace!("Received stream request: {req:#?}");
+        let (tx, rx) = ResponseStream::channel();
+        for idx in 0..3 {
+            let _ = tx.send(HelloReply {
+                message: format!("Hello {} #{idx}!", req.name).into(),
+                tag: idx,
+                ..Default::default()
+            });
+        }
+        rx
+    }
+
+    #[method(SayHellos)]
+    async fn say_hellos(
+        &self,
+        mut stream: Streaming<HelloRequest>,
+    ) -> Result<server::Response<HelloReply>, server::ServerError> {
+        let mut names = Vec::new();
+        while let Some(req) = stream.recv().await {
+            names.push(req?.name);
+        }
+        Ok(HelloReply {
+            message: format!("Hello {}!", names.join(", ")).into(),
+            ..Default::default()
+        }
+        .into())
+    }
+
+    #[method(SayHelloChat)]
+    async fn say_hello_chat(
+        &self,
+        mut stream: Streaming<HelloRequest>,
+    ) -> ResponseStream<HelloReply> {
+        let (tx, rx) = ResponseStream::channel();
+        ntex::rt::spawn(async move {
+            while let Some(req) = stream.recv().await {
+                match req {
+                    Ok(req) => {
+                        let msg = HelloReply {
+                            message: format!("Hello {}!", req.name).into(),
+                            ..Default::default()
+                        };
+                        if tx.send(msg).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        tx.error(err);
+                        break;
+                    }
+                }
+            }
+        });
+        rx
+    }
 }
 
 impl ServiceFactory<server::ServerRequest, SharedCfg> for GreeterServer {
@@ -65,11 +127,19 @@ async fn main() -> std::io::Result<()> {
     let port = matches.value_of("port").unwrap().to_owned();
     let threads = parse_usize_default(matches.value_of("threads"), num_cpus::get());
 
+    // services health status
+    let health = server::health::HealthReporter::new();
+    health.set_service_status::<GreeterServer>(server::health::ServingStatus::Serving);
+
     // bind to socket
     Server::builder()
         .bind("helloworld", format!("0.0.0.0:{port}"), async move |_| {
             // create service
-            server::GrpcServer::new(GreeterServer)
+            server::GrpcServer::new(
+                server::Router::new()
+                    .service(GreeterServer)
+                    .service(server::health::HealthServer::new(health.clone())),
+            )
         })?
         .workers(threads)
         .config("helloworld", SharedCfg::new("GRPC"))
@@ -13,6 +13,9 @@ impl ServiceGenerator for GrpcServiceGenerator {
 
         buf.push_str(&format!("\n/// `{}` service definition\n", service.name));
         generate_client(&service, buf, priv_buf);
+        if !service.methods.is_empty() {
+            generate_server(&service, buf, priv_buf);
+        }
     }
 }
 
@@ -76,11 +79,14 @@ fn generate_client(service: &Service, buf: &mut String, priv_buf: &mut String) {
         .collect();
     let comments = comments.join("");
 
+    let service_options = options_const(&service.encoded_options);
+
     let stream = format!(
         "#[derive(Debug, Clone, Copy, PartialEq, Eq)]
         pub struct {};
 
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(clippy::enum_variant_names)]
         pub enum {} {{
             {}
         }}
@@ -101,7 +107,7 @@ fn generate_client(service: &Service, buf: &mut String, priv_buf: &mut String) {
 
     let impl_stream = format!(
         "impl ::ntex_grpc::ServiceDef for {service_ident} {{
-            const NAME: &'static str = \"{service_name}\";
+            const NAME: &'static str = \"{service_name}\";{service_options}
             type Methods = {service_methods_name};
 
             #[inline]
@@ -152,6 +158,110 @@ fn generate_client(service: &Service, buf: &mut String, priv_buf: &mut String) {
     priv_buf.push_str(&impl_stream);
 }
 
+fn generate_server(service: &Service, buf: &mut String, priv_buf: &mut String) {
+    let service_ident = &service.name;
+    let server_ident = format!("{}Server", service.name);
+    let adapter_ident = format!("{}ServerAdapter", service.name);
+    let service_methods_name = format!("{}Methods", service.name);
+
+    let mut server_methods = Vec::new();
+    let mut service_methods_match = Vec::new();
+    for method in &service.methods {
+        let comments: String = method
+            .comments
+            .leading
+            .iter()
+            .map(|s| format!("///{s}\n"))
+            .collect();
+        let input = if method.client_streaming {
+            format!("::ntex_grpc::server::Streaming<{}>", method.input_type)
+        } else {
+            method.input_type.to_string()
+        };
+        let output = if method.server_streaming {
+            format!(
+                "::ntex_grpc::server::ResponseStream<{}>",
+                method.output_type
+            )
+        } else {
+            format!("::ntex_grpc::server::Response<{}>", method.output_type)
+        };
+        server_methods.push(format!(
+            "{comments}async fn {}(&self, req: ::ntex_grpc::server::Request<{input}>) -> ::std::result::Result<{output}, ::ntex_grpc::server::ServerError>;",
+            method.name
+        ));
+
+        service_methods_match.push(format!(
+            "Some({service_methods_name}::{}(method)) => {{
+                let message = method.server_request(&mut req).await?;
+                let req = req.into_request(message);
+                ::ntex_grpc::server::MethodResponse::into_response({server_ident}::{}(&self.0, req).await, &method)
+            }}",
+            method.proto_name, method.name
+        ));
+    }
+    let server_methods = server_methods.join("\n\n");
+    let service_methods_match = service_methods_match.join("\n");
+
+    buf.push_str(&format!(
+        "
+        /// `{service_ident}` service server
+        ///
+        /// Use `into_service()` for registering implementation in the grpc router.
+        pub trait {server_ident} {{
+            {server_methods}
+
+            /// Convert implementation to grpc service
+            fn into_service(self) -> {adapter_ident}<Self> where Self: Sized {{
+                {adapter_ident}(self)
+            }}
+        }}
+
+        /// Grpc service for `{server_ident}` implementation
+        #[derive(Debug, Clone)]
+        pub struct {adapter_ident}<T>(pub T);
+        "
+    ));
+
+    priv_buf.push_str(&format!(
+        "impl<T: {server_ident}> ::ntex_grpc::server::NamedService for {adapter_ident}<T> {{
+            const NAME: &'static str = <{service_ident} as ::ntex_grpc::ServiceDef>::NAME;
+        }}
+
+        impl<T: {server_ident}> ::ntex_grpc::Service<::ntex_grpc::server::ServerRequest> for {adapter_ident}<T> {{
+            type Response = ::ntex_grpc::server::ServerResponse;
+            type Error = ::ntex_grpc::server::ServerError;
+
+            async fn call(&self, mut req: ::ntex_grpc::server::ServerRequest, _: ::ntex_grpc::ServiceCtx<'_, Self>) -> ::std::result::Result<Self::Response, Self::Error> {{
+                use ::ntex_grpc::{{MethodDef, ServiceDef}};
+
+                match {service_ident}::method_by_name(&req.name) {{
+                    {service_methods_match}
+                    None => Err(::ntex_grpc::server::ServerError::new(
+                        ::ntex_grpc::GrpcStatus::Unimplemented,
+                        ::ntex_grpc::HeaderValue::from_str(&format!(\"Service method is not found: {{}}\", req.name))
+                            .unwrap_or(::ntex_grpc::HeaderValue::from_static(\"Service method is not found\")),
+                        None,
+                    )),
+                }}
+            }}
+        }}
+
+        impl<T: {server_ident} + Clone + 'static> ::ntex_grpc::ServiceFactory<::ntex_grpc::server::ServerRequest, ::ntex_grpc::SharedCfg> for {adapter_ident}<T> {{
+            type Response = ::ntex_grpc::server::ServerResponse;
+            type Error = ::ntex_grpc::server::ServerError;
+            type InitError = ();
+            type Service = {adapter_ident}<T>;
+
+            async fn create(&self, _: ::ntex_grpc::SharedCfg) -> ::std::result::Result<Self::Service, Self::InitError> {{
+                Ok(self.clone())
+            }}
+        }}
+
+        "
+    ));
+}
+
 fn gen_method(method: &Method, service: &Service) -> (String, String) {
     let proto_name = &method.proto_name;
     let path = if service.package.is_empty() {
@@ -173,6 +283,12 @@ fn gen_method(method: &Method, service: &Service) -> (String, String) {
         format!("super::{}", method.input_type)
     };
     let output_type = method.output_type.to_string();
+    let request_type = if method.client_streaming {
+        format!("::ntex_grpc::server::Streaming<{input_type}>")
+    } else {
+        input_type.clone()
+    };
+    let options = options_const(&method.encoded_options);
     let comments: Vec<_> = method
         .comments
         .leading
@@ -189,16 +305,212 @@ fn gen_method(method: &Method, service: &Service) -> (String, String) {
 
         impl ::ntex_grpc::MethodDef for {def_ident} {{
             const NAME: &'static str = \"{proto_name}\";
-            const PATH: ::ntex_grpc::ByteString = ::ntex_grpc::ByteString::from_static(\"{path}\");
+            const PATH: ::ntex_grpc::ByteString = ::ntex_grpc::ByteString::from_static(\"{path}\");{options}
             type Input = {input_type};
             type Output = {output_type};
+            type Request = {request_type};
         }}"),
-        format!(
-            "impl<T: ::ntex_grpc::client::Transport<{def_ident}>> {service_ident}<T> {{
+        if method.client_streaming && method.server_streaming {
+            format!(
+                "impl<T: ::ntex_grpc::client::StreamingTransport<{def_ident}>> {service_ident}<T> {{
+            {comments}
+            pub fn {method_ident}<'a>(&'a self) -> ::ntex_grpc::client::StreamingRequest<'a, T, {def_ident}, (::ntex_grpc::client::RequestSink<{def_ident}>, ::ntex_grpc::client::Streaming<{def_ident}>)> {{
+                ::ntex_grpc::client::StreamingRequest::bidi(&self.0)
+            }}
+        }}")
+        } else if method.client_streaming {
+            format!(
+                "impl<T: ::ntex_grpc::client::StreamingTransport<{def_ident}>> {service_ident}<T> {{
+            {comments}
+            pub fn {method_ident}<'a>(&'a self) -> ::ntex_grpc::client::StreamingRequest<'a, T, {def_ident}, ::ntex_grpc::client::ClientStreaming<{def_ident}>> {{
+                ::ntex_grpc::client::StreamingRequest::client(&self.0)
+            }}
+        }}")
+        } else if method.server_streaming {
+            format!(
+                "impl<T: ::ntex_grpc::client::StreamingTransport<{def_ident}>> {service_ident}<T> {{
+            {comments}
+            pub fn {method_ident}<'a>(&'a self, req: &'a {req_input_type}) -> ::ntex_grpc::client::StreamingRequest<'a, T, {def_ident}> {{
+                ::ntex_grpc::client::StreamingRequest::new(&self.0, req)
+            }}
+        }}")
+        } else {
+            format!(
+                "impl<T: ::ntex_grpc::client::Transport<{def_ident}>> {service_ident}<T> {{
             {comments}
             pub fn {method_ident}<'a>(&'a self, req: &'a {req_input_type}) -> ::ntex_grpc::client::Request<'a, T, {def_ident}> {{
                 ::ntex_grpc::client::Request::new(&self.0, req)
             }}
-        }}"),
+        }}")
+        },
     )
 }
+
+/// `OPTIONS` constant with encoded options, empty options use default value
+fn options_const(options: &[u8]) -> String {
+    if options.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\nconst OPTIONS: &'static [u8] = b\"{}\";",
+            options.escape_ascii()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    use std::{env, fs, path::Path};
+
+    use ntex_prost_build::Comments;
+
+    use super::*;
+
+    fn method(name: &str, proto_name: &str, client_streaming: bool) -> Method {
+        Method {
+            name: name.to_string(),
+            proto_name: proto_name.to_string(),
+            comments: Comments {
+                leading_detached: Vec::new(),
+                leading: vec![format!(" {proto_name} method")],
+                trailing: Vec::new(),
+            },
+            input_type: "::ntex_grpc::google_types::StringValue".to_string(),
+            output_type: "::ntex_grpc::google_types::StringValue".to_string(),
+            input_proto_type: ".google.protobuf.StringValue".to_string(),
+            output_proto_type: ".google.protobuf.StringValue".to_string(),
+            options: prost_types::MethodOptions::default(),
+            encoded_options: Vec::new(),
+            client_streaming,
+            server_streaming: false,
+            input_type_extern: true,
+        }
+    }
+
+    /// Compare generated code with checked-in fixture
+    ///
+    /// Fixtures are compiled by `fixtures` module, set `UPDATE_FIXTURES`
+    /// env variable to regenerate them.
+    fn check_fixture(name: &str, buf: &str) {
+        let mut fmt = Command::new("rustfmt")
+            .args(["--edition", "2024"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        fmt.stdin.take().unwrap().write_all(buf.as_bytes()).unwrap();
+        let out = fmt.wait_with_output().unwrap();
+        assert!(out.status.success());
+        let code = String::from_utf8(out.stdout).unwrap();
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(format!("{name}.rs"));
+        if env::var_os("UPDATE_FIXTURES").is_some() {
+            fs::write(&path, code).unwrap();
+        } else {
+            assert!(
+                fs::read_to_string(&path).unwrap() == code,
+                "{} is outdated, regenerate with UPDATE_FIXTURES=1",
+                path.display()
+            );
+        }
+    }
+
+    #[allow(dead_code, clippy::all)]
+    mod fixtures {
+        pub mod service {
+            include!("../tests/fixtures/service.rs");
+        }
+    }
+
+    #[ntex::test]
+    async fn server_trait() {
+        let service = Service {
+            name: "Echo".to_string(),
+            proto_name: "Echo".to_string(),
+            package: "test".to_string(),
+            comments: Comments {
+                leading_detached: Vec::new(),
+                leading: Vec::new(),
+                trailing: Vec::new(),
+            },
+            methods: vec![
+                method("say", "Say", false),
+                method("collect", "Collect", true),
+            ],
+            options: prost_types::ServiceOptions::default(),
+            encoded_options: Vec::new(),
+        };
+        let (mut buf, mut priv_buf) = (String::new(), String::new());
+        GrpcServiceGenerator.generate(service, &mut buf, &mut priv_buf);
+        check_fixture(
+            "service",
+            &format!("{buf}\nmod _priv_impl_test {{\nuse super::*;\n\n{priv_buf}}}"),
+        );
+
+        use fixtures::service::EchoServer;
+        use ntex::service::{Pipeline, ServiceFactory};
+        use ntex_grpc::google_types::StringValue;
+        use ntex_grpc::server::{
+            Request, Response, Router, ServerError, ServerRequest, Streaming,
+        };
+        use ntex_grpc::{ByteString, BytesMut, Message, SharedCfg};
+
+        #[derive(Clone)]
+        struct Echoer;
+
+        impl EchoServer for Echoer {
+            async fn say(
+                &self,
+                req: Request<StringValue>,
+            ) -> Result<Response<StringValue>, ServerError> {
+                Ok(req.into_inner().into())
+            }
+
+            async fn collect(
+                &self,
+                mut req: Request<Streaming<StringValue>>,
+            ) -> Result<Response<StringValue>, ServerError> {
+                let mut values = Vec::new();
+                while let Some(msg) = req.recv().await {
+                    values.push(msg?.value.to_string());
+                }
+                Ok(StringValue {
+                    value: values.join(",").into(),
+                }
+                .into())
+            }
+        }
+
+        let router = Router::new().service(Echoer.into_service());
+        let srv = Pipeline::new(router.create(SharedCfg::default()).await.unwrap());
+
+        let req = |method: &'static str, value: &'static str| {
+            let mut buf = BytesMut::new();
+            StringValue {
+                value: ByteString::from_static(value),
+            }
+            .write(&mut buf);
+            ServerRequest::new(
+                ByteString::from_static("test.Echo"),
+                ByteString::from_static(method),
+                buf.freeze(),
+                Default::default(),
+            )
+        };
+        for method in ["Say", "Collect"] {
+            let mut res = srv.call(req(method, "hello")).await.unwrap();
+            let res = StringValue::read(&mut res.payload).unwrap();
+            assert_eq!(res.value, "hello");
+        }
+
+        let err = srv.call(req("Unknown", "")).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unimplemented: \"Service method is not found: Unknown\""
+        );
+    }
+}
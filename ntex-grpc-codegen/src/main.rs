@@ -33,6 +33,18 @@ struct Args {
     #[clap(short, long, value_parser, name = "MAP")]
     map: Vec<String>,
 
+    /// Embed encoded FileDescriptorSet into generated file, could be used for server reflection
+    #[clap(short, long, value_parser)]
+    descriptor_set: bool,
+
+    /// Preserve unknown fields of messages, unknown fields are written back on encoding
+    #[clap(long, value_parser)]
+    preserve_unknown_fields: bool,
+
+    /// Retain unknown values of enum fields
+    #[clap(long, value_parser)]
+    open_enums: bool,
+
     /// Path to rustfmt configuration file
     #[clap(short, long, value_parser, name = "RUSTFMT-PATH")]
     rustfmt_path: Option<path::PathBuf>,
@@ -56,6 +68,18 @@ fn main() -> io::Result<()> {
         }
     }
 
+    if args.descriptor_set {
+        cfg.embed_file_descriptor_set();
+    }
+
+    if args.preserve_unknown_fields {
+        cfg.preserve_unknown_fields(["."]);
+    }
+
+    if args.open_enums {
+        cfg.open_enums(["."]);
+    }
+
     cfg.default_package_filename(args.out.clone());
 
     if let Err(e) = cfg.compile_protos(&args.proto, &args.include_dir) {
@@ -0,0 +1,214 @@
+/// `Echo` service definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Echo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum EchoMethods {
+    Say(EchoSayMethod),
+    Collect(EchoCollectMethod),
+}
+
+#[derive(Debug, Clone)]
+pub struct EchoClient<T>(T);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EchoSayMethod;
+
+impl ::ntex_grpc::MethodDef for EchoSayMethod {
+    const NAME: &'static str = "Say";
+    const PATH: ::ntex_grpc::ByteString = ::ntex_grpc::ByteString::from_static("/test.Echo/Say");
+    type Input = ::ntex_grpc::google_types::StringValue;
+    type Output = ::ntex_grpc::google_types::StringValue;
+    type Request = ::ntex_grpc::google_types::StringValue;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EchoCollectMethod;
+
+impl ::ntex_grpc::MethodDef for EchoCollectMethod {
+    const NAME: &'static str = "Collect";
+    const PATH: ::ntex_grpc::ByteString =
+        ::ntex_grpc::ByteString::from_static("/test.Echo/Collect");
+    type Input = ::ntex_grpc::google_types::StringValue;
+    type Output = ::ntex_grpc::google_types::StringValue;
+    type Request = ::ntex_grpc::server::Streaming<::ntex_grpc::google_types::StringValue>;
+}
+/// `Echo` service server
+///
+/// Use `into_service()` for registering implementation in the grpc router.
+pub trait EchoServer {
+    /// Say method
+    async fn say(
+        &self,
+        req: ::ntex_grpc::server::Request<::ntex_grpc::google_types::StringValue>,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::Response<::ntex_grpc::google_types::StringValue>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Collect method
+    async fn collect(
+        &self,
+        req: ::ntex_grpc::server::Request<
+            ::ntex_grpc::server::Streaming<::ntex_grpc::google_types::StringValue>,
+        >,
+    ) -> ::std::result::Result<
+        ::ntex_grpc::server::Response<::ntex_grpc::google_types::StringValue>,
+        ::ntex_grpc::server::ServerError,
+    >;
+
+    /// Convert implementation to grpc service
+    fn into_service(self) -> EchoServerAdapter<Self>
+    where
+        Self: Sized,
+    {
+        EchoServerAdapter(self)
+    }
+}
+
+/// Grpc service for `EchoServer` implementation
+#[derive(Debug, Clone)]
+pub struct EchoServerAdapter<T>(pub T);
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::ServiceDef for Echo {
+        const NAME: &'static str = "test.Echo";
+        type Methods = EchoMethods;
+
+        #[inline]
+        fn method_by_name(name: &str) -> Option<Self::Methods> {
+            use ::ntex_grpc::MethodDef;
+            match name {
+                EchoSayMethod::NAME => Some(EchoMethods::Say(EchoSayMethod)),
+                EchoCollectMethod::NAME => Some(EchoMethods::Collect(EchoCollectMethod)),
+                _ => None,
+            }
+        }
+    }
+
+    impl<T> EchoClient<T> {
+        #[inline]
+        /// Create new client instance
+        pub fn new(transport: T) -> Self {
+            Self(transport)
+        }
+    }
+
+    impl<T> ::ntex_grpc::client::ClientInformation<T> for EchoClient<T> {
+        #[inline]
+        /// Create new client instance
+        fn create(transport: T) -> Self {
+            Self(transport)
+        }
+
+        #[inline]
+        /// Get referece to underlying transport
+        fn transport(&self) -> &T {
+            &self.0
+        }
+
+        #[inline]
+        /// Get mut referece to underlying transport
+        fn transport_mut(&mut self) -> &mut T {
+            &mut self.0
+        }
+
+        #[inline]
+        /// Consume client and return inner transport
+        fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<T: ::ntex_grpc::client::Transport<EchoSayMethod>> EchoClient<T> {
+        /// Say method
+        pub fn say<'a>(
+            &'a self,
+            req: &'a ::ntex_grpc::google_types::StringValue,
+        ) -> ::ntex_grpc::client::Request<'a, T, EchoSayMethod> {
+            ::ntex_grpc::client::Request::new(&self.0, req)
+        }
+    }
+
+    impl<T: ::ntex_grpc::client::StreamingTransport<EchoCollectMethod>> EchoClient<T> {
+        /// Collect method
+        pub fn collect<'a>(
+            &'a self,
+        ) -> ::ntex_grpc::client::StreamingRequest<
+            'a,
+            T,
+            EchoCollectMethod,
+            ::ntex_grpc::client::ClientStreaming<EchoCollectMethod>,
+        > {
+            ::ntex_grpc::client::StreamingRequest::client(&self.0)
+        }
+    }
+    impl<T: EchoServer> ::ntex_grpc::server::NamedService for EchoServerAdapter<T> {
+        const NAME: &'static str = <Echo as ::ntex_grpc::ServiceDef>::NAME;
+    }
+
+    impl<T: EchoServer> ::ntex_grpc::Service<::ntex_grpc::server::ServerRequest>
+        for EchoServerAdapter<T>
+    {
+        type Response = ::ntex_grpc::server::ServerResponse;
+        type Error = ::ntex_grpc::server::ServerError;
+
+        async fn call(
+            &self,
+            mut req: ::ntex_grpc::server::ServerRequest,
+            _: ::ntex_grpc::ServiceCtx<'_, Self>,
+        ) -> ::std::result::Result<Self::Response, Self::Error> {
+            use ::ntex_grpc::{MethodDef, ServiceDef};
+
+            match Echo::method_by_name(&req.name) {
+                Some(EchoMethods::Say(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        EchoServer::say(&self.0, req).await,
+                        &method,
+                    )
+                }
+                Some(EchoMethods::Collect(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    ::ntex_grpc::server::MethodResponse::into_response(
+                        EchoServer::collect(&self.0, req).await,
+                        &method,
+                    )
+                }
+                None => Err(::ntex_grpc::server::ServerError::new(
+                    ::ntex_grpc::GrpcStatus::Unimplemented,
+                    ::ntex_grpc::HeaderValue::from_str(&format!(
+                        "Service method is not found: {}",
+                        req.name
+                    ))
+                    .unwrap_or(::ntex_grpc::HeaderValue::from_static(
+                        "Service method is not found",
+                    )),
+                    None,
+                )),
+            }
+        }
+    }
+
+    impl<T: EchoServer + Clone + 'static>
+        ::ntex_grpc::ServiceFactory<::ntex_grpc::server::ServerRequest, ::ntex_grpc::SharedCfg>
+        for EchoServerAdapter<T>
+    {
+        type Response = ::ntex_grpc::server::ServerResponse;
+        type Error = ::ntex_grpc::server::ServerError;
+        type InitError = ();
+        type Service = EchoServerAdapter<T>;
+
+        async fn create(
+            &self,
+            _: ::ntex_grpc::SharedCfg,
+        ) -> ::std::result::Result<Self::Service, Self::InitError> {
+            Ok(self.clone())
+        }
+    }
+}
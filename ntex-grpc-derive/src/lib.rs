@@ -1,8 +1,15 @@
 use proc_macro::TokenStream;
-use syn::{fold::Fold, parse::Parse, parse::ParseStream, punctuated::Punctuated};
+use syn::{
+    fold::Fold, parse::Parse, parse::ParseStream, punctuated::Punctuated, spanned::Spanned,
+};
 
 const ERR_M_MESSAGE: &str = "invalid method definition, expected: #[method(name)]";
 
+/// Generate grpc service for impl block
+///
+/// Each handler is bound to a service method with `#[method(Name)]` attribute.
+/// With `#[server(path::to::Service, exhaustive)]` compilation fails
+/// if any service method has no handler.
 #[proc_macro_attribute]
 pub fn server(attr: TokenStream, item: TokenStream) -> TokenStream {
     server_impl(attr, item)
@@ -10,21 +17,29 @@ pub fn server(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 fn server_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut srv = syn::parse_macro_input!(attr as GrpcService);
-    let input: syn::ItemImpl = syn::parse2(item.into()).unwrap();
+    let input = syn::parse_macro_input!(item as syn::ItemImpl);
 
     match input.self_ty.as_ref() {
-        syn::Type::Path(tp) => {
+        syn::Type::Path(tp) if !tp.path.segments.is_empty() => {
             srv.self_ty = tp.path.clone();
-            if let Some(s) = tp.path.segments.last() {
-                srv.name = format!("{}", s.ident);
-            } else {
-                panic!("struct name is required");
-            }
+            srv.name = format!("{}", tp.path.segments.last().unwrap().ident);
+        }
+        ty => {
+            return syn::Error::new_spanned(ty, "struct impl block is supported only")
+                .to_compile_error()
+                .into();
         }
-        _ => panic!("struct impl block is supported only"),
     }
 
     let input = srv.fold_item_impl(input);
+    let errors = srv
+        .errors
+        .into_iter()
+        .reduce(|mut err, e| {
+            err.combine(e);
+            err
+        })
+        .map(|err| err.to_compile_error());
 
     let ty = srv.self_ty;
     let srvpath = srv.service;
@@ -32,36 +47,104 @@ fn server_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let srvmod = srv.service_mod;
     let modname = quote::format_ident!("_priv_{}", srv.name);
     let methods_prefix = quote::format_ident!("{}Methods", srvname);
-    let mut methods_path = srvmod;
+    let mut methods_path = srvmod.clone();
     methods_path.segments.push(methods_prefix.into());
 
     let mut methods = Vec::new();
-    for (m_name, fn_name, span) in srv.methods {
+    for m in srv.methods {
+        let (m_name, fn_name, span) = (m.name, m.fn_name, m.span);
+        let variant = quote::quote_spanned! {m_name.span()=> #methods_path::#m_name };
+        // message type is defined by service method, `Streaming`
+        // for client streaming methods
+        let message = quote::quote! { method.server_request(&mut req).await? };
+
+        // every handler argument is extracted from request
+        let args: Vec<_> = m
+            .args
+            .iter()
+            .map(|arg| {
+                quote::quote_spanned! {arg.span()=>
+                    ::ntex_grpc::server::FromRequest::from_request(&req, &mut message)?
+                }
+            })
+            .collect();
+
+        // request message could be taken by one argument only
+        let mut m_type = srvmod.clone();
+        m_type
+            .segments
+            .push(quote::format_ident!("{}{}Method", srvname, m_name).into());
+        let takes_message: Vec<_> = m
+            .args
+            .iter()
+            .map(|arg| {
+                quote::quote_spanned! {arg.span()=>
+                    <#arg as ::ntex_grpc::server::FromRequest<<#m_type as ::ntex_grpc::MethodDef>::Request>>::MESSAGE
+                }
+            })
+            .collect();
+        let checks = m.args.iter().enumerate().skip(1).map(|(idx, arg)| {
+            let (current, prev) = (&takes_message[idx], &takes_message[..idx]);
+            quote::quote_spanned! {arg.span()=>
+                const _: () = assert!(
+                    !(#current && (#(#prev)||*)),
+                    "request message could be extracted by one handler argument only"
+                );
+            }
+        });
+        let extract = if args.is_empty() {
+            quote::quote! { let _ = #message; }
+        } else {
+            quote::quote! {
+                let mut message = Some(#message);
+                let req = req.into_request(());
+            }
+        };
+
         methods.push(quote::quote_spanned! {span=>
-            Some(#methods_path::#m_name(method)) => {
+            Some(#variant(method)) => {
                 use ::ntex_grpc::MethodDef;
-                let req = ::ntex_grpc::server::Request {
-                    message: method.decode(&mut req.payload)?,
-                    name: req.name,
-                    headers: req.headers
-                };
-
-                let result = #ty::#fn_name(self, ::ntex_grpc::server::FromRequest::from(req)).await;
+                #(#checks)*
+                #extract
 
-                let res = method.server_result(result);
-                let response = ::ntex_grpc::server::Response::from(res);
-                let mut buf = ::ntex_grpc::BytesMut::new();
-                method.encode(response.message, &mut buf);
+                let result = #ty::#fn_name(self, #(#args),*).await;
 
-                Ok(::ntex_grpc::server::ServerResponse::with_headers(buf.freeze(), response.headers))
+                ::ntex_grpc::server::MethodResponse::into_response(result, &method)
             }
         });
     }
 
+    // exhaustive services do not fallback to `Unimplemented` error,
+    // so compiler reports methods without handler
+    let exhaustive = srv.exhaustive.filter(|_| errors.is_none());
+    let (method_match, unimplemented) = if let Some(span) = exhaustive {
+        (
+            quote::quote_spanned! {span=> match #srvpath::method_by_name(&req.name) },
+            quote::quote! {},
+        )
+    } else {
+        (
+            quote::quote! { match #srvpath::method_by_name(&req.name) },
+            quote::quote! {
+                Some(_) => Err(::ntex_grpc::server::ServerError::new(
+                    ::ntex_grpc::GrpcStatus::Unimplemented,
+                    ::ntex_grpc::HeaderValue::from_shared(
+                        ::ntex_grpc::ByteString::from(format!("Service method is not implemented: {0}", req.name)).into_bytes()
+                    ).unwrap(),
+                    None
+                )),
+            },
+        )
+    };
+
     let service = quote::quote! {
         mod #modname {
             use super::*;
 
+            impl ::ntex_grpc::server::NamedService for #ty {
+                const NAME: &'static str = <#srvpath as ::ntex_grpc::ServiceDef>::NAME;
+            }
+
             impl ::ntex_grpc::Service<::ntex_grpc::server::ServerRequest> for #ty {
                 type Response = ::ntex_grpc::server::ServerResponse;
                 type Error = ::ntex_grpc::server::ServerError;
@@ -69,15 +152,9 @@ fn server_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
                 async fn call(&self, mut req: ::ntex_grpc::server::ServerRequest, _: ::ntex_grpc::ServiceCtx<'_, Self>) -> Result<Self::Response, Self::Error> {
                     use ::ntex_grpc::{ServiceDef, MethodDef};
 
-                    match #srvpath::method_by_name(&req.name) {
+                    #method_match {
                         #(#methods)*
-                        Some(_) => Err(::ntex_grpc::server::ServerError::new(
-                            ::ntex_grpc::GrpcStatus::Unimplemented,
-                            ::ntex_grpc::HeaderValue::from_shared(
-                                ::ntex_grpc::ByteString::from(format!("Service method is not implemented: {0}", req.name)).into_bytes()
-                            ).unwrap(),
-                            None
-                        )),
+                        #unimplemented
                         None => Err(::ntex_grpc::server::ServerError::new(
                             ::ntex_grpc::GrpcStatus::NotFound,
                             ::ntex_grpc::HeaderValue::from_shared(
@@ -94,6 +171,7 @@ fn server_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let tokens = quote::quote! {
         #input
         #service
+        #errors
     };
     tokens.into()
 }
@@ -105,50 +183,117 @@ struct GrpcService {
     service: syn::Path,
     service_mod: syn::Path,
     service_name: syn::Ident,
-    methods: Vec<(syn::Ident, syn::Ident, proc_macro2::Span)>,
+    exhaustive: Option<proc_macro2::Span>,
+    methods: Vec<HandlerMethod>,
+    errors: Vec<syn::Error>,
+}
+
+#[derive(Debug)]
+struct HandlerMethod {
+    /// Service method name
+    name: syn::Ident,
+    fn_name: syn::Ident,
+    span: proc_macro2::Span,
+    /// Types of handler arguments
+    args: Vec<syn::Type>,
 }
 
 impl Parse for GrpcService {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        const ERR_S_MESSAGE: &str = "invalid service definition, expected: #[server(path::to::Service)] or #[server(path::to::Service, exhaustive)]";
+
         let parsed: Punctuated<syn::Path, syn::Token![,]> = Punctuated::parse_terminated(input)?;
-        let path = parsed.first().unwrap().clone();
-        let service = parsed.first().unwrap().clone();
+        let mut parsed = parsed.into_iter();
+        let service = parsed
+            .next()
+            .ok_or_else(|| syn::Error::new(input.span(), ERR_S_MESSAGE))?;
+
+        let mut exhaustive = None;
+        for opt in parsed {
+            if opt.is_ident("exhaustive") && exhaustive.is_none() {
+                exhaustive = Some(opt.segments[0].ident.span());
+            } else {
+                return Err(syn::Error::new_spanned(opt, ERR_S_MESSAGE));
+            }
+        }
+
         let mut service_mod = service.clone();
         service_mod.segments.pop();
-        let service_name = path.segments.last().unwrap().ident.clone();
+        let service_name = service.segments.last().unwrap().ident.clone();
         Ok(GrpcService {
-            service,
             service_mod,
             service_name,
+            exhaustive,
             methods: Vec::new(),
+            errors: Vec::new(),
             name: String::new(),
-            self_ty: path,
+            self_ty: service.clone(),
+            service,
         })
     }
 }
 
+impl GrpcService {
+    fn method(&self, attr: &syn::Attribute, m: &syn::ImplItemFn) -> syn::Result<syn::Ident> {
+        let name: syn::Path = match attr.meta {
+            syn::Meta::List(ref lst) => lst.parse_args()?,
+            _ => return Err(syn::Error::new_spanned(attr, ERR_M_MESSAGE)),
+        };
+        let Some(m_name) = name.get_ident() else {
+            return Err(syn::Error::new_spanned(
+                name,
+                "only simple identifiers are supported, expected: #[method(name)]",
+            ));
+        };
+
+        if let Some(m) = self.methods.iter().find(|m| &m.name == m_name) {
+            return Err(syn::Error::new_spanned(
+                m_name,
+                format!("method `{m_name}` is already handled by `{}`", m.fn_name),
+            ));
+        }
+        if !matches!(
+            m.sig.inputs.first(),
+            Some(syn::FnArg::Receiver(recv)) if recv.reference.is_some() && recv.mutability.is_none()
+        ) {
+            return Err(syn::Error::new_spanned(
+                &m.sig,
+                "method handler must take `&self` as first argument",
+            ));
+        }
+        Ok(m_name.clone())
+    }
+}
+
 impl Fold for GrpcService {
     fn fold_impl_item_fn(&mut self, mut m: syn::ImplItemFn) -> syn::ImplItemFn {
-        for idx in 0..m.attrs.len() {
-            let attr = &m.attrs[idx];
-            if attr.path().is_ident("method") {
-                let lst = if let syn::Meta::List(ref lst) = attr.meta {
-                    lst
-                } else {
-                    panic!("{}", ERR_M_MESSAGE)
-                };
-
-                let name: syn::Path = lst.parse_args().expect(ERR_M_MESSAGE);
-                let m_name = if let Some(ident) = name.get_ident() {
-                    ident.clone()
-                } else {
-                    panic!("only simple identifiers are supported: {:?}", name);
-                };
-
-                let _ = m.attrs.remove(idx);
-                self.methods
-                    .push((m_name, m.sig.ident.clone(), m.sig.fn_token.span));
-                break;
+        let attrs = std::mem::take(&mut m.attrs);
+        for attr in attrs {
+            if !attr.path().is_ident("method") {
+                m.attrs.push(attr);
+                continue;
+            }
+
+            match self.method(&attr, &m) {
+                Ok(name) => {
+                    let args: Vec<_> = m
+                        .sig
+                        .inputs
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            syn::FnArg::Typed(arg) => Some(arg),
+                            syn::FnArg::Receiver(_) => None,
+                        })
+                        .collect();
+
+                    self.methods.push(HandlerMethod {
+                        name,
+                        fn_name: m.sig.ident.clone(),
+                        span: m.sig.fn_token.span,
+                        args: args.iter().map(|arg| (*arg.ty).clone()).collect(),
+                    });
+                }
+                Err(err) => self.errors.push(err),
             }
         }
 
@@ -0,0 +1,51 @@
+use ntex::service::Pipeline;
+use ntex_grpc::server::reflection::{ServerReflectionRequest, ServerReflectionResponse};
+use ntex_grpc::server::{ServerError, ServerRequest, Streaming};
+use ntex_grpc::{ByteString, BytesMut, Message, server};
+
+/// Request stream behind type alias
+type Requests = Streaming<ServerReflectionRequest>;
+
+#[derive(Clone)]
+struct Reflection;
+
+#[server(ntex_grpc::server::reflection::ServerReflection)]
+impl Reflection {
+    #[method(ServerReflectionInfo)]
+    async fn info(
+        &self,
+        mut stream: Requests,
+    ) -> Result<server::Response<ServerReflectionResponse>, ServerError> {
+        let mut hosts = Vec::new();
+        while let Some(req) = stream.recv().await {
+            hosts.push(req?.host.to_string());
+        }
+        Ok(ServerReflectionResponse {
+            valid_host: hosts.join(",").into(),
+            ..Default::default()
+        }
+        .into())
+    }
+}
+
+#[ntex::test]
+async fn client_streaming_alias() {
+    let srv = Pipeline::new(Reflection);
+
+    let mut payload = BytesMut::new();
+    ServerReflectionRequest {
+        host: "localhost".into(),
+        ..Default::default()
+    }
+    .write(&mut payload);
+    let req = ServerRequest::new(
+        ByteString::from_static("grpc.reflection.v1.ServerReflection"),
+        ByteString::from_static("ServerReflectionInfo"),
+        payload.freeze(),
+        Default::default(),
+    );
+
+    let mut res = srv.call(req).await.unwrap();
+    let res = ServerReflectionResponse::read(&mut res.payload).unwrap();
+    assert_eq!(res.valid_host, "localhost");
+}
@@ -0,0 +1,20 @@
+use ntex_grpc::server;
+use ntex_grpc::server::health::{HealthCheckRequest, HealthCheckResponse};
+use ntex_grpc::server::{Deadline, Request, ServerError};
+
+struct Checker;
+
+#[server(ntex_grpc::server::health::Health)]
+impl Checker {
+    #[method(Check)]
+    async fn check(
+        &self,
+        _: HealthCheckRequest,
+        _: Deadline,
+        _: Request<HealthCheckRequest>,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+}
+
+fn main() {}
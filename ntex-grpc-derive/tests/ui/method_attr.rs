@@ -0,0 +1,34 @@
+use ntex_grpc::server;
+use ntex_grpc::server::ServerError;
+use ntex_grpc::server::health::{HealthCheckRequest, HealthCheckResponse};
+
+struct Checker;
+
+#[server(ntex_grpc::server::health::Health)]
+impl Checker {
+    #[method]
+    async fn no_name(
+        &self,
+        _: HealthCheckRequest,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+
+    #[method(health::Check)]
+    async fn path(
+        &self,
+        _: HealthCheckRequest,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+
+    #[method = "Check"]
+    async fn name_value(
+        &self,
+        _: HealthCheckRequest,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ntex_grpc::server;
+
+trait Checker {}
+
+#[server(ntex_grpc::server::health::Health)]
+impl dyn Checker {}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use ntex_grpc::server;
+use ntex_grpc::server::ServerError;
+use ntex_grpc::server::health::{HealthCheckRequest, HealthCheckResponse};
+
+struct Checker;
+
+#[server(ntex_grpc::server::health::Health)]
+impl Checker {
+    #[method(Check)]
+    async fn check(
+        _: HealthCheckRequest,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+
+    #[method(Check)]
+    async fn check_mut(
+        &mut self,
+        _: HealthCheckRequest,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+
+    #[method(Check)]
+    async fn check_owned(
+        self,
+        _: HealthCheckRequest,
+    ) -> Result<server::Response<HealthCheckResponse>, ServerError> {
+        unimplemented!()
+    }
+}
+
+fn main() {}
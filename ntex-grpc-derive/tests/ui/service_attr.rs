@@ -0,0 +1,18 @@
+use ntex_grpc::server;
+
+struct Empty;
+
+#[server()]
+impl Empty {}
+
+struct Unknown;
+
+#[server(ntex_grpc::server::health::Health, unknown)]
+impl Unknown {}
+
+struct Twice;
+
+#[server(ntex_grpc::server::health::Health, exhaustive, exhaustive)]
+impl Twice {}
+
+fn main() {}
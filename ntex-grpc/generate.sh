@@ -3,3 +3,8 @@
 ntex-grpc timestamp.proto timestamp.rs --out-dir ./src/google_types --include-dir ../prost-build/third-party/include/google/protobuf/
 ntex-grpc duration.proto duration.rs --out-dir ./src/google_types --include-dir ../prost-build/third-party/include/google/protobuf/
 ntex-grpc wrappers.proto wrappers.rs --out-dir ./src/google_types --include-dir ../prost-build/third-party/include/google/protobuf/
+ntex-grpc any.proto any.rs --out-dir ./src/google_types --include-dir ../prost-build/third-party/include/google/protobuf/
+
+# google.rpc protos, requires googleapis checkout https://github.com/googleapis/googleapis
+ntex-grpc status.proto status.rs --out-dir ./src/google_types --include-dir ../../googleapis/google/rpc/ --include-dir ../prost-build/third-party/include/
+ntex-grpc error_details.proto error_details.rs --out-dir ./src/google_types --include-dir ../../googleapis/google/rpc/ --include-dir ../prost-build/third-party/include/
@@ -0,0 +1,475 @@
+use std::{rc::Rc, time};
+
+use ntex_error::Error;
+use ntex_http::HeaderMap;
+use ntex_service::Middleware;
+
+use super::streaming::OnComplete;
+use super::{ClientError, ClientStreaming, RequestContext, RequestSink, Response, Streaming};
+use super::{StreamingTransport, Transport};
+use crate::service::MethodDef;
+
+/// Client calls interceptor
+///
+/// Interceptor is called for every request before sending it to the peer,
+/// it could add request metadata or reject the call.
+pub trait Interceptor {
+    /// Inspect request before it is sent
+    ///
+    /// Returned error rejects the call, request is not sent.
+    async fn request(&self, path: &str, ctx: &mut RequestContext) -> Result<(), ClientError> {
+        let _ = (path, ctx);
+        Ok(())
+    }
+
+    /// Observe call result
+    ///
+    /// `Ok` contains response headers. For streaming methods result is
+    /// available when response stream is exhausted, failed or dropped,
+    /// `elapsed` covers the whole call.
+    fn response(
+        &self,
+        path: &str,
+        res: Result<&HeaderMap, &Error<ClientError>>,
+        elapsed: time::Duration,
+    ) {
+        let _ = (path, res, elapsed);
+    }
+}
+
+/// Middleware that applies interceptor to the transport
+///
+/// ```rust,ignore
+/// let transport = Intercept::new(Auth).create(Client::new(h2client), ());
+/// let client = GreeterClient::new(transport);
+/// ```
+#[derive(Debug)]
+pub struct Intercept<I>(Rc<I>);
+
+impl<I> Intercept<I> {
+    /// Create middleware for interceptor
+    pub fn new(interceptor: I) -> Self {
+        Self(Rc::new(interceptor))
+    }
+}
+
+impl<I> Clone for Intercept<I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I, T, C> Middleware<T, C> for Intercept<I> {
+    type Service = InterceptService<I, T>;
+
+    fn create(&self, transport: T, _: C) -> Self::Service {
+        InterceptService {
+            transport,
+            interceptor: self.0.clone(),
+        }
+    }
+}
+
+/// Transport with applied interceptor
+#[derive(Debug)]
+pub struct InterceptService<I, T> {
+    transport: T,
+    interceptor: Rc<I>,
+}
+
+impl<I, T> InterceptService<I, T> {
+    /// Get reference to underlying transport
+    pub fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    /// Get reference to interceptor
+    pub fn interceptor(&self) -> &I {
+        &self.interceptor
+    }
+}
+
+impl<I, T: Clone> Clone for InterceptService<I, T> {
+    fn clone(&self) -> Self {
+        Self {
+            transport: self.transport.clone(),
+            interceptor: self.interceptor.clone(),
+        }
+    }
+}
+
+impl<I, T> InterceptService<I, T>
+where
+    I: Interceptor + 'static,
+{
+    /// Intercept request and start the call
+    ///
+    /// Returned callback must be called on call completion.
+    async fn start<M, R, F>(
+        &self,
+        mut ctx: RequestContext,
+        f: impl FnOnce(RequestContext) -> F,
+    ) -> Result<(R, OnComplete), Error<ClientError>>
+    where
+        M: MethodDef,
+        F: Future<Output = Result<R, Error<ClientError>>>,
+    {
+        let start = time::Instant::now();
+        let path = M::PATH;
+        let interceptor = self.interceptor.clone();
+
+        let res = match interceptor.request(&path, &mut ctx).await {
+            Ok(()) => f(ctx).await,
+            Err(err) => Err(Error::from(err)),
+        };
+        let on_complete: OnComplete =
+            Box::new(move |res| interceptor.response(&path, res, start.elapsed()));
+
+        match res {
+            Ok(res) => Ok((res, on_complete)),
+            Err(err) => {
+                on_complete(Err(&err));
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<I, T, M> Transport<M> for InterceptService<I, T>
+where
+    I: Interceptor + 'static,
+    T: Transport<M, Error = Error<ClientError>>,
+    M: MethodDef,
+{
+    type Error = Error<ClientError>;
+
+    async fn request(
+        &self,
+        args: &M::Input,
+        ctx: RequestContext,
+    ) -> Result<Response<M>, Self::Error> {
+        let (res, on_complete) = self
+            .start::<M, _, _>(ctx, |ctx| self.transport.request(args, ctx))
+            .await?;
+        on_complete(Ok(res.headers()));
+        Ok(res)
+    }
+}
+
+impl<I, T, M> StreamingTransport<M> for InterceptService<I, T>
+where
+    I: Interceptor + 'static,
+    T: StreamingTransport<M, Error = Error<ClientError>>,
+    M: MethodDef,
+{
+    type Error = Error<ClientError>;
+
+    async fn server_streaming(
+        &self,
+        args: &M::Input,
+        ctx: RequestContext,
+    ) -> Result<Streaming<M>, Self::Error> {
+        let (mut stream, on_complete) = self
+            .start::<M, _, _>(ctx, |ctx| self.transport.server_streaming(args, ctx))
+            .await?;
+        stream.on_complete(on_complete);
+        Ok(stream)
+    }
+
+    async fn client_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<ClientStreaming<M>, Self::Error> {
+        let (mut call, on_complete) = self
+            .start::<M, _, _>(ctx, |ctx| self.transport.client_streaming(ctx))
+            .await?;
+        call.on_complete(on_complete);
+        Ok(call)
+    }
+
+    async fn bidi_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<(RequestSink<M>, Streaming<M>), Self::Error> {
+        let ((sink, mut stream), on_complete) = self
+            .start::<M, _, _>(ctx, |ctx| self.transport.bidi_streaming(ctx))
+            .await?;
+        stream.on_complete(on_complete);
+        Ok((sink, stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use ntex::time::{Millis, sleep};
+    use ntex_bytes::ByteString;
+    use ntex_h2::client::{ClientBuilder, SimpleClient};
+    use ntex_http::{HeaderValue, header};
+    use ntex_service::{cfg::SharedCfg, fn_service};
+
+    use super::*;
+    use crate::client::StreamingRequest;
+    use crate::server::health::{Health, HealthCheckRequest, HealthCheckResponse};
+    use crate::server::health::{HealthClient, HealthMethods};
+    use crate::server::{self, GrpcServer, MethodResponse, ResponseStream, ServerError};
+    use crate::server::{ServerRequest, ServerResponse};
+    use crate::{GrpcStatus, ServiceDef};
+
+    macro_rules! method {
+        ($name:ident, $method:literal) => {
+            struct $name;
+
+            impl MethodDef for $name {
+                const NAME: &'static str = $method;
+                const PATH: ByteString =
+                    ByteString::from_static(concat!("/test.Service/", $method));
+                type Input = HealthCheckRequest;
+                type Output = HealthCheckRequest;
+                type Request = server::Streaming<HealthCheckRequest>;
+            }
+        };
+    }
+
+    method!(CollectMethod, "Collect");
+    method!(ChatMethod, "Chat");
+
+    const CHECK: &str = "/grpc.health.v1.Health/Check";
+    const WATCH: &str = "/grpc.health.v1.Health/Watch";
+
+    #[derive(Default)]
+    struct Auth {
+        reject: Cell<bool>,
+        calls: RefCell<Vec<(String, Option<ClientError>, time::Duration)>>,
+    }
+
+    impl Interceptor for Rc<Auth> {
+        async fn request(&self, _: &str, ctx: &mut RequestContext) -> Result<(), ClientError> {
+            if self.reject.get() {
+                Err(ClientError::GrpcStatus(
+                    GrpcStatus::PermissionDenied,
+                    HeaderMap::default(),
+                ))
+            } else {
+                ctx.header(header::AUTHORIZATION, "token");
+                Ok(())
+            }
+        }
+
+        fn response(
+            &self,
+            path: &str,
+            res: Result<&HeaderMap, &Error<ClientError>>,
+            elapsed: time::Duration,
+        ) {
+            let err = res.err().map(|err| (**err).clone());
+            self.calls
+                .borrow_mut()
+                .push((path.to_string(), err, elapsed));
+        }
+    }
+
+    impl Auth {
+        fn take(&self) -> Vec<(String, Option<ClientError>, time::Duration)> {
+            self.calls.take()
+        }
+    }
+
+    /// Authorized health service, watch stream sends two messages with delay
+    async fn service(mut req: ServerRequest) -> Result<ServerResponse, ServerError> {
+        if !req.headers.contains_key(header::AUTHORIZATION) {
+            return Err(ServerError::new(
+                GrpcStatus::Unauthenticated,
+                HeaderValue::from_static("Not authorized"),
+                None,
+            ));
+        }
+        match &*req.name {
+            "Collect" => {
+                let mut stream = CollectMethod.server_request(&mut req).await?;
+                let mut count = 0;
+                while let Some(msg) = stream.recv().await {
+                    msg?;
+                    count += 1;
+                }
+                let msg = HealthCheckRequest {
+                    service: count.to_string().into(),
+                };
+                return msg.into_response(&CollectMethod);
+            }
+            "Chat" => {
+                let mut stream = ChatMethod.server_request(&mut req).await?;
+                let (tx, rx) = ResponseStream::channel();
+                ntex::rt::spawn(async move {
+                    while let Some(Ok(msg)) = stream.recv().await {
+                        if msg.service == "fail" {
+                            tx.error(ServerError::new(
+                                GrpcStatus::InvalidArgument,
+                                HeaderValue::from_static("Request is failed"),
+                                None,
+                            ));
+                            return;
+                        }
+                        let _ = tx.send(msg);
+                    }
+                });
+                return rx.into_response(&ChatMethod);
+            }
+            _ => (),
+        }
+        match Health::method_by_name(&req.name) {
+            Some(HealthMethods::Check(method)) => {
+                method.server_request(&mut req).await?;
+                HealthCheckResponse::default().into_response(&method)
+            }
+            Some(HealthMethods::Watch(method)) => {
+                method.server_request(&mut req).await?;
+                let (tx, rx) = ResponseStream::channel();
+                ntex::rt::spawn(async move {
+                    let _ = tx.send(HealthCheckResponse::default());
+                    sleep(Millis(50)).await;
+                    let _ = tx.send(HealthCheckResponse::default());
+                });
+                rx.into_response(&method)
+            }
+            None => unreachable!(),
+        }
+    }
+
+    async fn transport(port: u16, auth: &Rc<Auth>) -> InterceptService<Rc<Auth>, SimpleClient> {
+        let client = ClientBuilder::with_default(format!("127.0.0.1:{port}"))
+            .build(SharedCfg::default())
+            .await
+            .unwrap()
+            .client()
+            .await
+            .unwrap();
+        Intercept::new(auth.clone()).create(client, ())
+    }
+
+    async fn client(
+        port: u16,
+        auth: &Rc<Auth>,
+    ) -> HealthClient<InterceptService<Rc<Auth>, SimpleClient>> {
+        HealthClient::new(transport(port, auth).await)
+    }
+
+    #[ntex::test]
+    async fn unary() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let auth = Rc::new(Auth::default());
+        let client = client(srv.addr().port(), &auth).await;
+        let req = HealthCheckRequest::default();
+
+        // interceptor adds authorization header
+        client.check(&req).await.unwrap();
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, CHECK);
+        assert!(calls[0].1.is_none());
+
+        // rejected call is not sent
+        auth.reject.set(true);
+        let err = client.check(&req).await.unwrap_err().into_error();
+        assert!(matches!(
+            err,
+            ClientError::GrpcStatus(GrpcStatus::PermissionDenied, _)
+        ));
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert!(matches!(
+            calls[0].1,
+            Some(ClientError::GrpcStatus(GrpcStatus::PermissionDenied, _))
+        ));
+    }
+
+    #[ntex::test]
+    async fn server_streaming() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let auth = Rc::new(Auth::default());
+        let client = client(srv.addr().port(), &auth).await;
+        let req = HealthCheckRequest::default();
+
+        // result is reported at the end of the stream
+        let mut stream = client.watch(&req).await.unwrap();
+        assert!(stream.recv().await.unwrap().is_ok());
+        assert!(auth.take().is_empty());
+        assert!(stream.recv().await.unwrap().is_ok());
+        assert!(stream.recv().await.is_none());
+
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, WATCH);
+        assert!(calls[0].1.is_none());
+        assert!(calls[0].2 >= time::Duration::from_millis(50));
+
+        // dropped stream is reported as cancelled
+        let mut stream = client.watch(&req).await.unwrap();
+        assert!(stream.recv().await.unwrap().is_ok());
+        drop(stream);
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert!(matches!(
+            calls[0].1,
+            Some(ClientError::GrpcStatus(GrpcStatus::Cancelled, _))
+        ));
+    }
+
+    #[ntex::test]
+    async fn client_streaming() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let auth = Rc::new(Auth::default());
+        let transport = transport(srv.addr().port(), &auth).await;
+
+        let call = StreamingRequest::<_, CollectMethod, _>::client(&transport)
+            .await
+            .unwrap();
+        call.send(&HealthCheckRequest::default()).await.unwrap();
+        assert!(auth.take().is_empty());
+        assert_eq!(call.finish().await.unwrap().output.service, "1");
+
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, "/test.Service/Collect");
+        assert!(calls[0].1.is_none());
+    }
+
+    #[ntex::test]
+    async fn bidi_streaming() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let auth = Rc::new(Auth::default());
+        let transport = transport(srv.addr().port(), &auth).await;
+
+        let (sink, mut stream) = StreamingRequest::<_, ChatMethod, _>::bidi(&transport)
+            .await
+            .unwrap();
+        sink.send(&HealthCheckRequest::default()).await.unwrap();
+        assert!(stream.recv().await.unwrap().is_ok());
+        assert!(auth.take().is_empty());
+        sink.close().await.unwrap();
+        assert!(stream.recv().await.is_none());
+
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, "/test.Service/Chat");
+        assert!(calls[0].1.is_none());
+
+        // server error is reported
+        let (sink, mut stream) = StreamingRequest::<_, ChatMethod, _>::bidi(&transport)
+            .await
+            .unwrap();
+        sink.send(&HealthCheckRequest {
+            service: "fail".into(),
+        })
+        .await
+        .unwrap();
+        assert!(stream.recv().await.unwrap().is_err());
+        let calls = auth.take();
+        assert_eq!(calls.len(), 1);
+        assert!(matches!(
+            calls[0].1,
+            Some(ClientError::GrpcStatus(GrpcStatus::InvalidArgument, _))
+        ));
+    }
+}
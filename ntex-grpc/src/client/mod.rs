@@ -5,12 +5,18 @@ use ntex_error::{ErrorDiagnostic, ResultType};
 use ntex_h2::{OperationError, StreamError, client};
 use ntex_http::{HeaderMap, StatusCode, error::Error as HttpError};
 
+mod interceptor;
 mod request;
+mod streaming;
 mod transport;
 
+pub use self::interceptor::{Intercept, InterceptService, Interceptor};
 pub use self::request::{Request, RequestContext, Response};
+pub use self::streaming::{ClientStreaming, RequestSink, Streaming, StreamingRequest};
 
-use crate::{encoding::DecodeError, service::MethodDef, status::GrpcStatus};
+use crate::google_types::{ErrorDetails, Status};
+use crate::utils::FrameError;
+use crate::{consts, encoding::DecodeError, service::MethodDef, status::GrpcStatus};
 
 pub trait Transport<T: MethodDef> {
     /// Errors produced by the transport.
@@ -23,6 +29,33 @@ pub trait Transport<T: MethodDef> {
     ) -> Result<Response<T>, Self::Error>;
 }
 
+/// Transport for streaming methods
+pub trait StreamingTransport<T: MethodDef> {
+    /// Errors produced by the transport.
+    type Error;
+
+    /// Send single request message and receive stream of response messages
+    async fn server_streaming(
+        &self,
+        args: &T::Input,
+        ctx: RequestContext,
+    ) -> Result<Streaming<T>, Self::Error>;
+
+    /// Open stream of request messages and receive single response message
+    async fn client_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<ClientStreaming<T>, Self::Error>;
+
+    /// Open bidirectional stream
+    ///
+    /// Request sink and response stream are independent and could be used concurrently.
+    async fn bidi_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<(RequestSink<T>, Streaming<T>), Self::Error>;
+}
+
 /// Client utils methods
 pub trait ClientInformation<T> {
     /// Create new client instance
@@ -97,13 +130,45 @@ pub enum ClientError {
     GrpcStatus(GrpcStatus, HeaderMap),
 }
 
+impl ClientError {
+    /// Get `google.rpc.Status` from `grpc-status-details-bin` trailer
+    pub fn status_details(&self) -> Option<Result<Status, DecodeError>> {
+        match self {
+            ClientError::GrpcStatus(_, hdrs) | ClientError::DeadlineExceeded(hdrs) => hdrs
+                .get(consts::GRPC_STATUS_DETAILS)
+                .map(Status::from_header_value),
+            _ => None,
+        }
+    }
+
+    /// Get standard error details from `grpc-status-details-bin` trailer
+    pub fn error_details(&self) -> Option<Result<ErrorDetails, DecodeError>> {
+        self.status_details()
+            .map(|status| status.and_then(|status| status.error_details()))
+    }
+}
+
+impl From<FrameError> for ClientError {
+    fn from(err: FrameError) -> Self {
+        match err {
+            FrameError::Decode(err) => ClientError::Decode(err),
+            FrameError::TooLarge => {
+                ClientError::GrpcStatus(GrpcStatus::ResourceExhausted, HeaderMap::default())
+            }
+            FrameError::Uncompressed => {
+                ClientError::GrpcStatus(GrpcStatus::Internal, HeaderMap::default())
+            }
+        }
+    }
+}
+
 impl Clone for ClientError {
     fn clone(&self) -> Self {
         match self {
             Self::Client(err) => Self::Client(err.clone()),
             Self::Http(err) => Self::Http(*err),
             Self::Decode(err) => Self::Decode(err.clone()),
-            Self::Operation(err) => Self::Operation(err.clone()),
+            Self::Operation(err) => Self::Operation(*err),
             Self::Stream(err) => Self::Stream(*err),
             Self::Response(st, hdrs, payload) => {
                 Self::Response(*st, hdrs.clone(), payload.clone())
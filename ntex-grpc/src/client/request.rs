@@ -4,7 +4,8 @@ use std::{cell::Cell, convert::TryFrom, fmt, future::Future, mem, ops, pin::Pin,
 use ntex_http::{HeaderMap, HeaderName, HeaderValue, error::Error as HttpError};
 use ntex_util::future::BoxFuture;
 
-use crate::{client::Transport, consts, service::MethodDef};
+use crate::utils::DEFAULT_MAX_MESSAGE_SIZE;
+use crate::{CompressionEncoding, client::Transport, consts, service::MethodDef};
 
 pub struct RequestContext(Rc<RequestContextInner>);
 
@@ -19,16 +20,26 @@ struct RequestContextInner {
     err: Option<HttpError>,
     headers: Vec<(HeaderName, HeaderValue)>,
     timeout: Cell<Option<time::Duration>>,
+    encoding: CompressionEncoding,
+    max_message_size: usize,
     flags: Cell<Flags>,
 }
 
+impl Default for RequestContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RequestContext {
     /// Create new `RequestContext` instance
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self(Rc::new(RequestContextInner {
             err: None,
             headers: Vec::new(),
             timeout: Cell::new(None),
+            encoding: CompressionEncoding::Identity,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
             flags: Cell::new(Flags::empty()),
         }))
     }
@@ -54,6 +65,36 @@ impl RequestContext {
         self
     }
 
+    /// Get request messages compression
+    pub fn get_compression(&self) -> CompressionEncoding {
+        self.0.encoding
+    }
+
+    /// Set compression for request messages
+    ///
+    /// Default is `CompressionEncoding::Identity`.
+    pub fn compression(&mut self, encoding: CompressionEncoding) -> &mut Self {
+        if let Some(ctx) = ctx(self) {
+            ctx.encoding = encoding;
+        }
+        self
+    }
+
+    /// Get max size of response message
+    pub fn get_max_message_size(&self) -> usize {
+        self.0.max_message_size
+    }
+
+    /// Set max size of response message
+    ///
+    /// Larger responses fail with `ResourceExhausted` status. Default is 4 MiB.
+    pub fn max_message_size(&mut self, size: usize) -> &mut Self {
+        if let Some(ctx) = ctx(self) {
+            ctx.max_message_size = size;
+        }
+        self
+    }
+
     /// Disconnect connection on request drop
     pub fn disconnect_on_drop(&mut self) -> &mut Self {
         let mut flags = self.0.flags.get();
@@ -115,6 +156,8 @@ fn ctx(slf: &mut RequestContext) -> Option<&mut RequestContextInner> {
             err: None,
             headers: slf.0.headers.clone(),
             timeout: slf.0.timeout.clone(),
+            encoding: slf.0.encoding,
+            max_message_size: slf.0.max_message_size,
             flags: slf.0.flags.clone(),
         });
         Some(Rc::get_mut(&mut slf.0).unwrap())
@@ -205,6 +248,24 @@ where
         }
         self
     }
+
+    /// Set compression for request message
+    pub fn compression(&mut self, encoding: CompressionEncoding) -> &mut Self {
+        if let Some(ctx) = parts(&mut self.state) {
+            ctx.compression(encoding);
+        }
+        self
+    }
+
+    /// Use prepared request context
+    ///
+    /// Replaces headers, timeout and compression set earlier.
+    pub fn context(&mut self, context: RequestContext) -> &mut Self {
+        if let State::Request { ctx, .. } = &mut self.state {
+            *ctx = Some(context);
+        }
+        self
+    }
 }
 
 fn duration_to_grpc_timeout(duration: time::Duration) -> String {
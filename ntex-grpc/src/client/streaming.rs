@@ -0,0 +1,990 @@
+use std::task::{Context, Poll, ready};
+use std::{cell::Cell, fmt, future::Future, future::poll_fn, marker::PhantomData, pin::Pin};
+use std::{mem, time};
+
+use ntex_bytes::Bytes;
+use ntex_error::Error;
+use ntex_h2::{self as h2, frame::Reason};
+use ntex_http::{HeaderMap, HeaderName, HeaderValue, StatusCode, error::Error as HttpError};
+use ntex_util::{Stream, future::BoxFuture};
+
+use super::transport::{check_grpc_status, decode_message, encode_message, response_encoding};
+use super::{ClientError, StreamingTransport, request::RequestContext, request::Response};
+use crate::{
+    CompressionEncoding, DecodeError, GrpcStatus, consts, service::MethodDef, utils::FrameDecoder,
+};
+
+/// Callback that is called once the call is completed
+pub(super) type OnComplete = Box<dyn FnOnce(Result<&HeaderMap, &Error<ClientError>>)>;
+
+/// Stream of response messages
+pub struct Streaming<T: MethodDef> {
+    stream: h2::client::RecvStream,
+    service: &'static str,
+    status: Option<StatusCode>,
+    headers: HeaderMap,
+    trailers: HeaderMap,
+    payload: FrameDecoder,
+    encoding: CompressionEncoding,
+    eof: bool,
+    no_trailers: bool,
+    req_size: usize,
+    res_size: usize,
+    on_complete: Option<OnComplete>,
+    _t: PhantomData<T>,
+}
+
+impl<T: MethodDef> Streaming<T> {
+    pub(super) fn new(
+        stream: h2::client::RecvStream,
+        service: &'static str,
+        req_size: usize,
+        max_message_size: usize,
+    ) -> Self {
+        Self {
+            stream,
+            service,
+            req_size,
+            status: None,
+            eof: false,
+            no_trailers: false,
+            res_size: 0,
+            payload: FrameDecoder::new(max_message_size),
+            encoding: CompressionEncoding::Identity,
+            headers: HeaderMap::default(),
+            trailers: HeaderMap::default(),
+            on_complete: None,
+            _t: PhantomData,
+        }
+    }
+
+    /// Wait for response headers and create response stream
+    pub(super) async fn start(
+        stream: h2::client::RecvStream,
+        service: &'static str,
+        req_size: usize,
+        max_message_size: usize,
+    ) -> Result<Self, Error<ClientError>> {
+        let mut slf = Self::new(stream, service, req_size, max_message_size);
+
+        while slf.status.is_none() {
+            let Some(msg) = slf.stream.recv().await else {
+                return Err(
+                    Error::from(ClientError::UnexpectedEof(None, HeaderMap::default()))
+                        .set_service(service),
+                );
+            };
+            slf.handle(msg.kind).map_err(|e| e.set_service(service))?;
+        }
+        Ok(slf)
+    }
+
+    /// Set callback that is called when the stream is exhausted, failed or dropped
+    pub(super) fn on_complete(&mut self, f: OnComplete) {
+        self.on_complete = Some(f);
+    }
+
+    #[inline]
+    /// Response headers
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    #[inline]
+    /// Response trailers
+    ///
+    /// Trailers are available only after the stream is exhausted.
+    pub fn trailers(&self) -> &HeaderMap {
+        &self.trailers
+    }
+
+    #[inline]
+    /// Http response status
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    #[inline]
+    /// Check if the stream is exhausted
+    pub fn is_eof(&self) -> bool {
+        self.eof
+    }
+
+    #[inline]
+    /// Size of the request payload
+    pub fn req_size(&self) -> usize {
+        self.req_size
+    }
+
+    #[inline]
+    /// Size of the received payload
+    pub fn res_size(&self) -> usize {
+        self.res_size
+    }
+
+    /// Receive next message from the stream
+    ///
+    /// Returns `None` when the server has closed the stream with `Ok` status.
+    pub async fn recv(&mut self) -> Option<Result<T::Output, Error<ClientError>>> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Attempt to pull out the next message of the stream
+    pub fn poll_recv(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<T::Output, Error<ClientError>>>> {
+        let item = ready!(self.poll_message(cx));
+        if let Some(on_complete) = self.on_complete.take() {
+            match &item {
+                Some(Ok(_)) => self.on_complete = Some(on_complete),
+                Some(Err(err)) => on_complete(Err(err)),
+                None => on_complete(Ok(&self.headers)),
+            }
+        }
+        Poll::Ready(item)
+    }
+
+    fn poll_message(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<T::Output, Error<ClientError>>>> {
+        loop {
+            match self.decode() {
+                Ok(Some(msg)) => return Poll::Ready(Some(Ok(msg))),
+                Ok(None) => (),
+                Err(err) => {
+                    self.eof = true;
+                    self.payload.clear();
+                    return Poll::Ready(Some(Err(err.set_service(self.service))));
+                }
+            }
+            if self.eof {
+                return Poll::Ready(None);
+            }
+
+            let Some(msg) = ready!(self.stream.poll_recv(cx)) else {
+                self.eof = true;
+                return Poll::Ready(Some(Err(Error::from(ClientError::UnexpectedEof(
+                    self.status,
+                    self.headers.clone(),
+                ))
+                .set_service(self.service))));
+            };
+
+            if let Err(err) = self.handle(msg.kind) {
+                self.eof = true;
+                self.payload.clear();
+                return Poll::Ready(Some(Err(err.set_service(self.service))));
+            }
+        }
+    }
+
+    /// Handle http/2 stream message
+    fn handle(&mut self, msg: h2::MessageKind) -> Result<(), Error<ClientError>> {
+        match msg {
+            h2::MessageKind::Headers {
+                headers,
+                pseudo,
+                eof,
+            } => {
+                if self.status.is_none() {
+                    self.status = pseudo.status;
+                    if eof {
+                        // trailers-only response
+                        self.eof = true;
+                        match pseudo.status {
+                            Some(st)
+                                if !st.is_success()
+                                    && !headers.contains_key(consts::GRPC_STATUS) =>
+                            {
+                                return Err(Error::from(ClientError::Response(
+                                    Some(st),
+                                    headers,
+                                    Bytes::new(),
+                                )));
+                            }
+                            _ => self.trailers = check_trailers(headers)?,
+                        }
+                        return Ok(());
+                    }
+                    match pseudo.status {
+                        Some(st) if st.is_success() => {
+                            self.encoding = response_encoding(&headers)?;
+                            self.headers = headers;
+                        }
+                        st => {
+                            return Err(Error::from(ClientError::Response(
+                                st,
+                                headers,
+                                Bytes::new(),
+                            )));
+                        }
+                    }
+                } else if eof {
+                    self.eof = true;
+                    self.trailers = check_trailers(headers)?;
+                }
+                Ok(())
+            }
+            h2::MessageKind::Data(data, _) | h2::MessageKind::Eof(h2::StreamEof::Data(data))
+                if self.status.is_none() =>
+            {
+                Err(Error::from(ClientError::Response(
+                    None,
+                    HeaderMap::default(),
+                    data,
+                )))
+            }
+            h2::MessageKind::Data(data, _cap) => {
+                self.res_size += data.len();
+                self.payload.push(data);
+                Ok(())
+            }
+            h2::MessageKind::Eof(data) => {
+                self.eof = true;
+                match data {
+                    h2::StreamEof::Data(data) => {
+                        // stream without trailers, reported after buffered messages
+                        self.res_size += data.len();
+                        self.payload.push(data);
+                        self.no_trailers = true;
+                        Ok(())
+                    }
+                    h2::StreamEof::Trailers(hdrs) => {
+                        if self.status.is_none() {
+                            return Err(Error::from(ClientError::UnexpectedEof(None, hdrs)));
+                        }
+                        self.trailers = check_trailers(hdrs)?;
+                        Ok(())
+                    }
+                    h2::StreamEof::Error(err) => Err(err.map(ClientError::Stream)),
+                }
+            }
+            h2::MessageKind::Disconnect(err) => {
+                self.eof = true;
+                Err(err.map(ClientError::Operation))
+            }
+        }
+    }
+
+    /// Decode next complete message from the buffer
+    fn decode(&mut self) -> Result<Option<T::Output>, Error<ClientError>> {
+        if let Some(frame) = self.payload.decode().map_err(ClientError::from)? {
+            decode_message(frame, self.encoding, self.payload.max_size()).map(Some)
+        } else {
+            if self.eof {
+                self.payload.finish().map_err(ClientError::Decode)?;
+                if mem::take(&mut self.no_trailers) {
+                    return Err(Error::from(ClientError::GrpcStatus(
+                        GrpcStatus::Internal,
+                        HeaderMap::default(),
+                    )));
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+impl<T: MethodDef> Stream for Streaming<T> {
+    type Item = Result<T::Output, Error<ClientError>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+impl<T: MethodDef> Unpin for Streaming<T> {}
+
+impl<T: MethodDef> Drop for Streaming<T> {
+    fn drop(&mut self) {
+        // stream is dropped before completion
+        if let Some(on_complete) = self.on_complete.take() {
+            let err = Error::from(ClientError::GrpcStatus(
+                GrpcStatus::Cancelled,
+                HeaderMap::default(),
+            ))
+            .set_service(self.service);
+            on_complete(Err(&err));
+        }
+    }
+}
+
+impl<T: MethodDef> fmt::Debug for Streaming<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(format!("StreamingFor<{}>", T::NAME).as_str())
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("trailers", &self.trailers)
+            .field("eof", &self.eof)
+            .finish()
+    }
+}
+
+/// Sending part of the streaming request
+///
+/// Sink could be used independently from the response stream, for example
+/// from a separate task. Dropping the sink before it is closed cancels the call.
+pub struct RequestSink<T: MethodDef> {
+    stream: h2::client::SendStream,
+    service: &'static str,
+    encoding: CompressionEncoding,
+    size: Cell<usize>,
+    _t: PhantomData<T>,
+}
+
+impl<T: MethodDef> RequestSink<T> {
+    pub(super) fn new(
+        stream: h2::client::SendStream,
+        service: &'static str,
+        encoding: CompressionEncoding,
+    ) -> Self {
+        Self {
+            stream,
+            service,
+            encoding,
+            size: Cell::new(0),
+            _t: PhantomData,
+        }
+    }
+
+    /// Send request message to the peer
+    pub async fn send(&self, msg: &T::Input) -> Result<(), Error<ClientError>> {
+        let buf = encode_message(msg, self.encoding);
+        self.size.set(self.size.get() + buf.len());
+        self.stream
+            .send_payload(buf.freeze(), false)
+            .await
+            .map_err(|e| e.map(ClientError::from).set_service(self.service))
+    }
+
+    /// Close request stream
+    pub async fn close(&self) -> Result<(), Error<ClientError>> {
+        self.stream
+            .send_payload(Bytes::new(), true)
+            .await
+            .map_err(|e| e.map(ClientError::from).set_service(self.service))
+    }
+
+    /// Cancel the call
+    pub fn cancel(&self) {
+        self.stream.reset(Reason::CANCEL);
+    }
+
+    #[inline]
+    /// Size of sent payload
+    pub fn req_size(&self) -> usize {
+        self.size.get()
+    }
+}
+
+impl<T: MethodDef> fmt::Debug for RequestSink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(format!("RequestSinkFor<{}>", T::NAME).as_str())
+            .field("req_size", &self.size.get())
+            .finish()
+    }
+}
+
+/// Client streaming call
+pub struct ClientStreaming<T: MethodDef> {
+    sink: RequestSink<T>,
+    stream: Streaming<T>,
+}
+
+impl<T: MethodDef> ClientStreaming<T> {
+    pub(super) fn new(sink: RequestSink<T>, stream: Streaming<T>) -> Self {
+        Self { sink, stream }
+    }
+
+    #[inline]
+    /// Get reference to request sink
+    pub fn sink(&self) -> &RequestSink<T> {
+        &self.sink
+    }
+
+    pub(super) fn on_complete(&mut self, f: OnComplete) {
+        self.stream.on_complete(f);
+    }
+
+    #[inline]
+    /// Send request message to the peer
+    pub async fn send(&self, msg: &T::Input) -> Result<(), Error<ClientError>> {
+        self.sink.send(msg).await
+    }
+
+    /// Close request stream and wait for response
+    pub async fn finish(mut self) -> Result<Response<T>, Error<ClientError>> {
+        let on_complete = self.stream.on_complete.take();
+        let res = self.response().await;
+        if let Some(on_complete) = on_complete {
+            on_complete(res.as_ref().map(Response::headers));
+        }
+        res
+    }
+
+    async fn response(&mut self) -> Result<Response<T>, Error<ClientError>> {
+        self.sink.close().await?;
+
+        let output = match self.stream.recv().await {
+            Some(Ok(output)) => output,
+            Some(Err(err)) => return Err(err),
+            None => {
+                return Err(Error::from(ClientError::UnexpectedEof(
+                    self.stream.status,
+                    mem::take(&mut self.stream.trailers),
+                ))
+                .set_service(self.stream.service));
+            }
+        };
+        if let Some(Err(err)) = self.stream.recv().await {
+            return Err(err);
+        }
+
+        Ok(Response {
+            output,
+            req_size: self.sink.req_size(),
+            res_size: self.stream.res_size,
+            headers: mem::take(&mut self.stream.headers),
+            trailers: mem::take(&mut self.stream.trailers),
+        })
+    }
+}
+
+impl<T: MethodDef> fmt::Debug for ClientStreaming<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(format!("ClientStreamingFor<{}>", T::NAME).as_str())
+            .field("sink", &self.sink)
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+type CallFn<'a, T, M, R> = fn(
+    &'a T,
+    Option<&'a <M as MethodDef>::Input>,
+    RequestContext,
+) -> BoxFuture<'a, Result<R, <T as StreamingTransport<M>>::Error>>;
+
+/// Streaming request builder
+pub struct StreamingRequest<'a, T, M, R = Streaming<M>>
+where
+    T: StreamingTransport<M> + 'a,
+    M: MethodDef,
+{
+    transport: &'a T,
+    state: StreamingState<'a, T, M, R>,
+}
+
+enum StreamingState<'a, T, M, R>
+where
+    T: StreamingTransport<M> + 'a,
+    M: MethodDef,
+{
+    Call {
+        fut: BoxFuture<'a, Result<R, T::Error>>,
+    },
+    Request {
+        input: Option<&'a M::Input>,
+        ctx: RequestContext,
+        call: CallFn<'a, T, M, R>,
+    },
+    None,
+}
+
+impl<'a, T, M> StreamingRequest<'a, T, M>
+where
+    T: StreamingTransport<M>,
+    M: MethodDef + 'a,
+{
+    /// Server streaming request
+    pub fn new(transport: &'a T, input: &'a M::Input) -> Self {
+        Self::create(transport, Some(input), |t, input, ctx| {
+            Box::pin(t.server_streaming(input.unwrap_or_else(|| unreachable!()), ctx))
+        })
+    }
+}
+
+impl<'a, T, M> StreamingRequest<'a, T, M, ClientStreaming<M>>
+where
+    T: StreamingTransport<M>,
+    M: MethodDef + 'a,
+{
+    /// Client streaming request
+    pub fn client(transport: &'a T) -> Self {
+        Self::create(transport, None, |t, _, ctx| {
+            Box::pin(t.client_streaming(ctx))
+        })
+    }
+}
+
+impl<'a, T, M> StreamingRequest<'a, T, M, (RequestSink<M>, Streaming<M>)>
+where
+    T: StreamingTransport<M>,
+    M: MethodDef + 'a,
+{
+    /// Bidirectional streaming request
+    pub fn bidi(transport: &'a T) -> Self {
+        Self::create(transport, None, |t, _, ctx| Box::pin(t.bidi_streaming(ctx)))
+    }
+}
+
+impl<'a, T, M, R> StreamingRequest<'a, T, M, R>
+where
+    T: StreamingTransport<M>,
+    M: MethodDef,
+{
+    fn create(transport: &'a T, input: Option<&'a M::Input>, call: CallFn<'a, T, M, R>) -> Self {
+        Self {
+            transport,
+            state: StreamingState::Request {
+                input,
+                call,
+                ctx: RequestContext::new(),
+            },
+        }
+    }
+
+    /// Append a header to existing headers.
+    pub fn header<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        HeaderName: TryFrom<K>,
+        HeaderValue: TryFrom<V>,
+        <HeaderName as TryFrom<K>>::Error: Into<HttpError>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HttpError>,
+    {
+        if let StreamingState::Request { ctx, .. } = &mut self.state {
+            ctx.header(key, value);
+        }
+        self
+    }
+
+    /// Set the max duration the request is allowed to take.
+    pub fn timeout<U>(&mut self, timeout: U) -> &mut Self
+    where
+        time::Duration: From<U>,
+    {
+        if let StreamingState::Request { ctx, .. } = &mut self.state {
+            ctx.timeout(timeout);
+        }
+        self
+    }
+
+    /// Set compression for request messages
+    pub fn compression(&mut self, encoding: CompressionEncoding) -> &mut Self {
+        if let StreamingState::Request { ctx, .. } = &mut self.state {
+            ctx.compression(encoding);
+        }
+        self
+    }
+
+    /// Use prepared request context
+    ///
+    /// Replaces headers, timeout and compression set earlier.
+    pub fn context(&mut self, context: RequestContext) -> &mut Self {
+        if let StreamingState::Request { ctx, .. } = &mut self.state {
+            *ctx = context;
+        }
+        self
+    }
+}
+
+impl<'a, T, M: 'a, R> Future for StreamingRequest<'a, T, M, R>
+where
+    T: StreamingTransport<M>,
+    M: MethodDef,
+{
+    type Output = Result<R, T::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let StreamingState::Call { ref mut fut } = self.state {
+                return Pin::new(fut).poll(cx);
+            }
+
+            if let StreamingState::Request { input, ctx, call } =
+                mem::replace(&mut self.state, StreamingState::None)
+            {
+                self.state = StreamingState::Call {
+                    fut: call(self.transport, input, ctx),
+                };
+            }
+        }
+    }
+}
+
+/// Check grpc status in trailers
+///
+/// Trailers without grpc status are reported as `Internal` error.
+fn check_trailers(hdrs: HeaderMap) -> Result<HeaderMap, Error<ClientError>> {
+    match check_grpc_status(&hdrs) {
+        Some(Ok(GrpcStatus::Ok)) => Ok(hdrs),
+        None => Err(Error::from(ClientError::GrpcStatus(
+            GrpcStatus::Internal,
+            hdrs,
+        ))),
+        Some(Ok(GrpcStatus::DeadlineExceeded)) => {
+            Err(Error::from(ClientError::DeadlineExceeded(hdrs)))
+        }
+        Some(Ok(st)) => Err(Error::from(ClientError::GrpcStatus(st, hdrs))),
+        Some(Err(())) => Err(Error::from(ClientError::Decode(DecodeError::new(
+            "Cannot parse grpc status",
+        )))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex_bytes::ByteString;
+    use ntex_h2::client::{ClientBuilder, SimpleClient};
+    use ntex_service::{cfg::SharedCfg, fn_service};
+
+    use super::*;
+    use crate::server::health::{HealthCheckRequest, HealthCheckResponse, HealthClient};
+    use crate::server::{self, GrpcServer, MethodResponse, ServerError, ServerRequest};
+    use crate::server::{ResponseStream, ServerResponse};
+
+    macro_rules! method {
+        ($name:ident, $method:literal, $req:ty) => {
+            struct $name;
+
+            impl MethodDef for $name {
+                const NAME: &'static str = $method;
+                const PATH: ByteString =
+                    ByteString::from_static(concat!("/test.Service/", $method));
+                type Input = HealthCheckRequest;
+                type Output = HealthCheckRequest;
+                type Request = $req;
+            }
+        };
+    }
+
+    method!(WatchMethod, "Watch", HealthCheckRequest);
+    method!(
+        CollectMethod,
+        "Collect",
+        server::Streaming<HealthCheckRequest>
+    );
+    method!(ChatMethod, "Chat", server::Streaming<HealthCheckRequest>);
+
+    fn msg(service: &str) -> HealthCheckRequest {
+        HealthCheckRequest {
+            service: service.into(),
+        }
+    }
+
+    fn failure() -> ServerError {
+        ServerError::new(
+            GrpcStatus::InvalidArgument,
+            HeaderValue::from_static("Request is failed"),
+            None,
+        )
+    }
+
+    /// Test service for streaming methods
+    ///
+    /// `fail` request message fails the call.
+    async fn service(mut req: ServerRequest) -> Result<ServerResponse, ServerError> {
+        match &*req.name {
+            "Watch" => {
+                let input = WatchMethod.server_request(&mut req).await?;
+                let (tx, rx) = ResponseStream::channel();
+                for idx in 0..3 {
+                    let _ = tx.send(msg(&format!("{}-{idx}", input.service)));
+                }
+                if input.service == "fail" {
+                    tx.error(failure());
+                }
+                rx.header(
+                    HeaderName::from_static("x-trailer"),
+                    HeaderValue::from_static("watch"),
+                )
+                .into_response(&WatchMethod)
+            }
+            "Collect" => {
+                let mut stream = CollectMethod.server_request(&mut req).await?;
+                let mut names = Vec::new();
+                while let Some(input) = stream.recv().await {
+                    let input = input?;
+                    if input.service == "fail" {
+                        return Err(failure());
+                    }
+                    names.push(input.service.to_string());
+                }
+                let mut res = server::Response::new(msg(&names.join(",")));
+                res.headers.push((
+                    HeaderName::from_static("x-trailer"),
+                    HeaderValue::from_static("collect"),
+                ));
+                res.into_response(&CollectMethod)
+            }
+            _ => {
+                let mut stream = ChatMethod.server_request(&mut req).await?;
+                let (tx, rx) = ResponseStream::channel();
+                ntex::rt::spawn(async move {
+                    while let Some(input) = stream.recv().await {
+                        match input {
+                            Ok(input) if input.service == "fail" => {
+                                tx.error(failure());
+                                return;
+                            }
+                            Ok(input) => {
+                                let _ = tx.send(input);
+                            }
+                            Err(err) => {
+                                tx.error(err);
+                                return;
+                            }
+                        }
+                    }
+                });
+                rx.into_response(&ChatMethod)
+            }
+        }
+    }
+
+    async fn simple_client(port: u16) -> SimpleClient {
+        ClientBuilder::with_default(format!("127.0.0.1:{port}"))
+            .build(SharedCfg::default())
+            .await
+            .unwrap()
+            .client()
+            .await
+            .unwrap()
+    }
+
+    /// Reply with malformed response selected by `x-case` request header
+    async fn respond(msg: h2::Message) -> Result<(), h2::StreamError> {
+        let h2::MessageKind::Headers { headers, .. } = msg.kind else {
+            return Ok(());
+        };
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(consts::GRPC_STATUS, GrpcStatus::Ok.into());
+
+        let payload = encode_message(
+            &HealthCheckResponse::default(),
+            CompressionEncoding::Identity,
+        );
+
+        match headers.get("x-case").and_then(|v| v.to_str().ok()) {
+            Some("no-status") => {
+                msg.stream
+                    .send_response(StatusCode::OK, HeaderMap::default(), false)
+                    .unwrap();
+                let _ = msg.stream.send_payload(payload.freeze(), false).await;
+                msg.stream.send_trailers(HeaderMap::default());
+            }
+            Some("unavailable") => {
+                msg.stream
+                    .send_response(StatusCode::SERVICE_UNAVAILABLE, HeaderMap::default(), true)
+                    .unwrap();
+            }
+            Some("no-trailers") => {
+                msg.stream
+                    .send_response(StatusCode::OK, HeaderMap::default(), false)
+                    .unwrap();
+                let _ = msg.stream.send_payload(payload.freeze(), true).await;
+            }
+            _ => {
+                msg.stream
+                    .send_response(StatusCode::OK, hdrs, true)
+                    .unwrap();
+            }
+        }
+        Ok(())
+    }
+
+    async fn client(port: u16) -> HealthClient<SimpleClient> {
+        HealthClient::new(simple_client(port).await)
+    }
+
+    /// Collect response stream, returns number of messages and stream error
+    async fn watch(
+        client: &HealthClient<SimpleClient>,
+        case: &'static str,
+    ) -> (usize, Option<ClientError>) {
+        let req = HealthCheckRequest::default();
+        let mut stream = match client.watch(&req).header("x-case", case).await {
+            Ok(stream) => stream,
+            Err(err) => return (0, Some(err.into_error())),
+        };
+        let mut count = 0;
+        while let Some(msg) = stream.recv().await {
+            match msg {
+                Ok(_) => count += 1,
+                Err(err) => return (count, Some(err.into_error())),
+            }
+        }
+        (count, None)
+    }
+
+    #[ntex::test]
+    async fn response_errors() {
+        let srv = ntex::server::test_server(async || h2::server::Server::new(fn_service(respond)));
+        let client = client(srv.addr().port()).await;
+
+        // trailers-only response with ok status
+        let (count, err) = watch(&client, "ok").await;
+        assert_eq!(count, 0);
+        assert!(err.is_none());
+
+        // trailers without grpc status
+        let (count, err) = watch(&client, "no-status").await;
+        assert_eq!(count, 1);
+        assert!(matches!(
+            err,
+            Some(ClientError::GrpcStatus(GrpcStatus::Internal, _))
+        ));
+
+        // trailers-only response with http error status
+        let (count, err) = watch(&client, "unavailable").await;
+        assert_eq!(count, 0);
+        assert!(matches!(
+            err,
+            Some(ClientError::Response(
+                Some(StatusCode::SERVICE_UNAVAILABLE),
+                ..
+            ))
+        ));
+
+        // stream is closed without trailers
+        let (count, err) = watch(&client, "no-trailers").await;
+        assert_eq!(count, 1);
+        assert!(matches!(
+            err,
+            Some(ClientError::GrpcStatus(GrpcStatus::Internal, _))
+        ));
+    }
+
+    #[ntex::test]
+    async fn server_streaming() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let client = simple_client(srv.addr().port()).await;
+
+        let input = msg("test");
+        let mut stream = StreamingRequest::<_, WatchMethod>::new(&client, &input)
+            .await
+            .unwrap();
+        let mut names = Vec::new();
+        while let Some(output) = stream.recv().await {
+            names.push(output.unwrap().service.to_string());
+        }
+        assert_eq!(names, ["test-0", "test-1", "test-2"]);
+        assert!(stream.is_eof());
+        assert_eq!(
+            stream.trailers().get("x-trailer"),
+            Some(&HeaderValue::from_static("watch"))
+        );
+
+        // error status after messages
+        let input = msg("fail");
+        let mut stream = StreamingRequest::<_, WatchMethod>::new(&client, &input)
+            .await
+            .unwrap();
+        let mut count = 0;
+        let err = loop {
+            match stream.recv().await.unwrap() {
+                Ok(_) => count += 1,
+                Err(err) => break err.into_error(),
+            }
+        };
+        assert_eq!(count, 3);
+        assert!(matches!(
+            err,
+            ClientError::GrpcStatus(GrpcStatus::InvalidArgument, _)
+        ));
+        assert!(stream.recv().await.is_none());
+
+        // early close, response stream is dropped after first message
+        let input = msg("test");
+        let mut stream = StreamingRequest::<_, WatchMethod>::new(&client, &input)
+            .await
+            .unwrap();
+        assert!(stream.recv().await.unwrap().is_ok());
+        drop(stream);
+
+        let mut stream = StreamingRequest::<_, WatchMethod>::new(&client, &input)
+            .await
+            .unwrap();
+        assert_eq!(stream.recv().await.unwrap().unwrap().service, "test-0");
+    }
+
+    #[ntex::test]
+    async fn client_streaming() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let client = simple_client(srv.addr().port()).await;
+
+        let call = StreamingRequest::<_, CollectMethod, _>::client(&client)
+            .await
+            .unwrap();
+        for name in ["a", "b", "c"] {
+            call.send(&msg(name)).await.unwrap();
+        }
+        let res = call.finish().await.unwrap();
+        assert_eq!(res.output.service, "a,b,c");
+        assert_eq!(
+            res.trailers().get("x-trailer"),
+            Some(&HeaderValue::from_static("collect"))
+        );
+
+        // empty request stream
+        let call = StreamingRequest::<_, CollectMethod, _>::client(&client)
+            .await
+            .unwrap();
+        assert_eq!(call.finish().await.unwrap().output.service, "");
+
+        // server fails the call before request stream is closed
+        let call = StreamingRequest::<_, CollectMethod, _>::client(&client)
+            .await
+            .unwrap();
+        call.send(&msg("a")).await.unwrap();
+        call.send(&msg("fail")).await.unwrap();
+        let err = call.finish().await.unwrap_err().into_error();
+        assert!(
+            matches!(err, ClientError::GrpcStatus(GrpcStatus::InvalidArgument, _)),
+            "{err:?}"
+        );
+    }
+
+    #[ntex::test]
+    async fn bidi_streaming() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(service)));
+        let client = simple_client(srv.addr().port()).await;
+
+        let (sink, mut stream) = StreamingRequest::<_, ChatMethod, _>::bidi(&client)
+            .await
+            .unwrap();
+        for name in ["a", "b"] {
+            sink.send(&msg(name)).await.unwrap();
+            assert_eq!(stream.recv().await.unwrap().unwrap().service, name);
+        }
+        sink.close().await.unwrap();
+        assert!(stream.recv().await.is_none());
+        assert_eq!(
+            stream.trailers().get(consts::GRPC_STATUS),
+            Some(&HeaderValue::from_static("0"))
+        );
+
+        // error status in trailers
+        let (sink, mut stream) = StreamingRequest::<_, ChatMethod, _>::bidi(&client)
+            .await
+            .unwrap();
+        sink.send(&msg("fail")).await.unwrap();
+        let err = stream.recv().await.unwrap().unwrap_err().into_error();
+        assert!(matches!(
+            err,
+            ClientError::GrpcStatus(GrpcStatus::InvalidArgument, _)
+        ));
+
+        // early close, client cancels the call
+        let (sink, mut stream) = StreamingRequest::<_, ChatMethod, _>::bidi(&client)
+            .await
+            .unwrap();
+        sink.send(&msg("a")).await.unwrap();
+        assert_eq!(stream.recv().await.unwrap().unwrap().service, "a");
+        sink.cancel();
+        assert!(stream.recv().await.unwrap().is_err());
+        assert!(stream.recv().await.is_none());
+    }
+}
@@ -1,12 +1,15 @@
 use std::{convert::TryFrom, str::FromStr};
 
-use ntex_bytes::{Buf, BufMut, BytesMut};
+use ntex_bytes::{BufMut, BytesMut};
 use ntex_error::Error;
 use ntex_h2::{self as h2};
 use ntex_http::{HeaderMap, Method, header};
 
-use super::{Client, ClientError, Transport, request::RequestContext, request::Response};
-use crate::{DecodeError, GrpcStatus, Message, consts, service::MethodDef, utils::Data};
+use super::streaming::{ClientStreaming, RequestSink, Streaming};
+use super::{Client, ClientError, StreamingTransport, Transport};
+use super::{request::RequestContext, request::Response};
+use crate::utils::{Frame, FrameDecoder, encode_frame};
+use crate::{CompressionEncoding, DecodeError, GrpcStatus, Message, consts, service::MethodDef};
 
 impl<T: MethodDef> Transport<T> for Client {
     type Error = Error<ClientError>;
@@ -48,26 +51,12 @@ impl<T: MethodDef> Transport<T> for h2::client::SimpleClient {
         val: &T::Input,
         ctx: RequestContext,
     ) -> Result<Response<T>, Self::Error> {
-        let len = val.encoded_len();
-        let mut buf = BytesMut::with_capacity(len + 5);
-        buf.put_u8(0); // compression
-        buf.put_u32(len as u32); // length
-        val.write(&mut buf);
+        let buf = encode_message(val, ctx.get_compression());
         let req_size = buf.len();
 
-        let mut hdrs = HeaderMap::new();
-        hdrs.append(header::CONTENT_TYPE, consts::HDRV_CT_GRPC);
-        hdrs.append(header::USER_AGENT, consts::HDRV_USER_AGENT);
-        hdrs.insert(header::TE, consts::HDRV_TRAILERS);
-        hdrs.insert(consts::GRPC_ENCODING, consts::IDENTITY);
-        hdrs.insert(consts::GRPC_ACCEPT_ENCODING, consts::IDENTITY);
-        for (key, val) in ctx.headers() {
-            hdrs.insert(key.clone(), val.clone());
-        }
-
         // send request
         let (snd_stream, rcv_stream) = self
-            .send(Method::POST, T::PATH, hdrs, false)
+            .send(Method::POST, T::PATH, request_headers(&ctx), false)
             .await
             .map_err(|e| e.map(ClientError::from))?;
         if ctx.get_disconnect_on_drop() {
@@ -82,7 +71,8 @@ impl<T: MethodDef> Transport<T> for h2::client::SimpleClient {
         let mut status = None;
         let mut hdrs = HeaderMap::default();
         let mut trailers = HeaderMap::default();
-        let mut payload = Data::Empty;
+        let mut payload = FrameDecoder::new(ctx.get_max_message_size());
+        let mut received = 0;
 
         async {
             loop {
@@ -102,19 +92,17 @@ impl<T: MethodDef> Transport<T> for h2::client::SimpleClient {
                                 Some(Ok(GrpcStatus::DeadlineExceeded)) => {
                                     return Err(Error::from(ClientError::DeadlineExceeded(hdrs)));
                                 }
-                                Some(Ok(status)) => {
-                                    if status != GrpcStatus::Ok {
-                                        return Err(Error::from(ClientError::GrpcStatus(
-                                            status, headers,
-                                        )));
-                                    }
+                                Some(Ok(status)) if status != GrpcStatus::Ok => {
+                                    return Err(Error::from(ClientError::GrpcStatus(
+                                        status, headers,
+                                    )));
                                 }
                                 Some(Err(())) => {
                                     return Err(Error::from(ClientError::Decode(
                                         DecodeError::new("Cannot parse grpc status"),
                                     )));
                                 }
-                                None => {}
+                                Some(Ok(_)) | None => {}
                             }
 
                             return Err(Error::from(ClientError::UnexpectedEof(
@@ -127,12 +115,14 @@ impl<T: MethodDef> Transport<T> for h2::client::SimpleClient {
                         continue;
                     }
                     h2::MessageKind::Data(data, _cap) => {
+                        received += data.len();
                         payload.push(data);
                         continue;
                     }
                     h2::MessageKind::Eof(data) => {
                         match data {
                             h2::StreamEof::Data(data) => {
+                                received += data.len();
                                 payload.push(data);
                             }
                             h2::StreamEof::Trailers(hdrs) => {
@@ -165,31 +155,45 @@ impl<T: MethodDef> Transport<T> for h2::client::SimpleClient {
                     }
                 }
 
-                let mut data = payload.get();
                 match status {
                     Some(st) => {
                         if !st.is_success() {
-                            return Err(Error::from(ClientError::Response(Some(st), hdrs, data)));
+                            return Err(Error::from(ClientError::Response(
+                                Some(st),
+                                hdrs,
+                                payload.take(),
+                            )));
                         }
                     }
-                    None => return Err(Error::from(ClientError::Response(None, hdrs, data))),
+                    None => {
+                        return Err(Error::from(ClientError::Response(
+                            None,
+                            hdrs,
+                            payload.take(),
+                        )));
+                    }
                 }
-                let _compressed = data.get_u8();
-                let len = data.get_u32();
-                let Some(mut block) = data.split_to_checked(len as usize) else {
-                    return Err(Error::from(ClientError::UnexpectedEof(None, hdrs)));
+                let Some(frame) = payload.decode().map_err(ClientError::from)? else {
+                    payload.finish().map_err(ClientError::Decode)?;
+                    return Err(Error::from(ClientError::UnexpectedEof(status, hdrs)));
                 };
+                let output = decode_message(frame, response_encoding(&hdrs)?, payload.max_size())?;
 
-                return match <T::Output as Message>::read(&mut block) {
-                    Ok(output) => Ok(Response {
-                        output,
-                        trailers,
-                        req_size,
-                        headers: hdrs,
-                        res_size: data.len(),
-                    }),
-                    Err(e) => Err(Error::from(ClientError::Decode(e))),
-                };
+                // unary response must contain exactly one message
+                if payload.decode().map_err(ClientError::from)?.is_some() {
+                    return Err(Error::from(ClientError::Decode(DecodeError::new(
+                        "Unexpected response message",
+                    ))));
+                }
+                payload.finish().map_err(ClientError::Decode)?;
+
+                return Ok(Response {
+                    output,
+                    trailers,
+                    req_size,
+                    res_size: received,
+                    headers: hdrs,
+                });
             }
         }
         .await
@@ -197,7 +201,198 @@ impl<T: MethodDef> Transport<T> for h2::client::SimpleClient {
     }
 }
 
-fn check_grpc_status(hdrs: &HeaderMap) -> Option<Result<GrpcStatus, ()>> {
+impl<T: MethodDef> StreamingTransport<T> for Client {
+    type Error = Error<ClientError>;
+
+    #[inline]
+    async fn server_streaming(
+        &self,
+        val: &T::Input,
+        ctx: RequestContext,
+    ) -> Result<Streaming<T>, Self::Error> {
+        StreamingTransport::server_streaming(&self.0, val, ctx).await
+    }
+
+    #[inline]
+    async fn client_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<ClientStreaming<T>, Self::Error> {
+        StreamingTransport::client_streaming(&self.0, ctx).await
+    }
+
+    #[inline]
+    async fn bidi_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<(RequestSink<T>, Streaming<T>), Self::Error> {
+        StreamingTransport::bidi_streaming(&self.0, ctx).await
+    }
+}
+
+impl<T: MethodDef> StreamingTransport<T> for h2::client::Client {
+    type Error = Error<ClientError>;
+
+    #[inline]
+    async fn server_streaming(
+        &self,
+        val: &T::Input,
+        ctx: RequestContext,
+    ) -> Result<Streaming<T>, Self::Error> {
+        StreamingTransport::server_streaming(
+            &self.client().await.map_err(|e| e.map(ClientError::from))?,
+            val,
+            ctx,
+        )
+        .await
+    }
+
+    #[inline]
+    async fn client_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<ClientStreaming<T>, Self::Error> {
+        StreamingTransport::client_streaming(
+            &self.client().await.map_err(|e| e.map(ClientError::from))?,
+            ctx,
+        )
+        .await
+    }
+
+    #[inline]
+    async fn bidi_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<(RequestSink<T>, Streaming<T>), Self::Error> {
+        StreamingTransport::bidi_streaming(
+            &self.client().await.map_err(|e| e.map(ClientError::from))?,
+            ctx,
+        )
+        .await
+    }
+}
+
+impl<T: MethodDef> StreamingTransport<T> for h2::client::SimpleClient {
+    type Error = Error<ClientError>;
+
+    async fn server_streaming(
+        &self,
+        val: &T::Input,
+        ctx: RequestContext,
+    ) -> Result<Streaming<T>, Self::Error> {
+        let buf = encode_message(val, ctx.get_compression());
+        let req_size = buf.len();
+
+        // send request
+        let (snd_stream, rcv_stream) = self
+            .send(Method::POST, T::PATH, request_headers(&ctx), false)
+            .await
+            .map_err(|e| e.map(ClientError::from).set_service(self.service()))?;
+        if ctx.get_disconnect_on_drop() {
+            snd_stream.disconnect_on_drop();
+        }
+        snd_stream
+            .send_payload(buf.freeze(), true)
+            .await
+            .map_err(|e| e.map(ClientError::from).set_service(self.service()))?;
+
+        Streaming::start(
+            rcv_stream,
+            self.service(),
+            req_size,
+            ctx.get_max_message_size(),
+        )
+        .await
+    }
+
+    async fn client_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<ClientStreaming<T>, Self::Error> {
+        let (sink, stream) = StreamingTransport::bidi_streaming(self, ctx).await?;
+        Ok(ClientStreaming::new(sink, stream))
+    }
+
+    async fn bidi_streaming(
+        &self,
+        ctx: RequestContext,
+    ) -> Result<(RequestSink<T>, Streaming<T>), Self::Error> {
+        let (snd_stream, rcv_stream) = self
+            .send(Method::POST, T::PATH, request_headers(&ctx), false)
+            .await
+            .map_err(|e| e.map(ClientError::from).set_service(self.service()))?;
+        if ctx.get_disconnect_on_drop() {
+            snd_stream.disconnect_on_drop();
+        }
+
+        Ok((
+            RequestSink::new(snd_stream, self.service(), ctx.get_compression()),
+            Streaming::new(rcv_stream, self.service(), 0, ctx.get_max_message_size()),
+        ))
+    }
+}
+
+/// Decode response message from the frame
+pub(super) fn decode_message<M: Message>(
+    frame: Frame,
+    encoding: CompressionEncoding,
+    max_size: usize,
+) -> Result<M, Error<ClientError>> {
+    let mut payload = if frame.compressed {
+        encoding
+            .decompress(&frame.payload, max_size)
+            .map_err(ClientError::from)?
+    } else {
+        frame.payload
+    };
+    M::read(&mut payload).map_err(|e| Error::from(ClientError::Decode(e)))
+}
+
+/// Encode length-prefixed message
+pub(super) fn encode_message<M: Message>(val: &M, encoding: CompressionEncoding) -> BytesMut {
+    let len = val.encoded_len();
+    if encoding == CompressionEncoding::Identity {
+        let mut buf = BytesMut::with_capacity(len + 5);
+        buf.put_u8(0); // compression
+        buf.put_u32(len as u32); // length
+        val.write(&mut buf);
+        buf
+    } else {
+        let mut msg = BytesMut::with_capacity(len);
+        val.write(&mut msg);
+        let mut buf = BytesMut::new();
+        encode_frame(&msg, encoding, &mut buf);
+        buf
+    }
+}
+
+/// Get encoding of response messages
+pub(super) fn response_encoding(hdrs: &HeaderMap) -> Result<CompressionEncoding, ClientError> {
+    CompressionEncoding::from_headers(hdrs).map_err(|name| {
+        ClientError::Decode(DecodeError::new(format!(
+            "Unsupported response encoding: {name}"
+        )))
+    })
+}
+
+/// Build grpc request headers
+fn request_headers(ctx: &RequestContext) -> HeaderMap {
+    let mut hdrs = HeaderMap::new();
+    hdrs.append(header::CONTENT_TYPE, consts::HDRV_CT_GRPC);
+    hdrs.append(header::USER_AGENT, consts::HDRV_USER_AGENT);
+    hdrs.insert(header::TE, consts::HDRV_TRAILERS);
+    hdrs.insert(consts::GRPC_ENCODING, ctx.get_compression().header_value());
+    hdrs.insert(
+        consts::GRPC_ACCEPT_ENCODING,
+        CompressionEncoding::accept_encoding(),
+    );
+    for (key, val) in ctx.headers() {
+        hdrs.insert(key.clone(), val.clone());
+    }
+    hdrs
+}
+
+pub(super) fn check_grpc_status(hdrs: &HeaderMap) -> Option<Result<GrpcStatus, ()>> {
     // check grpc status
     if let Some(val) = hdrs.get(consts::GRPC_STATUS) {
         if let Ok(status) = val
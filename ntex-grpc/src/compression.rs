@@ -0,0 +1,275 @@
+#![allow(clippy::declare_interior_mutable_const)]
+use ntex_bytes::{Bytes, BytesMut};
+use ntex_http::{HeaderMap, HeaderValue};
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "zstd"))]
+use crate::DecodeError;
+use crate::{consts, utils::FrameError};
+
+/// Message compression algorithm
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CompressionEncoding {
+    /// No compression
+    #[default]
+    Identity,
+    #[cfg(feature = "gzip")]
+    /// Gzip compression
+    Gzip,
+    #[cfg(feature = "deflate")]
+    /// Deflate compression
+    Deflate,
+    #[cfg(feature = "zstd")]
+    /// Zstd compression
+    Zstd,
+}
+
+const ENCODINGS: &[CompressionEncoding] = &[
+    #[cfg(feature = "gzip")]
+    CompressionEncoding::Gzip,
+    #[cfg(feature = "deflate")]
+    CompressionEncoding::Deflate,
+    #[cfg(feature = "zstd")]
+    CompressionEncoding::Zstd,
+    CompressionEncoding::Identity,
+];
+
+impl CompressionEncoding {
+    /// Name of the encoding
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            CompressionEncoding::Identity => "identity",
+            #[cfg(feature = "gzip")]
+            CompressionEncoding::Gzip => "gzip",
+            #[cfg(feature = "deflate")]
+            CompressionEncoding::Deflate => "deflate",
+            #[cfg(feature = "zstd")]
+            CompressionEncoding::Zstd => "zstd",
+        }
+    }
+
+    /// Find supported encoding by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        ENCODINGS
+            .iter()
+            .find(|enc| enc.as_str().eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Get encoding from `grpc-encoding` header
+    ///
+    /// Returns `Err` with encoding name if encoding is not supported.
+    pub(crate) fn from_headers(hdrs: &HeaderMap) -> Result<Self, String> {
+        match hdrs.get(consts::GRPC_ENCODING) {
+            Some(val) => {
+                let name = val.to_str().unwrap_or_default();
+                Self::from_name(name).ok_or_else(|| name.to_string())
+            }
+            None => Ok(CompressionEncoding::Identity),
+        }
+    }
+
+    /// Select encoding accepted by the peer
+    ///
+    /// Returns `Identity` if encoding is not listed in `grpc-accept-encoding` header.
+    pub(crate) fn accepted(self, hdrs: &HeaderMap) -> Self {
+        if self != CompressionEncoding::Identity {
+            for val in hdrs.get_all(consts::GRPC_ACCEPT_ENCODING) {
+                if let Ok(val) = val.to_str()
+                    && val
+                        .split(',')
+                        .any(|name| Self::from_name(name) == Some(self))
+                {
+                    return self;
+                }
+            }
+        }
+        CompressionEncoding::Identity
+    }
+
+    /// `grpc-accept-encoding` header value with all supported encodings
+    pub(crate) fn accept_encoding() -> HeaderValue {
+        const ACCEPT: HeaderValue = HeaderValue::from_static(accept_list());
+        ACCEPT
+    }
+
+    pub(crate) fn header_value(self) -> HeaderValue {
+        HeaderValue::from_static(self.as_str())
+    }
+
+    /// Compress message and append it to the buffer
+    #[allow(unused_variables)]
+    pub(crate) fn compress(self, data: &[u8], buf: &mut BytesMut) {
+        match self {
+            CompressionEncoding::Identity => buf.extend_from_slice(data),
+            #[cfg(feature = "gzip")]
+            CompressionEncoding::Gzip => {
+                use std::io::Write;
+
+                let mut enc =
+                    flate2::write::GzEncoder::new(Writer(buf), flate2::Compression::default());
+                enc.write_all(data)
+                    .and_then(|()| enc.finish().map(|_| ()))
+                    .expect("Writing to buffer cannot fail");
+            }
+            #[cfg(feature = "deflate")]
+            CompressionEncoding::Deflate => {
+                use std::io::Write;
+
+                let mut enc =
+                    flate2::write::ZlibEncoder::new(Writer(buf), flate2::Compression::default());
+                enc.write_all(data)
+                    .and_then(|()| enc.finish().map(|_| ()))
+                    .expect("Writing to buffer cannot fail");
+            }
+            #[cfg(feature = "zstd")]
+            CompressionEncoding::Zstd => {
+                zstd::stream::copy_encode(data, Writer(buf), zstd::DEFAULT_COMPRESSION_LEVEL)
+                    .expect("Writing to buffer cannot fail");
+            }
+        }
+    }
+
+    /// Decompress message
+    ///
+    /// Fails with `FrameError::TooLarge` if decompressed message is larger than `max_size`.
+    #[allow(unused_variables)]
+    pub(crate) fn decompress(self, data: &[u8], max_size: usize) -> Result<Bytes, FrameError> {
+        match self {
+            CompressionEncoding::Identity => Err(FrameError::Uncompressed),
+            #[cfg(feature = "gzip")]
+            CompressionEncoding::Gzip => read_limited(
+                flate2::read::GzDecoder::new(data),
+                max_size,
+                "Cannot decompress gzip message",
+            ),
+            #[cfg(feature = "deflate")]
+            CompressionEncoding::Deflate => read_limited(
+                flate2::read::ZlibDecoder::new(data),
+                max_size,
+                "Cannot decompress deflate message",
+            ),
+            #[cfg(feature = "zstd")]
+            CompressionEncoding::Zstd => read_limited(
+                zstd::stream::read::Decoder::new(data)
+                    .map_err(|_| DecodeError::new("Cannot decompress zstd message"))?,
+                max_size,
+                "Cannot decompress zstd message",
+            ),
+        }
+    }
+}
+
+/// Read decompressed data, stops reading after `max_size` bytes
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "zstd"))]
+fn read_limited<R: std::io::Read>(
+    reader: R,
+    max_size: usize,
+    error: &'static str,
+) -> Result<Bytes, FrameError> {
+    let mut buf = BytesMut::new();
+    std::io::copy(&mut reader.take(max_size as u64 + 1), &mut Writer(&mut buf))
+        .map_err(|_| DecodeError::new(error))?;
+    if buf.len() > max_size {
+        Err(FrameError::TooLarge)
+    } else {
+        Ok(buf.freeze())
+    }
+}
+
+/// Comma separated list of supported encodings
+const fn accept_list() -> &'static str {
+    match (
+        cfg!(feature = "gzip"),
+        cfg!(feature = "deflate"),
+        cfg!(feature = "zstd"),
+    ) {
+        (true, true, true) => "gzip,deflate,zstd,identity",
+        (true, true, false) => "gzip,deflate,identity",
+        (true, false, true) => "gzip,zstd,identity",
+        (true, false, false) => "gzip,identity",
+        (false, true, true) => "deflate,zstd,identity",
+        (false, true, false) => "deflate,identity",
+        (false, false, true) => "zstd,identity",
+        (false, false, false) => "identity",
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "zstd"))]
+struct Writer<'a>(&'a mut BytesMut);
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "zstd"))]
+impl std::io::Write for Writer<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding_names() {
+        assert_eq!(
+            CompressionEncoding::from_name("identity"),
+            Some(CompressionEncoding::Identity)
+        );
+        assert_eq!(CompressionEncoding::from_name("snappy"), None);
+        assert!(accept_list().ends_with("identity"));
+    }
+
+    #[test]
+    fn decompress_identity() {
+        let err = CompressionEncoding::Identity
+            .decompress(b"data", 1024)
+            .unwrap_err();
+        let err = crate::server::ServerError::from(err);
+        assert_eq!(err.status, crate::GrpcStatus::Internal);
+    }
+
+    #[test]
+    #[cfg(any(feature = "gzip", feature = "deflate", feature = "zstd"))]
+    fn roundtrip() {
+        let data = b"hello world hello world hello world".repeat(10);
+        for enc in ENCODINGS {
+            if *enc == CompressionEncoding::Identity {
+                continue;
+            }
+            let mut buf = BytesMut::new();
+            enc.compress(&data, &mut buf);
+            assert!(buf.len() < data.len());
+            assert_eq!(
+                enc.decompress(&buf, data.len()).unwrap(),
+                Bytes::from(data.clone())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "gzip", feature = "deflate", feature = "zstd"))]
+    fn decompress_max_size() {
+        let data = vec![0; 1024 * 1024];
+        for enc in ENCODINGS {
+            if *enc == CompressionEncoding::Identity {
+                continue;
+            }
+            let mut buf = BytesMut::new();
+            enc.compress(&data, &mut buf);
+            assert!(buf.len() < 8 * 1024);
+            assert!(matches!(
+                enc.decompress(&buf, data.len() - 1),
+                Err(FrameError::TooLarge)
+            ));
+            assert!(matches!(
+                enc.decompress(&buf[..buf.len() / 2], data.len()),
+                Err(FrameError::Decode(_))
+            ));
+        }
+    }
+}
@@ -7,9 +7,9 @@ pub(crate) const HDRV_TRAILERS: HeaderValue = HeaderValue::from_static("trailers
 
 pub const GRPC_STATUS: HeaderName = HeaderName::from_static("grpc-status");
 pub const GRPC_MESSAGE: HeaderName = HeaderName::from_static("grpc-message");
+pub const GRPC_STATUS_DETAILS: HeaderName = HeaderName::from_static("grpc-status-details-bin");
 
 pub(crate) const GRPC_TIMEOUT: HeaderName = HeaderName::from_static("grpc-timeout");
 pub(crate) const GRPC_ENCODING: HeaderName = HeaderName::from_static("grpc-encoding");
 pub(crate) const GRPC_ACCEPT_ENCODING: HeaderName =
     HeaderName::from_static("grpc-accept-encoding");
-pub(crate) const IDENTITY: HeaderValue = HeaderValue::from_static("identity");
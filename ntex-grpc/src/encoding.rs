@@ -276,6 +276,37 @@ pub fn skip_field(wire_type: WireType, tag: u32, buf: &mut Bytes) -> Result<(),
     Ok(())
 }
 
+/// Decodes group content, input buffer is advanced past end group key
+pub fn decode_group(tag: u32, buf: &mut Bytes) -> Result<Bytes, DecodeError> {
+    let group = buf.clone();
+    loop {
+        let len = group.len() - buf.len();
+        let (inner_tag, inner_wire_type) = decode_key(buf)?;
+        if inner_wire_type == WireType::EndGroup {
+            if inner_tag != tag {
+                return Err(DecodeError::new("unexpected end group tag"));
+            }
+            return Ok(group.slice(..len));
+        }
+        skip_field(inner_wire_type, inner_tag, buf)?;
+    }
+}
+
+/// Skips field and appends its key and value to the unknown fields buffer
+pub fn skip_unknown_field(
+    wire_type: WireType,
+    tag: u32,
+    buf: &mut Bytes,
+    unknown: &mut BytesMut,
+) -> Result<(), DecodeError> {
+    let value = buf.clone();
+    skip_field(wire_type, tag, buf)?;
+
+    encode_key(tag, wire_type, unknown);
+    unknown.extend_from_slice(&value[..value.len() - buf.len()]);
+    Ok(())
+}
+
 /// A Protobuf message decoding error.
 #[derive(Clone, PartialEq, Eq)]
 pub struct DecodeError {
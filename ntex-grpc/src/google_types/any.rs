@@ -0,0 +1,94 @@
+#![allow(
+    dead_code,
+    unused_mut,
+    unused_variables,
+    clippy::identity_op,
+    clippy::derivable_impls,
+    clippy::unit_arg,
+    clippy::derive_partial_eq_without_eq
+)]
+//! DO NOT MODIFY. Auto-generated file
+
+///  `Any` contains an arbitrary serialized protocol buffer message along with a
+///  URL that describes the type of the serialized message.
+///
+///  Protobuf library provides support to pack/unpack Any values in the form
+///  of utility functions or additional generated methods of the Any type.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Any {
+    ///  A URL/resource name that uniquely identifies the type of the serialized
+    ///  protocol buffer message. This string must contain at least
+    ///  one "/" character. The last segment of the URL's path must represent
+    ///  the fully qualified name of the type (as in
+    ///  `path/google.protobuf.Duration`). The name should be in a canonical form
+    ///  (e.g., leading "." is not accepted).
+    pub type_url: crate::ByteString,
+    ///  Must be a valid serialized protocol buffer of the above specified type.
+    pub value: crate::Bytes,
+}
+
+mod _priv_impl {
+    use super::*;
+
+    impl crate::Message for Any {
+        #[inline]
+        fn write(&self, dst: &mut crate::BytesMut) {
+            crate::NativeType::serialize(
+                &self.type_url,
+                1,
+                crate::types::DefaultValue::Default,
+                dst,
+            );
+            crate::NativeType::serialize(&self.value, 2, crate::types::DefaultValue::Default, dst);
+        }
+
+        #[inline]
+        fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
+            let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "Any";
+            while !src.is_empty() {
+                let (tag, wire_type) = crate::encoding::decode_key(src)?;
+                match tag {
+                    1 => crate::NativeType::deserialize(&mut self.type_url, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "type_url"))?,
+                    2 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "value"))?,
+                    _ => crate::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + crate::NativeType::serialized_len(
+                &self.type_url,
+                1,
+                crate::types::DefaultValue::Default,
+            ) + crate::NativeType::serialized_len(
+                &self.value,
+                2,
+                crate::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Any {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                type_url: ::core::default::Default::default(),
+                value: ::core::default::Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use ntex_bytes::{ByteString, BytesMut};
+
+use super::Any;
+use crate::{DecodeError, Message};
+
+/// Protobuf message with known type name
+pub trait Name: Message {
+    /// Simple name of the message, `Duration`
+    const NAME: &'static str;
+    /// Package of the message, `google.protobuf`
+    const PACKAGE: &'static str;
+
+    /// Fully-qualified message name, `google.protobuf.Duration`
+    fn full_name() -> String {
+        format!("{}.{}", Self::PACKAGE, Self::NAME)
+    }
+
+    /// Type url of the message, `type.googleapis.com/google.protobuf.Duration`
+    fn type_url() -> String {
+        format!("type.googleapis.com/{}.{}", Self::PACKAGE, Self::NAME)
+    }
+}
+
+impl Any {
+    /// Serialize message into `Any`
+    pub fn from_msg<M: Name>(msg: &M) -> Self {
+        let mut buf = BytesMut::with_capacity(msg.encoded_len());
+        msg.write(&mut buf);
+        Any {
+            type_url: ByteString::from(M::type_url()),
+            value: buf.freeze(),
+        }
+    }
+
+    /// Check if `Any` contains message of the type
+    pub fn is<M: Name>(&self) -> bool {
+        let name = self
+            .type_url
+            .rsplit_once('/')
+            .map_or(self.type_url.as_ref(), |(_, name)| name);
+        name.strip_prefix(M::PACKAGE)
+            .and_then(|name| name.strip_prefix('.'))
+            .is_some_and(|name| name == M::NAME)
+    }
+
+    /// Deserialize message from `Any`
+    ///
+    /// Returns error if `Any` contains message of different type.
+    pub fn to_msg<M: Name>(&self) -> Result<M, DecodeError> {
+        if self.is::<M>() {
+            M::read(&mut self.value.clone())
+        } else {
+            Err(DecodeError::new(format!(
+                "Expected type url {}, got {}",
+                M::type_url(),
+                self.type_url
+            )))
+        }
+    }
+}
+
+impl Name for Any {
+    const NAME: &'static str = "Any";
+    const PACKAGE: &'static str = "google.protobuf";
+}
+
+impl Name for super::Duration {
+    const NAME: &'static str = "Duration";
+    const PACKAGE: &'static str = "google.protobuf";
+}
+
+impl Name for super::Timestamp {
+    const NAME: &'static str = "Timestamp";
+    const PACKAGE: &'static str = "google.protobuf";
+}
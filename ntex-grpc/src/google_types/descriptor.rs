@@ -0,0 +1,321 @@
+#![allow(
+    dead_code,
+    unused_mut,
+    unused_variables,
+    clippy::identity_op,
+    clippy::derivable_impls,
+    clippy::unit_arg,
+    clippy::derive_partial_eq_without_eq,
+    clippy::manual_range_patterns,
+    clippy::semicolon_if_nothing_returned
+)]
+//! DO NOT MODIFY. Auto-generated file
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ServiceOptions {
+    ///  Is this service deprecated?
+    ///  Depending on the target platform, this can emit Deprecated annotations
+    ///  for the service, or it will be completely ignored; in the very least,
+    ///  this is a formalization for deprecating services.
+    pub deprecated: bool,
+    /// Encoded fields unknown to the message definition
+    pub unknown_fields: crate::Bytes,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MethodOptions {
+    ///  Is this method deprecated?
+    ///  Depending on the target platform, this can emit Deprecated annotations
+    ///  for the method, or it will be completely ignored; in the very least,
+    ///  this is a formalization for deprecating methods.
+    pub deprecated: bool,
+    pub idempotency_level: method_options::IdempotencyLevel,
+    /// Encoded fields unknown to the message definition
+    pub unknown_fields: crate::Bytes,
+}
+
+/// Nested message and enum types in `MethodOptions`.
+pub mod method_options {
+    ///  Is this method side-effect-free (or safe in HTTP parlance), or idempotent,
+    ///  or neither? HTTP based RPC implementation may choose GET verb for safe
+    ///  methods, and PUT verb for idempotent methods instead of the default POST.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[repr(i32)]
+    pub enum IdempotencyLevel {
+        IdempotencyUnknown = 0,
+        ///  implies idempotent
+        NoSideEffects = 1,
+        ///  idempotent, but may have side effects
+        Idempotent = 2,
+    }
+
+    impl IdempotencyLevel {
+        /// String value of the enum field names used in the ProtoBuf definition with stripped prefix.
+        pub fn to_str_name(self) -> &'static str {
+            match self {
+                IdempotencyLevel::IdempotencyUnknown => "IDEMPOTENCY_UNKNOWN",
+                IdempotencyLevel::NoSideEffects => "NO_SIDE_EFFECTS",
+                IdempotencyLevel::Idempotent => "IDEMPOTENT",
+            }
+        }
+
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn to_origin_name(self) -> &'static str {
+            match self {
+                IdempotencyLevel::IdempotencyUnknown => "IDEMPOTENCY_UNKNOWN",
+                IdempotencyLevel::NoSideEffects => "NO_SIDE_EFFECTS",
+                IdempotencyLevel::Idempotent => "IDEMPOTENT",
+            }
+        }
+
+        pub fn from_i32(value: i32) -> ::std::option::Option<Self> {
+            match value {
+                0 => Some(IdempotencyLevel::IdempotencyUnknown),
+                1 => Some(IdempotencyLevel::NoSideEffects),
+                2 => Some(IdempotencyLevel::Idempotent),
+                _ => ::std::option::Option::None,
+            }
+        }
+    }
+}
+
+mod _priv_impl {
+    use super::*;
+
+    impl crate::Message for ServiceOptions {
+        #[inline]
+        fn write(&self, dst: &mut crate::BytesMut) {
+            crate::NativeType::serialize(
+                &self.deprecated,
+                33,
+                crate::types::DefaultValue::Value(&false),
+                dst,
+            );
+            dst.extend_from_slice(&self.unknown_fields);
+        }
+
+        #[inline]
+        fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
+            let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "ServiceOptions";
+            let mut unknown_fields = crate::BytesMut::from(&self.unknown_fields[..]);
+            while !src.is_empty() {
+                let (tag, wire_type) = crate::encoding::decode_key(src)?;
+                match tag {
+                    33 => {
+                        crate::NativeType::deserialize(&mut self.deprecated, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "deprecated"))?
+                    }
+                    _ => crate::encoding::skip_unknown_field(
+                        wire_type,
+                        tag,
+                        src,
+                        &mut unknown_fields,
+                    )?,
+                }
+            }
+            self.unknown_fields = unknown_fields.freeze();
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + crate::NativeType::serialized_len(
+                &self.deprecated,
+                33,
+                crate::types::DefaultValue::Value(&false),
+            ) + self.unknown_fields.len()
+        }
+    }
+
+    impl ::std::default::Default for ServiceOptions {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                deprecated: false,
+                unknown_fields: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl crate::types::Extendable for ServiceOptions {
+        const NAME: &'static str = "google.protobuf.ServiceOptions";
+
+        #[inline]
+        fn unknown_fields(&self) -> &crate::Bytes {
+            &self.unknown_fields
+        }
+
+        #[inline]
+        fn unknown_fields_mut(&mut self) -> &mut crate::Bytes {
+            &mut self.unknown_fields
+        }
+    }
+
+    impl crate::Message for MethodOptions {
+        #[inline]
+        fn write(&self, dst: &mut crate::BytesMut) {
+            crate::NativeType::serialize(
+                &self.deprecated,
+                33,
+                crate::types::DefaultValue::Value(&false),
+                dst,
+            );
+            crate::NativeType::serialize(
+                &self.idempotency_level,
+                34,
+                crate::types::DefaultValue::Value(
+                    &method_options::IdempotencyLevel::IdempotencyUnknown,
+                ),
+                dst,
+            );
+            dst.extend_from_slice(&self.unknown_fields);
+        }
+
+        #[inline]
+        fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
+            let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "MethodOptions";
+            let mut unknown_fields = crate::BytesMut::from(&self.unknown_fields[..]);
+            while !src.is_empty() {
+                let (tag, wire_type) = crate::encoding::decode_key(src)?;
+                match tag {
+                    33 => {
+                        crate::NativeType::deserialize(&mut self.deprecated, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "deprecated"))?
+                    }
+                    34 => crate::NativeType::deserialize(
+                        &mut self.idempotency_level,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "idempotency_level"))?,
+                    _ => crate::encoding::skip_unknown_field(
+                        wire_type,
+                        tag,
+                        src,
+                        &mut unknown_fields,
+                    )?,
+                }
+            }
+            self.unknown_fields = unknown_fields.freeze();
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + crate::NativeType::serialized_len(
+                &self.deprecated,
+                33,
+                crate::types::DefaultValue::Value(&false),
+            ) + crate::NativeType::serialized_len(
+                &self.idempotency_level,
+                34,
+                crate::types::DefaultValue::Value(
+                    &method_options::IdempotencyLevel::IdempotencyUnknown,
+                ),
+            ) + self.unknown_fields.len()
+        }
+    }
+
+    impl ::std::default::Default for MethodOptions {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                deprecated: false,
+                idempotency_level: method_options::IdempotencyLevel::IdempotencyUnknown,
+                unknown_fields: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl crate::types::Extendable for MethodOptions {
+        const NAME: &'static str = "google.protobuf.MethodOptions";
+
+        #[inline]
+        fn unknown_fields(&self) -> &crate::Bytes {
+            &self.unknown_fields
+        }
+
+        #[inline]
+        fn unknown_fields_mut(&mut self) -> &mut crate::Bytes {
+            &mut self.unknown_fields
+        }
+    }
+
+    impl crate::NativeType for method_options::IdempotencyLevel {
+        const TYPE: crate::WireType = crate::WireType::Varint;
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            *self = crate::encoding::decode_varint(src)
+                .map(|val| Self::from_i32(val as i32).unwrap_or_default())?;
+            Ok(())
+        }
+
+        #[inline]
+        fn encode_value(&self, dst: &mut crate::BytesMut) {
+            crate::encoding::encode_varint(*self as i32 as u64, dst);
+        }
+
+        #[inline]
+        fn encoded_len(&self, tag: u32) -> usize {
+            crate::encoding::key_len(tag)
+                + crate::encoding::encoded_len_varint(*self as i32 as u64)
+        }
+
+        #[inline]
+        fn value_len(&self) -> usize {
+            crate::encoding::encoded_len_varint(*self as i32 as u64)
+        }
+
+        #[inline]
+        fn is_default(&self) -> bool {
+            self == &method_options::IdempotencyLevel::IdempotencyUnknown
+        }
+    }
+
+    impl ::std::default::Default for method_options::IdempotencyLevel {
+        #[inline]
+        fn default() -> Self {
+            method_options::IdempotencyLevel::IdempotencyUnknown
+        }
+    }
+
+    impl crate::types::Enumeration for method_options::IdempotencyLevel {
+        #[inline]
+        fn from_i32(value: i32) -> ::std::option::Option<Self> {
+            method_options::IdempotencyLevel::from_i32(value)
+        }
+
+        #[inline]
+        fn to_i32(self) -> i32 {
+            self as i32
+        }
+    }
+}
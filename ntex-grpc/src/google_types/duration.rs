@@ -20,43 +20,49 @@
 ///
 ///  Example 1: Compute Duration from two Timestamps in pseudo code.
 ///
-///      Timestamp start = ...;
-///      Timestamp end = ...;
-///      Duration duration = ...;
+/// ```text
+///  Timestamp start = ...;
+///  Timestamp end = ...;
+///  Duration duration = ...;
 ///
-///      duration.seconds = end.seconds - start.seconds;
-///      duration.nanos = end.nanos - start.nanos;
+///  duration.seconds = end.seconds - start.seconds;
+///  duration.nanos = end.nanos - start.nanos;
 ///
-///      if (duration.seconds < 0 && duration.nanos > 0) {
-///        duration.seconds += 1;
-///        duration.nanos -= 1000000000;
-///      } else if (duration.seconds > 0 && duration.nanos < 0) {
-///        duration.seconds -= 1;
-///        duration.nanos += 1000000000;
-///      }
+///  if (duration.seconds < 0 && duration.nanos > 0) {
+///    duration.seconds += 1;
+///    duration.nanos -= 1000000000;
+///  } else if (duration.seconds > 0 && duration.nanos < 0) {
+///    duration.seconds -= 1;
+///    duration.nanos += 1000000000;
+///  }
+/// ```
 ///
 ///  Example 2: Compute Timestamp from Timestamp + Duration in pseudo code.
 ///
-///      Timestamp start = ...;
-///      Duration duration = ...;
-///      Timestamp end = ...;
+/// ```text
+///  Timestamp start = ...;
+///  Duration duration = ...;
+///  Timestamp end = ...;
 ///
-///      end.seconds = start.seconds + duration.seconds;
-///      end.nanos = start.nanos + duration.nanos;
+///  end.seconds = start.seconds + duration.seconds;
+///  end.nanos = start.nanos + duration.nanos;
 ///
-///      if (end.nanos < 0) {
-///        end.seconds -= 1;
-///        end.nanos += 1000000000;
-///      } else if (end.nanos >= 1000000000) {
-///        end.seconds += 1;
-///        end.nanos -= 1000000000;
-///      }
+///  if (end.nanos < 0) {
+///    end.seconds -= 1;
+///    end.nanos += 1000000000;
+///  } else if (end.nanos >= 1000000000) {
+///    end.seconds += 1;
+///    end.nanos -= 1000000000;
+///  }
+/// ```
 ///
 ///  Example 3: Compute Duration from datetime.timedelta in Python.
 ///
-///      td = datetime.timedelta(days=3, minutes=10)
-///      duration = Duration()
-///      duration.FromTimedelta(td)
+/// ```text
+///  td = datetime.timedelta(days=3, minutes=10)
+///  duration = Duration()
+///  duration.FromTimedelta(td)
+/// ```
 ///
 ///  # JSON Mapping
 ///
@@ -101,19 +107,28 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "Duration";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "Duration";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.seconds, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.seconds, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "seconds"))?,
-                    2 => crate::NativeType::deserialize(&mut msg.nanos, tag, wire_type, src)
+                    2 => crate::NativeType::deserialize(&mut self.nanos, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "nanos"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
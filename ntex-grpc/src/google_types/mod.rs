@@ -7,12 +7,24 @@
     clippy::missing_panics_doc,
     clippy::doc_markdown
 )]
+mod any;
+mod any_impl;
+mod descriptor;
 mod duration;
 mod duration_impl;
+mod error_details;
+mod status;
+mod status_impl;
 mod timestamp;
 mod timestamp_impl;
 mod wrappers;
 
+pub use self::any::Any;
+pub use self::any_impl::Name;
+pub use self::descriptor::{MethodOptions, ServiceOptions, method_options};
 pub use self::duration::Duration;
+pub use self::error_details::*;
+pub use self::status::Status;
+pub use self::status_impl::ErrorDetails;
 pub use self::timestamp::Timestamp;
 pub use self::wrappers::*;
@@ -0,0 +1,111 @@
+#![allow(
+    dead_code,
+    unused_mut,
+    unused_variables,
+    clippy::identity_op,
+    clippy::derivable_impls,
+    clippy::unit_arg,
+    clippy::derive_partial_eq_without_eq
+)]
+//! DO NOT MODIFY. Auto-generated file
+
+///  The `Status` type defines a logical error model that is suitable for
+///  different programming environments, including REST APIs and RPC APIs. It is
+///  used by [gRPC](<https://github.com/grpc>). Each `Status` message contains
+///  three pieces of data: error code, error message, and error details.
+///
+///  You can find out more about this error model and how to work with it in the
+///  [API Design Guide](<https://cloud.google.com/apis/design/errors>).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Status {
+    ///  The status code, which should be an enum value of
+    ///  \[google.rpc.Code\]\[google.rpc.Code\].
+    pub code: i32,
+    ///  A developer-facing error message, which should be in English. Any
+    ///  user-facing error message should be localized and sent in the
+    ///  \[google.rpc.Status.details\]\[google.rpc.Status.details\] field, or localized
+    ///  by the client.
+    pub message: crate::ByteString,
+    ///  A list of messages that carry the error details.  There is a common set of
+    ///  message types for APIs to use.
+    pub details: Vec<crate::google_types::Any>,
+}
+
+mod _priv_impl {
+    use super::*;
+
+    impl crate::Message for Status {
+        #[inline]
+        fn write(&self, dst: &mut crate::BytesMut) {
+            crate::NativeType::serialize(&self.code, 1, crate::types::DefaultValue::Default, dst);
+            crate::NativeType::serialize(
+                &self.message,
+                2,
+                crate::types::DefaultValue::Default,
+                dst,
+            );
+            crate::NativeType::serialize(
+                &self.details,
+                3,
+                crate::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
+            let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "Status";
+            while !src.is_empty() {
+                let (tag, wire_type) = crate::encoding::decode_key(src)?;
+                match tag {
+                    1 => crate::NativeType::deserialize(&mut self.code, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "code"))?,
+                    2 => crate::NativeType::deserialize(&mut self.message, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "message"))?,
+                    3 => crate::NativeType::deserialize(&mut self.details, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "details"))?,
+                    _ => crate::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + crate::NativeType::serialized_len(
+                &self.code,
+                1,
+                crate::types::DefaultValue::Default,
+            ) + crate::NativeType::serialized_len(
+                &self.message,
+                2,
+                crate::types::DefaultValue::Default,
+            ) + crate::NativeType::serialized_len(
+                &self.details,
+                3,
+                crate::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Status {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                code: ::core::default::Default::default(),
+                message: ::core::default::Default::default(),
+                details: ::core::default::Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use ntex_bytes::{ByteString, Bytes, BytesMut};
+use ntex_http::HeaderValue;
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+
+use super::any_impl::Name;
+use super::{
+    Any, BadRequest, DebugInfo, ErrorInfo, Help, LocalizedMessage, PreconditionFailure,
+    QuotaFailure, RequestInfo, ResourceInfo, RetryInfo, Status,
+};
+use crate::{DecodeError, GrpcStatus, Message};
+
+macro_rules! rpc_name {
+    ($($name:ident),+) => {
+        $(
+            impl Name for $name {
+                const NAME: &'static str = stringify!($name);
+                const PACKAGE: &'static str = "google.rpc";
+            }
+        )+
+    };
+}
+
+rpc_name!(
+    Status,
+    ErrorInfo,
+    RetryInfo,
+    DebugInfo,
+    QuotaFailure,
+    PreconditionFailure,
+    BadRequest,
+    RequestInfo,
+    ResourceInfo,
+    Help,
+    LocalizedMessage
+);
+
+/// Standard error details of `google.rpc.Status`
+///
+/// Each detail type could be present only once, unknown details are ignored.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ErrorDetails {
+    pub error_info: Option<ErrorInfo>,
+    pub retry_info: Option<RetryInfo>,
+    pub debug_info: Option<DebugInfo>,
+    pub quota_failure: Option<QuotaFailure>,
+    pub precondition_failure: Option<PreconditionFailure>,
+    pub bad_request: Option<BadRequest>,
+    pub request_info: Option<RequestInfo>,
+    pub resource_info: Option<ResourceInfo>,
+    pub help: Option<Help>,
+    pub localized_message: Option<LocalizedMessage>,
+}
+
+impl ErrorDetails {
+    /// Create empty error details
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if error details are empty
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Decode error details from list of `Any` messages
+    pub fn from_any(details: &[Any]) -> Result<Self, DecodeError> {
+        fn set<M: Name>(any: &Any, item: &mut Option<M>) -> Result<bool, DecodeError> {
+            if any.is::<M>() {
+                *item = Some(any.to_msg()?);
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+
+        let mut res = Self::default();
+        for any in details {
+            let _ = set(any, &mut res.error_info)?
+                || set(any, &mut res.retry_info)?
+                || set(any, &mut res.debug_info)?
+                || set(any, &mut res.quota_failure)?
+                || set(any, &mut res.precondition_failure)?
+                || set(any, &mut res.bad_request)?
+                || set(any, &mut res.request_info)?
+                || set(any, &mut res.resource_info)?
+                || set(any, &mut res.help)?
+                || set(any, &mut res.localized_message)?;
+        }
+        Ok(res)
+    }
+
+    /// Encode error details to list of `Any` messages
+    pub fn to_any(&self) -> Vec<Any> {
+        fn push<M: Name>(item: Option<&M>, details: &mut Vec<Any>) {
+            if let Some(item) = item {
+                details.push(Any::from_msg(item));
+            }
+        }
+
+        let mut details = Vec::new();
+        push(self.error_info.as_ref(), &mut details);
+        push(self.retry_info.as_ref(), &mut details);
+        push(self.debug_info.as_ref(), &mut details);
+        push(self.quota_failure.as_ref(), &mut details);
+        push(self.precondition_failure.as_ref(), &mut details);
+        push(self.bad_request.as_ref(), &mut details);
+        push(self.request_info.as_ref(), &mut details);
+        push(self.resource_info.as_ref(), &mut details);
+        push(self.help.as_ref(), &mut details);
+        push(self.localized_message.as_ref(), &mut details);
+        details
+    }
+}
+
+impl Status {
+    /// Create status with standard error details
+    pub fn new<T: Into<ByteString>>(code: GrpcStatus, message: T, details: &ErrorDetails) -> Self {
+        Status {
+            code: i32::from(code.code()),
+            message: message.into(),
+            details: details.to_any(),
+        }
+    }
+
+    /// Decode standard error details
+    pub fn error_details(&self) -> Result<ErrorDetails, DecodeError> {
+        ErrorDetails::from_any(&self.details)
+    }
+
+    /// Encode status to `grpc-status-details-bin` header value
+    pub fn to_header_value(&self) -> HeaderValue {
+        let mut buf = BytesMut::with_capacity(self.encoded_len());
+        self.write(&mut buf);
+        HeaderValue::try_from(STANDARD_NO_PAD.encode(&buf))
+            .expect("base64 is always valid header value")
+    }
+
+    /// Decode status from `grpc-status-details-bin` header value
+    ///
+    /// Padded and unpadded base64 encodings are accepted.
+    pub fn from_header_value(value: &HeaderValue) -> Result<Self, DecodeError> {
+        let value = value.as_bytes();
+        let value = if value.len().is_multiple_of(4) {
+            STANDARD.decode(value)
+        } else {
+            STANDARD_NO_PAD.decode(value)
+        }
+        .map_err(|e| DecodeError::new(format!("Cannot decode status details: {e}")))?;
+        Status::read(&mut Bytes::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google_types::bad_request::FieldViolation;
+
+    #[test]
+    fn status_details() {
+        let details = ErrorDetails {
+            bad_request: Some(BadRequest {
+                field_violations: vec![FieldViolation {
+                    field: ByteString::from_static("name"),
+                    description: ByteString::from_static("Name is required"),
+                }],
+            }),
+            request_info: Some(RequestInfo {
+                request_id: ByteString::from_static("1"),
+                serving_data: ByteString::new(),
+            }),
+            ..Default::default()
+        };
+        let mut status = Status::new(GrpcStatus::InvalidArgument, "Invalid request", &details);
+        status.details.push(Any {
+            type_url: ByteString::from_static("type.googleapis.com/test.Unknown"),
+            value: Bytes::from_static(b"unknown"),
+        });
+        assert_eq!(
+            status.details[1].type_url,
+            "type.googleapis.com/google.rpc.RequestInfo"
+        );
+
+        let value = status.to_header_value();
+        let decoded = Status::from_header_value(&value).unwrap();
+        assert_eq!(decoded, status);
+        assert_eq!(decoded.code, 3);
+        assert_eq!(decoded.error_details().unwrap(), details);
+
+        // padded encoding
+        let mut buf = BytesMut::new();
+        status.write(&mut buf);
+        let value = HeaderValue::try_from(STANDARD.encode(&buf)).unwrap();
+        assert_eq!(Status::from_header_value(&value).unwrap(), status);
+
+        let value = HeaderValue::from_static("!!!");
+        assert!(Status::from_header_value(&value).is_err());
+    }
+}
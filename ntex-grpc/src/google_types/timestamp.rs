@@ -26,52 +26,64 @@
 ///
 ///  Example 1: Compute Timestamp from POSIX `time()`.
 ///
-///      Timestamp timestamp;
-///      timestamp.set_seconds(time(NULL));
-///      timestamp.set_nanos(0);
+/// ```text
+///  Timestamp timestamp;
+///  timestamp.set_seconds(time(NULL));
+///  timestamp.set_nanos(0);
+/// ```
 ///
 ///  Example 2: Compute Timestamp from POSIX `gettimeofday()`.
 ///
-///      struct timeval tv;
-///      gettimeofday(&tv, NULL);
+/// ```text
+///  struct timeval tv;
+///  gettimeofday(&tv, NULL);
 ///
-///      Timestamp timestamp;
-///      timestamp.set_seconds(tv.tv_sec);
-///      timestamp.set_nanos(tv.tv_usec * 1000);
+///  Timestamp timestamp;
+///  timestamp.set_seconds(tv.tv_sec);
+///  timestamp.set_nanos(tv.tv_usec * 1000);
+/// ```
 ///
 ///  Example 3: Compute Timestamp from Win32 `GetSystemTimeAsFileTime()`.
 ///
-///      FILETIME ft;
-///      GetSystemTimeAsFileTime(&ft);
-///      UINT64 ticks = (((UINT64)ft.dwHighDateTime) << 32) | ft.dwLowDateTime;
+/// ```text
+///  FILETIME ft;
+///  GetSystemTimeAsFileTime(&ft);
+///  UINT64 ticks = (((UINT64)ft.dwHighDateTime) << 32) | ft.dwLowDateTime;
 ///
-///      // A Windows tick is 100 nanoseconds. Windows epoch 1601-01-01T00:00:00Z
-///      // is 11644473600 seconds before Unix epoch 1970-01-01T00:00:00Z.
-///      Timestamp timestamp;
-///      timestamp.set_seconds((INT64) ((ticks / 10000000) - 11644473600LL));
-///      timestamp.set_nanos((INT32) ((ticks % 10000000) * 100));
+///  // A Windows tick is 100 nanoseconds. Windows epoch 1601-01-01T00:00:00Z
+///  // is 11644473600 seconds before Unix epoch 1970-01-01T00:00:00Z.
+///  Timestamp timestamp;
+///  timestamp.set_seconds((INT64) ((ticks / 10000000) - 11644473600LL));
+///  timestamp.set_nanos((INT32) ((ticks % 10000000) * 100));
+/// ```
 ///
 ///  Example 4: Compute Timestamp from Java `System.currentTimeMillis()`.
 ///
-///      long millis = System.currentTimeMillis();
+/// ```text
+///  long millis = System.currentTimeMillis();
 ///
-///      Timestamp timestamp = Timestamp.newBuilder().setSeconds(millis / 1000)
-///          .setNanos((int) ((millis % 1000) * 1000000)).build();
+///  Timestamp timestamp = Timestamp.newBuilder().setSeconds(millis / 1000)
+///      .setNanos((int) ((millis % 1000) * 1000000)).build();
+/// ```
 ///
 ///
 ///  Example 5: Compute Timestamp from Java `Instant.now()`.
 ///
-///      Instant now = Instant.now();
+/// ```text
+///  Instant now = Instant.now();
 ///
-///      Timestamp timestamp =
-///          Timestamp.newBuilder().setSeconds(now.getEpochSecond())
-///              .setNanos(now.getNano()).build();
+///  Timestamp timestamp =
+///      Timestamp.newBuilder().setSeconds(now.getEpochSecond())
+///          .setNanos(now.getNano()).build();
+/// ```
 ///
 ///
 ///  Example 6: Compute Timestamp from current time in Python.
 ///
-///      timestamp = Timestamp()
-///      timestamp.GetCurrentTime()
+/// ```text
+///  timestamp = Timestamp()
+///  timestamp.GetCurrentTime()
+/// ```
 ///
 ///  # JSON Mapping
 ///
@@ -131,19 +143,28 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "Timestamp";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "Timestamp";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.seconds, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.seconds, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "seconds"))?,
-                    2 => crate::NativeType::deserialize(&mut msg.nanos, tag, wire_type, src)
+                    2 => crate::NativeType::deserialize(&mut self.nanos, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "nanos"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -181,17 +181,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "Int64Value";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "Int64Value";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -221,17 +230,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "UInt64Value";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "UInt64Value";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -261,17 +279,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "Int32Value";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "Int32Value";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -301,17 +328,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "UInt32Value";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "UInt32Value";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -341,17 +377,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "BoolValue";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "BoolValue";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -381,17 +426,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "StringValue";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "StringValue";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -421,17 +475,26 @@ mod _priv_impl {
 
         #[inline]
         fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
-            const STRUCT_NAME: &str = "BytesValue";
             let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "BytesValue";
             while !src.is_empty() {
                 let (tag, wire_type) = crate::encoding::decode_key(src)?;
                 match tag {
-                    1 => crate::NativeType::deserialize(&mut msg.value, tag, wire_type, src)
+                    1 => crate::NativeType::deserialize(&mut self.value, tag, wire_type, src)
                         .map_err(|err| err.push(STRUCT_NAME, "value"))?,
                     _ => crate::encoding::skip_field(wire_type, tag, src)?,
                 }
             }
-            Ok(msg)
+            Ok(())
         }
 
         #[inline]
@@ -5,6 +5,7 @@
     clippy::missing_errors_doc,
     clippy::missing_fields_in_debug
 )]
+mod compression;
 mod consts;
 mod service;
 mod status;
@@ -14,8 +15,11 @@ pub mod client;
 pub mod server;
 pub mod types;
 
+pub use crate::compression::CompressionEncoding;
 pub use crate::encoding::DecodeError;
 
+#[doc(hidden)]
+pub use crate::service::MethodRequest;
 pub use crate::service::{MethodDef, ServiceDef};
 pub use crate::status::GrpcStatus;
 pub use crate::types::{Message, NativeType};
@@ -31,7 +35,7 @@ pub use ntex_bytes::{ByteString, Bytes, BytesMut};
 #[doc(hidden)]
 pub use ntex_http::HeaderValue;
 #[doc(hidden)]
-pub use ntex_service::{Service, ServiceCtx, ServiceFactory};
+pub use ntex_service::{Service, ServiceCtx, ServiceFactory, cfg::SharedCfg};
 #[doc(hidden)]
 pub use ntex_util::HashMap;
 
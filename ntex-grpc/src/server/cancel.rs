@@ -0,0 +1,85 @@
+use std::{cell::Cell, fmt, future::poll_fn, rc::Rc, task::Poll};
+
+use ntex_util::channel::condition::Condition;
+
+/// Call cancellation signal
+///
+/// Call is cancelled if client resets the stream, connection is closed
+/// or call deadline is exceeded.
+#[derive(Clone, Default)]
+pub struct Cancellation(Rc<Inner>);
+
+#[derive(Default)]
+struct Inner {
+    cancelled: Cell<bool>,
+    waiters: Condition,
+}
+
+impl Cancellation {
+    /// Create new cancellation signal
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if call is cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.get()
+    }
+
+    /// Wait until call is cancelled
+    pub async fn cancelled(&self) {
+        if self.is_cancelled() {
+            return;
+        }
+
+        let waiter = self.0.waiters.wait();
+        poll_fn(|cx| {
+            if self.is_cancelled() {
+                Poll::Ready(())
+            } else {
+                let _ = waiter.poll_ready(cx);
+                Poll::Pending
+            }
+        })
+        .await;
+    }
+
+    /// Cancel call
+    pub fn cancel(&self) {
+        if !self.0.cancelled.replace(true) {
+            self.0.waiters.notify();
+        }
+    }
+}
+
+impl fmt::Debug for Cancellation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cancellation")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ntex::test]
+    async fn cancellation() {
+        let cancel = Cancellation::new();
+        assert!(!cancel.is_cancelled());
+
+        let cancel2 = cancel.clone();
+        let handle = ntex::rt::spawn(async move {
+            cancel2.cancelled().await;
+            true
+        });
+        ntex::time::sleep(ntex::time::Millis(10)).await;
+        cancel.cancel();
+        assert!(handle.await.unwrap());
+        assert!(cancel.is_cancelled());
+
+        // already cancelled
+        cancel.cancelled().await;
+    }
+}
@@ -1,6 +1,10 @@
+use ntex_bytes::BytesMut;
 use ntex_http::{HeaderMap, HeaderValue};
 
-use crate::{DecodeError, GrpcStatus};
+use super::{Response, ResponseStream, ServerResponse};
+use crate::google_types::{ErrorDetails, Status};
+use crate::utils::FrameError;
+use crate::{DecodeError, GrpcStatus, Message, MethodDef, consts};
 
 #[derive(thiserror::Error, Clone, Debug)]
 #[error("{status:?}: {message:?}")]
@@ -18,6 +22,45 @@ impl ServerError {
             headers: headers.unwrap_or_default(),
         }
     }
+
+    #[must_use]
+    /// Attach standard error details
+    ///
+    /// Details are sent in `grpc-status-details-bin` trailer as `google.rpc.Status`.
+    pub fn with_details(self, details: &ErrorDetails) -> Self {
+        let status = Status::new(
+            self.status,
+            String::from_utf8_lossy(self.message.as_bytes()).into_owned(),
+            details,
+        );
+        self.with_status_details(&status)
+    }
+
+    #[must_use]
+    /// Attach `google.rpc.Status` to the error
+    pub fn with_status_details(mut self, status: &Status) -> Self {
+        self.headers
+            .insert(consts::GRPC_STATUS_DETAILS, status.to_header_value());
+        self
+    }
+}
+
+impl From<FrameError> for ServerError {
+    fn from(err: FrameError) -> Self {
+        match err {
+            FrameError::Decode(err) => Self::from(err),
+            FrameError::TooLarge => Self::new(
+                GrpcStatus::ResourceExhausted,
+                HeaderValue::from_static("Message is larger than max message size"),
+                None,
+            ),
+            FrameError::Uncompressed => Self::new(
+                GrpcStatus::Internal,
+                HeaderValue::from_static("Compressed message received without grpc-encoding"),
+                None,
+            ),
+        }
+    }
 }
 
 impl From<DecodeError> for ServerError {
@@ -50,3 +93,82 @@ impl<T, E: Into<T>> MethodResult<T> for Result<T, E> {
         }
     }
 }
+
+/// Conversion of method handler result to server response
+pub trait MethodResponse<T> {
+    fn into_response<M>(self, method: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>;
+}
+
+impl<T: Message> MethodResponse<T> for T {
+    #[inline]
+    fn into_response<M>(self, method: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>,
+    {
+        let mut buf = BytesMut::new();
+        method.encode(self, &mut buf);
+        Ok(ServerResponse::new(buf.freeze()))
+    }
+}
+
+impl<T: Message, E: Into<T>> MethodResponse<T> for Result<T, E> {
+    #[inline]
+    fn into_response<M>(self, method: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>,
+    {
+        MethodResult::<T>::into(self).into_response(method)
+    }
+}
+
+impl<T: Message> MethodResponse<T> for Response<T> {
+    #[inline]
+    fn into_response<M>(self, method: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>,
+    {
+        let mut buf = BytesMut::new();
+        method.encode(self.message, &mut buf);
+        Ok(ServerResponse::with_headers(buf.freeze(), self.headers))
+    }
+}
+
+impl<T: Message, E: Into<ServerError>> MethodResponse<T> for Result<Response<T>, E> {
+    #[inline]
+    fn into_response<M>(self, method: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>,
+    {
+        match self {
+            Ok(res) => res.into_response(method),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl<T: Message + 'static> MethodResponse<T> for ResponseStream<T> {
+    #[inline]
+    fn into_response<M>(self, _: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>,
+    {
+        Ok(self.into_server_response())
+    }
+}
+
+impl<T: Message + 'static, E: Into<ServerError>> MethodResponse<T>
+    for Result<ResponseStream<T>, E>
+{
+    #[inline]
+    fn into_response<M>(self, method: &M) -> Result<ServerResponse, ServerError>
+    where
+        M: MethodDef<Output = T>,
+    {
+        match self {
+            Ok(stream) => stream.into_response(method),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
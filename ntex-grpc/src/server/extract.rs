@@ -0,0 +1,521 @@
+use std::{any, fmt, net::SocketAddr, ops, rc::Rc, time};
+
+use ntex_http::HeaderValue;
+
+use super::{Cancellation, Request, ServerError, Streaming};
+use crate::{GrpcStatus, Message};
+
+/// Extract handler argument from request
+///
+/// Handler arguments are extracted in order of declaration. Request message
+/// could be extracted by one argument only, `#[server]` macro rejects handlers
+/// with several arguments that take the message.
+pub trait FromRequest<T>: Sized {
+    #[doc(hidden)]
+    /// Extractor takes request message
+    const MESSAGE: bool = false;
+
+    /// Extract value from request
+    fn from_request(req: &Request<()>, message: &mut Option<T>) -> Result<Self, ServerError>;
+
+    /// Extract optional value from request
+    ///
+    /// Used by `Option<Self>` extractor, returns `Ok(None)` if value is missing.
+    /// Default implementation fails on any extraction error.
+    fn from_request_optional(
+        req: &Request<()>,
+        message: &mut Option<T>,
+    ) -> Result<Option<Self>, ServerError> {
+        Self::from_request(req, message).map(Some)
+    }
+}
+
+impl<T: Message> FromRequest<T> for T {
+    const MESSAGE: bool = true;
+
+    #[inline]
+    fn from_request(_: &Request<()>, message: &mut Option<T>) -> Result<T, ServerError> {
+        message.take().ok_or_else(message_error)
+    }
+
+    #[inline]
+    fn from_request_optional(
+        _: &Request<()>,
+        message: &mut Option<T>,
+    ) -> Result<Option<T>, ServerError> {
+        Ok(message.take())
+    }
+}
+
+impl<T: Message> FromRequest<Streaming<T>> for Streaming<T> {
+    const MESSAGE: bool = true;
+
+    #[inline]
+    fn from_request(
+        _: &Request<()>,
+        message: &mut Option<Streaming<T>>,
+    ) -> Result<Streaming<T>, ServerError> {
+        message.take().ok_or_else(message_error)
+    }
+
+    #[inline]
+    fn from_request_optional(
+        _: &Request<()>,
+        message: &mut Option<Streaming<T>>,
+    ) -> Result<Option<Streaming<T>>, ServerError> {
+        Ok(message.take())
+    }
+}
+
+impl<T> FromRequest<T> for Request<T> {
+    const MESSAGE: bool = true;
+
+    #[inline]
+    fn from_request(
+        req: &Request<()>,
+        message: &mut Option<T>,
+    ) -> Result<Request<T>, ServerError> {
+        Ok(req.with_message(message.take().ok_or_else(message_error)?))
+    }
+
+    #[inline]
+    fn from_request_optional(
+        req: &Request<()>,
+        message: &mut Option<T>,
+    ) -> Result<Option<Request<T>>, ServerError> {
+        Ok(message.take().map(|message| req.with_message(message)))
+    }
+}
+
+/// Optional extractor, `None` if value is missing
+///
+/// Other extraction errors, for example invalid metadata value, fail the call.
+impl<T, E: FromRequest<T>> FromRequest<T> for Option<E> {
+    const MESSAGE: bool = E::MESSAGE;
+
+    #[inline]
+    fn from_request(req: &Request<()>, message: &mut Option<T>) -> Result<Self, ServerError> {
+        E::from_request_optional(req, message)
+    }
+}
+
+fn message_error() -> ServerError {
+    ServerError::new(
+        GrpcStatus::Internal,
+        HeaderValue::from_static("Request message is already extracted"),
+        None,
+    )
+}
+
+fn error(status: GrpcStatus, msg: &str) -> ServerError {
+    ServerError::new(
+        status,
+        HeaderValue::from_str(msg).unwrap_or(HeaderValue::from_static("Cannot extract argument")),
+        None,
+    )
+}
+
+/// Typed request metadata value
+pub trait MetadataValue: Sized {
+    /// Metadata key
+    const KEY: &'static str;
+
+    /// Parse metadata value
+    fn parse(value: &HeaderValue) -> Option<Self>;
+}
+
+/// Typed request metadata extractor
+///
+/// Call fails with `InvalidArgument` status if metadata is missing or invalid,
+/// use `Option<Metadata<T>>` for optional metadata.
+#[derive(Clone, Debug)]
+pub struct Metadata<T>(pub T);
+
+impl<T> Metadata<T> {
+    /// Unwrap into inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> ops::Deref for Metadata<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<M, T: MetadataValue> FromRequest<M> for Metadata<T> {
+    fn from_request(req: &Request<()>, message: &mut Option<M>) -> Result<Self, ServerError> {
+        Self::from_request_optional(req, message)?.ok_or_else(|| {
+            error(
+                GrpcStatus::InvalidArgument,
+                &format!("Metadata is required: {}", T::KEY),
+            )
+        })
+    }
+
+    fn from_request_optional(
+        req: &Request<()>,
+        _: &mut Option<M>,
+    ) -> Result<Option<Self>, ServerError> {
+        let Some(value) = req.headers.get(T::KEY) else {
+            return Ok(None);
+        };
+        T::parse(value).map(|v| Some(Metadata(v))).ok_or_else(|| {
+            error(
+                GrpcStatus::InvalidArgument,
+                &format!("Cannot parse metadata: {}", T::KEY),
+            )
+        })
+    }
+}
+
+/// Call deadline extractor
+///
+/// Deadline is set if client provides `grpc-timeout` metadata.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Deadline(pub Option<time::Instant>);
+
+impl Deadline {
+    /// Time remaining until deadline, `None` if deadline is not set
+    pub fn remaining(&self) -> Option<time::Duration> {
+        self.0
+            .map(|deadline| deadline.saturating_duration_since(time::Instant::now()))
+    }
+
+    /// Check if deadline is exceeded
+    pub fn is_expired(&self) -> bool {
+        self.0
+            .is_some_and(|deadline| deadline <= time::Instant::now())
+    }
+}
+
+impl<M> FromRequest<M> for Deadline {
+    #[inline]
+    fn from_request(req: &Request<()>, _: &mut Option<M>) -> Result<Self, ServerError> {
+        Ok(Deadline(req.deadline))
+    }
+}
+
+impl<M> FromRequest<M> for Cancellation {
+    #[inline]
+    fn from_request(req: &Request<()>, _: &mut Option<M>) -> Result<Self, ServerError> {
+        Ok(req.cancellation.clone())
+    }
+}
+
+/// Peer address extractor
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PeerAddr(pub SocketAddr);
+
+impl<M> FromRequest<M> for PeerAddr {
+    #[inline]
+    fn from_request(req: &Request<()>, message: &mut Option<M>) -> Result<Self, ServerError> {
+        Self::from_request_optional(req, message)?.ok_or_else(|| {
+            ServerError::new(
+                GrpcStatus::Internal,
+                HeaderValue::from_static("Peer address is not available"),
+                None,
+            )
+        })
+    }
+
+    #[inline]
+    fn from_request_optional(
+        req: &Request<()>,
+        _: &mut Option<M>,
+    ) -> Result<Option<Self>, ServerError> {
+        Ok(req.peer_addr.map(PeerAddr))
+    }
+}
+
+/// Request data extractor
+///
+/// Request data is inserted to `ServerRequest::extensions` before dispatch,
+/// for example auth claims from interceptor.
+#[derive(Clone, Debug)]
+pub struct ReqData<T>(pub T);
+
+impl<T> ReqData<T> {
+    /// Unwrap into inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> ops::Deref for ReqData<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<M, T: Clone + 'static> FromRequest<M> for ReqData<T> {
+    fn from_request(req: &Request<()>, message: &mut Option<M>) -> Result<Self, ServerError> {
+        Self::from_request_optional(req, message)?.ok_or_else(|| {
+            error(
+                GrpcStatus::Internal,
+                &format!("Request data is not available: {}", any::type_name::<T>()),
+            )
+        })
+    }
+
+    fn from_request_optional(
+        req: &Request<()>,
+        _: &mut Option<M>,
+    ) -> Result<Option<Self>, ServerError> {
+        Ok(req.extensions().get::<T>().cloned().map(ReqData))
+    }
+}
+
+/// Application state extractor
+///
+/// State is registered with `GrpcServer::state()`.
+pub struct State<T>(Rc<T>);
+
+impl<T> State<T> {
+    /// Create new state
+    pub fn new(state: T) -> State<T> {
+        State(Rc::new(state))
+    }
+
+    /// Get reference to inner state
+    pub fn get_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Clone for State<T> {
+    fn clone(&self) -> Self {
+        State(self.0.clone())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for State<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("State").field(&self.0).finish()
+    }
+}
+
+impl<T> ops::Deref for State<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<M, T: 'static> FromRequest<M> for State<T> {
+    fn from_request(req: &Request<()>, message: &mut Option<M>) -> Result<Self, ServerError> {
+        Self::from_request_optional(req, message)?.ok_or_else(|| {
+            error(
+                GrpcStatus::Internal,
+                &format!("State is not configured: {}", any::type_name::<T>()),
+            )
+        })
+    }
+
+    fn from_request_optional(
+        req: &Request<()>,
+        _: &mut Option<M>,
+    ) -> Result<Option<Self>, ServerError> {
+        Ok(req.state.get::<State<T>>().cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use ntex_bytes::{ByteString, Bytes};
+    use ntex_http::{HeaderMap, HeaderName};
+    use ntex_util::services::Extensions;
+
+    use super::*;
+    use crate::{google_types::Duration, server::ServerRequest};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Claims(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    struct RequestId(u64);
+
+    impl MetadataValue for RequestId {
+        const KEY: &'static str = "x-request-id";
+
+        fn parse(value: &HeaderValue) -> Option<Self> {
+            value.to_str().ok()?.parse().ok().map(RequestId)
+        }
+    }
+
+    fn request(f: impl FnOnce(&mut ServerRequest)) -> Request<()> {
+        let mut req = ServerRequest::new(
+            ByteString::from_static("test.Service"),
+            ByteString::from_static("Method"),
+            Bytes::new(),
+            HeaderMap::default(),
+        );
+        f(&mut req);
+        req.into_request(())
+    }
+
+    fn extract<T: FromRequest<Duration>>(req: &Request<()>) -> Result<T, ServerError> {
+        T::from_request(req, &mut None)
+    }
+
+    fn message() -> Duration {
+        Duration {
+            seconds: 1,
+            nanos: 0,
+        }
+    }
+
+    #[test]
+    fn message_extractors() {
+        let req = request(|req| {
+            req.extensions.insert(Claims("user"));
+        });
+
+        let mut message = Some(message());
+        let msg: Duration = FromRequest::from_request(&req, &mut message).unwrap();
+        assert_eq!(msg, self::message());
+        let err = Duration::from_request(&req, &mut message).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::Internal);
+
+        let mut message = Some(self::message());
+        let req2: Request<Duration> = FromRequest::from_request(&req, &mut message).unwrap();
+        assert_eq!(req2.message, self::message());
+        assert_eq!(req2.extensions().get::<Claims>(), Some(&Claims("user")));
+        assert!(Request::<Duration>::from_request(&req, &mut message).is_err());
+
+        // optional message is missing only if it is taken
+        let mut message = Some(self::message());
+        let msg: Option<Duration> = FromRequest::from_request(&req, &mut message).unwrap();
+        assert_eq!(msg, Some(self::message()));
+        let msg: Option<Duration> = FromRequest::from_request(&req, &mut message).unwrap();
+        assert_eq!(msg, None);
+    }
+
+    #[test]
+    fn metadata() {
+        let req = request(|req| {
+            req.headers.insert(
+                HeaderName::from_static("x-request-id"),
+                HeaderValue::from_static("10"),
+            );
+        });
+        assert_eq!(
+            *extract::<Metadata<RequestId>>(&req).unwrap(),
+            RequestId(10)
+        );
+        let id = extract::<Option<Metadata<RequestId>>>(&req).unwrap();
+        assert_eq!(id.map(Metadata::into_inner), Some(RequestId(10)));
+
+        // missing metadata
+        let req = request(|_| ());
+        let err = extract::<Metadata<RequestId>>(&req).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::InvalidArgument);
+        assert!(
+            extract::<Option<Metadata<RequestId>>>(&req)
+                .unwrap()
+                .is_none()
+        );
+
+        // invalid metadata fails optional extractor as well
+        let req = request(|req| {
+            req.headers.insert(
+                HeaderName::from_static("x-request-id"),
+                HeaderValue::from_static("id"),
+            );
+        });
+        let err = extract::<Metadata<RequestId>>(&req).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::InvalidArgument);
+        let err = extract::<Option<Metadata<RequestId>>>(&req).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::InvalidArgument);
+    }
+
+    #[test]
+    fn deadline() {
+        let req = request(|_| ());
+        let deadline = extract::<Deadline>(&req).unwrap();
+        assert_eq!(deadline.remaining(), None);
+        assert!(!deadline.is_expired());
+
+        let req = request(|req| {
+            req.deadline = Some(time::Instant::now() + time::Duration::from_secs(60));
+        });
+        let deadline = extract::<Deadline>(&req).unwrap();
+        assert!(deadline.remaining().unwrap() > time::Duration::from_secs(1));
+        assert!(!deadline.is_expired());
+
+        // expired deadline
+        let req = request(|req| {
+            req.deadline = Some(time::Instant::now());
+        });
+        let deadline = extract::<Option<Deadline>>(&req).unwrap().unwrap();
+        assert_eq!(deadline.remaining(), Some(time::Duration::ZERO));
+        assert!(deadline.is_expired());
+    }
+
+    #[test]
+    fn cancellation() {
+        let req = request(|_| ());
+        let cancel = extract::<Cancellation>(&req).unwrap();
+        assert!(!cancel.is_cancelled());
+        req.cancellation().cancel();
+        assert!(cancel.is_cancelled());
+    }
+
+    #[test]
+    fn peer_addr() {
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 8080));
+        let req = request(|req| req.peer_addr = Some(addr));
+        assert_eq!(extract::<PeerAddr>(&req).unwrap(), PeerAddr(addr));
+        assert_eq!(
+            extract::<Option<PeerAddr>>(&req).unwrap(),
+            Some(PeerAddr(addr))
+        );
+
+        let req = request(|_| ());
+        let err = extract::<PeerAddr>(&req).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::Internal);
+        assert_eq!(extract::<Option<PeerAddr>>(&req).unwrap(), None);
+    }
+
+    #[test]
+    fn req_data() {
+        let req = request(|req| {
+            req.extensions.insert(Claims("user"));
+        });
+        let claims = extract::<ReqData<Claims>>(&req).unwrap();
+        assert_eq!(claims.into_inner(), Claims("user"));
+        let claims = extract::<Option<ReqData<Claims>>>(&req).unwrap();
+        assert_eq!(claims.map(ReqData::into_inner), Some(Claims("user")));
+
+        let err = extract::<ReqData<String>>(&req).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::Internal);
+        assert!(extract::<Option<ReqData<String>>>(&req).unwrap().is_none());
+    }
+
+    #[test]
+    fn state() {
+        let req = request(|req| {
+            let mut state = Extensions::new();
+            state.insert(State::new(5usize));
+            req.state = Rc::new(state);
+        });
+        assert_eq!(*extract::<State<usize>>(&req).unwrap(), 5);
+        let state = extract::<Option<State<usize>>>(&req).unwrap();
+        assert_eq!(state.as_deref(), Some(&5));
+
+        let err = extract::<State<String>>(&req).err().unwrap();
+        assert_eq!(err.status, GrpcStatus::Internal);
+        assert!(extract::<Option<State<String>>>(&req).unwrap().is_none());
+    }
+}
@@ -0,0 +1,359 @@
+//! `grpc.health.v1.Health` service implementation
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::{fmt, pin::Pin};
+
+use ntex_bytes::ByteString;
+use ntex_http::HeaderValue;
+use ntex_service::{Service, ServiceCtx, ServiceFactory, cfg::SharedCfg};
+use ntex_util::{HashMap, Stream};
+
+use super::{
+    MethodResponse, NamedService, ResponseStream, ServerError, ServerRequest, ServerResponse,
+};
+use crate::{GrpcStatus, MethodDef, ServiceDef};
+
+mod proto;
+
+pub use self::proto::health_check_response::ServingStatus;
+pub use self::proto::{
+    Health, HealthCheckMethod, HealthCheckRequest, HealthCheckResponse, HealthClient,
+    HealthMethods, HealthWatchMethod,
+};
+
+/// Serving status registry
+///
+/// Reporter is cheap to clone and could be shared between worker threads.
+/// Overall server status is registered under empty service name and
+/// is set to `Serving` on creation.
+#[derive(Clone)]
+pub struct HealthReporter(Arc<Mutex<Inner>>);
+
+#[derive(Default)]
+struct Inner {
+    statuses: HashMap<ByteString, ServingStatus>,
+    watchers: HashMap<usize, Waker>,
+    next_id: usize,
+}
+
+impl HealthReporter {
+    /// Create new reporter
+    pub fn new() -> Self {
+        let mut inner = Inner::default();
+        inner
+            .statuses
+            .insert(ByteString::new(), ServingStatus::Serving);
+        Self(Arc::new(Mutex::new(inner)))
+    }
+
+    fn inner(&self) -> MutexGuard<'_, Inner> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Get serving status of the service
+    pub fn serving_status(&self, service: &str) -> Option<ServingStatus> {
+        self.inner().statuses.get(service).copied()
+    }
+
+    /// Set serving status of the service
+    ///
+    /// Active watchers are notified if status is changed.
+    pub fn set_serving_status(&self, service: &str, status: ServingStatus) {
+        let mut inner = self.inner();
+        if inner.statuses.get(service) != Some(&status) {
+            inner.statuses.insert(ByteString::from(service), status);
+            inner.notify();
+        }
+    }
+
+    /// Set serving status of the `#[server]` service
+    pub fn set_service_status<T: NamedService>(&self, status: ServingStatus) {
+        self.set_serving_status(T::NAME, status);
+    }
+
+    /// Remove service from the registry
+    ///
+    /// Check requests for the service fail with `NotFound` status.
+    pub fn clear_serving_status(&self, service: &str) {
+        let mut inner = self.inner();
+        if inner.statuses.remove(service).is_some() {
+            inner.notify();
+        }
+    }
+
+    /// Set all services to `NotServing` status
+    ///
+    /// Could be used before graceful shutdown of the server.
+    pub fn shutdown(&self) {
+        let mut inner = self.inner();
+        for status in inner.statuses.values_mut() {
+            *status = ServingStatus::NotServing;
+        }
+        inner.notify();
+    }
+}
+
+impl Inner {
+    fn notify(&mut self) {
+        for (_, waker) in self.watchers.drain() {
+            waker.wake();
+        }
+    }
+}
+
+impl Default for HealthReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for HealthReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HealthReporter")
+            .field("statuses", &self.inner().statuses)
+            .finish()
+    }
+}
+
+/// `grpc.health.v1.Health` service
+///
+/// Could be registered in [`Router`](super::Router) next to the user services.
+#[derive(Clone, Debug, Default)]
+pub struct HealthServer {
+    reporter: HealthReporter,
+}
+
+impl HealthServer {
+    /// Create health service for the reporter
+    pub fn new(reporter: HealthReporter) -> Self {
+        Self { reporter }
+    }
+
+    /// Get serving status reporter
+    pub fn reporter(&self) -> &HealthReporter {
+        &self.reporter
+    }
+}
+
+impl NamedService for HealthServer {
+    const NAME: &'static str = <Health as ServiceDef>::NAME;
+}
+
+impl ServiceFactory<ServerRequest, SharedCfg> for HealthServer {
+    type Response = ServerResponse;
+    type Error = ServerError;
+    type InitError = ();
+    type Service = HealthServer;
+
+    async fn create(&self, _: SharedCfg) -> Result<Self::Service, Self::InitError> {
+        Ok(self.clone())
+    }
+}
+
+impl Service<ServerRequest> for HealthServer {
+    type Response = ServerResponse;
+    type Error = ServerError;
+
+    async fn call(
+        &self,
+        mut req: ServerRequest,
+        _: ServiceCtx<'_, Self>,
+    ) -> Result<Self::Response, Self::Error> {
+        match Health::method_by_name(&req.name) {
+            Some(HealthMethods::Check(method)) => {
+                let msg = method.server_request(&mut req).await?;
+                if let Some(status) = self.reporter.serving_status(&msg.service) {
+                    HealthCheckResponse { status }.into_response(&method)
+                } else {
+                    Err(ServerError::new(
+                        GrpcStatus::NotFound,
+                        HeaderValue::from_static("Service is unknown"),
+                        None,
+                    ))
+                }
+            }
+            Some(HealthMethods::Watch(method)) => {
+                let msg = method.server_request(&mut req).await?;
+                ResponseStream::new(Watch::new(self.reporter.clone(), msg.service))
+                    .into_response(&method)
+            }
+            None => Err(ServerError::new(
+                GrpcStatus::Unimplemented,
+                HeaderValue::from_str(&format!("Service method is not found: {}", req.name))
+                    .unwrap_or(HeaderValue::from_static("Service method is not found")),
+                None,
+            )),
+        }
+    }
+}
+
+/// Stream of serving status changes
+struct Watch {
+    id: usize,
+    reporter: HealthReporter,
+    service: ByteString,
+    last: Option<ServingStatus>,
+}
+
+impl Watch {
+    fn new(reporter: HealthReporter, service: ByteString) -> Self {
+        let id = {
+            let mut inner = reporter.inner();
+            inner.next_id = inner.next_id.wrapping_add(1);
+            inner.next_id
+        };
+        Self {
+            id,
+            reporter,
+            service,
+            last: None,
+        }
+    }
+}
+
+impl Stream for Watch {
+    type Item = Result<HealthCheckResponse, ServerError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let mut inner = this.reporter.inner();
+        let status = inner
+            .statuses
+            .get(&this.service)
+            .copied()
+            .unwrap_or(ServingStatus::ServiceUnknown);
+
+        if this.last == Some(status) {
+            inner.watchers.insert(this.id, cx.waker().clone());
+            Poll::Pending
+        } else {
+            this.last = Some(status);
+            Poll::Ready(Some(Ok(HealthCheckResponse { status })))
+        }
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        self.reporter.inner().watchers.remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex_bytes::BytesMut;
+    use ntex_http::HeaderMap;
+    use ntex_service::Pipeline;
+
+    use super::*;
+    use crate::Message;
+    use crate::server::{ServerError, ServerRequest};
+
+    #[test]
+    fn watch() {
+        let reporter = HealthReporter::new();
+        assert_eq!(reporter.serving_status(""), Some(ServingStatus::Serving));
+        assert_eq!(reporter.serving_status("test.Service"), None);
+
+        let mut watch = Watch::new(reporter.clone(), ByteString::from_static("test.Service"));
+        let next =
+            |watch: &mut Watch| Pin::new(watch).poll_next(&mut Context::from_waker(Waker::noop()));
+        assert!(matches!(
+            next(&mut watch),
+            Poll::Ready(Some(Ok(HealthCheckResponse {
+                status: ServingStatus::ServiceUnknown
+            })))
+        ));
+        assert!(next(&mut watch).is_pending());
+        assert_eq!(reporter.inner().watchers.len(), 1);
+
+        reporter.set_serving_status("test.Service", ServingStatus::Serving);
+        assert!(matches!(
+            next(&mut watch),
+            Poll::Ready(Some(Ok(HealthCheckResponse {
+                status: ServingStatus::Serving
+            })))
+        ));
+
+        reporter.shutdown();
+        assert_eq!(reporter.serving_status(""), Some(ServingStatus::NotServing));
+        assert!(matches!(
+            next(&mut watch),
+            Poll::Ready(Some(Ok(HealthCheckResponse {
+                status: ServingStatus::NotServing
+            })))
+        ));
+
+        drop(watch);
+        assert!(reporter.inner().watchers.is_empty());
+    }
+
+    fn check_request(service: &'static str) -> ServerRequest {
+        let mut buf = BytesMut::new();
+        HealthCheckRequest {
+            service: ByteString::from_static(service),
+        }
+        .write(&mut buf);
+        ServerRequest::new(
+            ByteString::from_static("grpc.health.v1.Health"),
+            ByteString::from_static("Check"),
+            buf.freeze(),
+            HeaderMap::default(),
+        )
+    }
+
+    #[ntex::test]
+    async fn check() {
+        let reporter = HealthReporter::new();
+        reporter.set_serving_status("test.Service", ServingStatus::NotServing);
+        let srv = Pipeline::new(
+            HealthServer::new(reporter.clone())
+                .create(SharedCfg::default())
+                .await
+                .unwrap(),
+        );
+        let status = async |service| {
+            let mut res = srv.call(check_request(service)).await?;
+            Ok::<_, ServerError>(HealthCheckResponse::read(&mut res.payload).unwrap().status)
+        };
+
+        assert_eq!(status("").await.unwrap(), ServingStatus::Serving);
+        assert_eq!(
+            status("test.Service").await.unwrap(),
+            ServingStatus::NotServing
+        );
+        let err = status("test.Unknown").await.unwrap_err();
+        assert_eq!(err.status, GrpcStatus::NotFound);
+
+        reporter.set_service_status::<HealthServer>(ServingStatus::Serving);
+        assert_eq!(
+            status("grpc.health.v1.Health").await.unwrap(),
+            ServingStatus::Serving
+        );
+
+        reporter.shutdown();
+        assert_eq!(status("").await.unwrap(), ServingStatus::NotServing);
+
+        reporter.clear_serving_status("test.Service");
+        let err = status("test.Service").await.unwrap_err();
+        assert_eq!(err.status, GrpcStatus::NotFound);
+    }
+
+    #[ntex::test]
+    async fn unknown_method() {
+        let srv = Pipeline::new(
+            HealthServer::default()
+                .create(SharedCfg::default())
+                .await
+                .unwrap(),
+        );
+        let req = ServerRequest::new(
+            ByteString::from_static("grpc.health.v1.Health"),
+            ByteString::from_static("Unknown"),
+            ntex_bytes::Bytes::new(),
+            HeaderMap::default(),
+        );
+        let err = srv.call(req).await.unwrap_err();
+        assert_eq!(err.status, GrpcStatus::Unimplemented);
+    }
+}
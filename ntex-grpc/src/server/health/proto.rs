@@ -0,0 +1,452 @@
+#![allow(
+    async_fn_in_trait,
+    dead_code,
+    unused_mut,
+    unused_variables,
+    clippy::identity_op,
+    clippy::derivable_impls,
+    clippy::unit_arg,
+    clippy::derive_partial_eq_without_eq,
+    clippy::manual_range_patterns,
+    clippy::wildcard_imports,
+    clippy::default_trait_access,
+    clippy::cast_sign_loss,
+    clippy::cast_possible_wrap,
+    clippy::doc_markdown
+)]
+//! DO NOT MODIFY. Auto-generated file
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct HealthCheckRequest {
+    pub service: crate::ByteString,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct HealthCheckResponse {
+    pub status: health_check_response::ServingStatus,
+}
+
+/// Nested message and enum types in `HealthCheckResponse`.
+pub mod health_check_response {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[repr(i32)]
+    pub enum ServingStatus {
+        Unknown = 0,
+        Serving = 1,
+        NotServing = 2,
+        ///  Used only by the Watch method.
+        ServiceUnknown = 3,
+    }
+
+    impl ServingStatus {
+        /// String value of the enum field names used in the ProtoBuf definition with stripped prefix.
+        pub fn to_str_name(self) -> &'static str {
+            match self {
+                ServingStatus::Unknown => "UNKNOWN",
+                ServingStatus::Serving => "SERVING",
+                ServingStatus::NotServing => "NOT_SERVING",
+                ServingStatus::ServiceUnknown => "SERVICE_UNKNOWN",
+            }
+        }
+
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn to_origin_name(self) -> &'static str {
+            match self {
+                ServingStatus::Unknown => "UNKNOWN",
+                ServingStatus::Serving => "SERVING",
+                ServingStatus::NotServing => "NOT_SERVING",
+                ServingStatus::ServiceUnknown => "SERVICE_UNKNOWN",
+            }
+        }
+
+        pub fn from_i32(value: i32) -> ::std::option::Option<Self> {
+            match value {
+                0 => Some(ServingStatus::Unknown),
+                1 => Some(ServingStatus::Serving),
+                2 => Some(ServingStatus::NotServing),
+                3 => Some(ServingStatus::ServiceUnknown),
+                _ => ::std::option::Option::None,
+            }
+        }
+    }
+}
+
+/// `Health` service definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Health;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthMethods {
+    Check(HealthCheckMethod),
+    Watch(HealthWatchMethod),
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthClient<T>(T);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HealthCheckMethod;
+
+impl crate::MethodDef for HealthCheckMethod {
+    const NAME: &'static str = "Check";
+    const PATH: crate::ByteString = crate::ByteString::from_static("/grpc.health.v1.Health/Check");
+    type Input = HealthCheckRequest;
+    type Output = HealthCheckResponse;
+    type Request = HealthCheckRequest;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HealthWatchMethod;
+
+impl crate::MethodDef for HealthWatchMethod {
+    const NAME: &'static str = "Watch";
+    const PATH: crate::ByteString = crate::ByteString::from_static("/grpc.health.v1.Health/Watch");
+    type Input = HealthCheckRequest;
+    type Output = HealthCheckResponse;
+    type Request = HealthCheckRequest;
+}
+
+/// `Health` service server
+///
+/// Use `into_service()` for registering implementation in the grpc router.
+pub trait HealthServer {
+    ///  If the requested service is unknown, the call will fail with status
+    ///  NOT_FOUND.
+    async fn check(
+        &self,
+        req: crate::server::Request<HealthCheckRequest>,
+    ) -> ::std::result::Result<
+        crate::server::Response<HealthCheckResponse>,
+        crate::server::ServerError,
+    >;
+
+    ///  Performs a watch for the serving status of the requested service.
+    ///  The server will immediately send back a message indicating the current
+    ///  serving status.  It will then subsequently send a new message whenever
+    ///  the service's serving status changes.
+    async fn watch(
+        &self,
+        req: crate::server::Request<HealthCheckRequest>,
+    ) -> ::std::result::Result<
+        crate::server::ResponseStream<HealthCheckResponse>,
+        crate::server::ServerError,
+    >;
+
+    /// Convert implementation to grpc service
+    fn into_service(self) -> HealthServerAdapter<Self>
+    where
+        Self: Sized,
+    {
+        HealthServerAdapter(self)
+    }
+}
+
+/// Grpc service for `HealthServer` implementation
+#[derive(Debug, Clone)]
+pub struct HealthServerAdapter<T>(pub T);
+
+mod _priv_impl_grpc_health_v1 {
+    use super::*;
+
+    impl crate::Message for HealthCheckRequest {
+        #[inline]
+        fn write(&self, dst: &mut crate::BytesMut) {
+            crate::NativeType::serialize(
+                &self.service,
+                1,
+                crate::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
+            let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "HealthCheckRequest";
+            while !src.is_empty() {
+                let (tag, wire_type) = crate::encoding::decode_key(src)?;
+                match tag {
+                    1 => crate::NativeType::deserialize(&mut self.service, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "service"))?,
+                    _ => crate::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + crate::NativeType::serialized_len(
+                &self.service,
+                1,
+                crate::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for HealthCheckRequest {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                service: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl crate::Message for HealthCheckResponse {
+        #[inline]
+        fn write(&self, dst: &mut crate::BytesMut) {
+            crate::NativeType::serialize(
+                &self.status,
+                1,
+                crate::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(src: &mut crate::Bytes) -> ::std::result::Result<Self, crate::DecodeError> {
+            let mut msg = Self::default();
+            crate::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            const STRUCT_NAME: &str = "HealthCheckResponse";
+            while !src.is_empty() {
+                let (tag, wire_type) = crate::encoding::decode_key(src)?;
+                match tag {
+                    1 => crate::NativeType::deserialize(&mut self.status, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "status"))?,
+                    _ => crate::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + crate::NativeType::serialized_len(
+                &self.status,
+                1,
+                crate::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for HealthCheckResponse {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                status: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl crate::NativeType for health_check_response::ServingStatus {
+        const TYPE: crate::WireType = crate::WireType::Varint;
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut crate::Bytes,
+        ) -> ::std::result::Result<(), crate::DecodeError> {
+            *self = crate::encoding::decode_varint(src)
+                .map(|val| Self::from_i32(val as i32).unwrap_or_default())?;
+            Ok(())
+        }
+
+        #[inline]
+        fn encode_value(&self, dst: &mut crate::BytesMut) {
+            crate::encoding::encode_varint(*self as i32 as u64, dst);
+        }
+
+        #[inline]
+        fn encoded_len(&self, tag: u32) -> usize {
+            crate::encoding::key_len(tag)
+                + crate::encoding::encoded_len_varint(*self as i32 as u64)
+        }
+
+        #[inline]
+        fn value_len(&self) -> usize {
+            crate::encoding::encoded_len_varint(*self as i32 as u64)
+        }
+
+        #[inline]
+        fn is_default(&self) -> bool {
+            self == &health_check_response::ServingStatus::Unknown
+        }
+    }
+
+    impl ::std::default::Default for health_check_response::ServingStatus {
+        #[inline]
+        fn default() -> Self {
+            health_check_response::ServingStatus::Unknown
+        }
+    }
+
+    impl crate::types::Enumeration for health_check_response::ServingStatus {
+        #[inline]
+        fn from_i32(value: i32) -> ::std::option::Option<Self> {
+            health_check_response::ServingStatus::from_i32(value)
+        }
+
+        #[inline]
+        fn to_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl crate::ServiceDef for Health {
+        const NAME: &'static str = "grpc.health.v1.Health";
+        type Methods = HealthMethods;
+
+        #[inline]
+        fn method_by_name(name: &str) -> Option<Self::Methods> {
+            use crate::MethodDef;
+            match name {
+                HealthCheckMethod::NAME => Some(HealthMethods::Check(HealthCheckMethod)),
+                HealthWatchMethod::NAME => Some(HealthMethods::Watch(HealthWatchMethod)),
+                _ => None,
+            }
+        }
+    }
+
+    impl<T> HealthClient<T> {
+        #[inline]
+        /// Create new client instance
+        pub fn new(transport: T) -> Self {
+            Self(transport)
+        }
+    }
+
+    impl<T> crate::client::ClientInformation<T> for HealthClient<T> {
+        #[inline]
+        /// Create new client instance
+        fn create(transport: T) -> Self {
+            Self(transport)
+        }
+
+        #[inline]
+        /// Get referece to underlying transport
+        fn transport(&self) -> &T {
+            &self.0
+        }
+
+        #[inline]
+        /// Get mut referece to underlying transport
+        fn transport_mut(&mut self) -> &mut T {
+            &mut self.0
+        }
+
+        #[inline]
+        /// Consume client and return inner transport
+        fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<T: crate::client::Transport<HealthCheckMethod>> HealthClient<T> {
+        ///  If the requested service is unknown, the call will fail with status
+        ///  NOT_FOUND.
+        pub fn check<'a>(
+            &'a self,
+            req: &'a super::HealthCheckRequest,
+        ) -> crate::client::Request<'a, T, HealthCheckMethod> {
+            crate::client::Request::new(&self.0, req)
+        }
+    }
+
+    impl<T: crate::client::StreamingTransport<HealthWatchMethod>> HealthClient<T> {
+        ///  Performs a watch for the serving status of the requested service.
+        ///  The server will immediately send back a message indicating the current
+        ///  serving status.  It will then subsequently send a new message whenever
+        ///  the service's serving status changes.
+        pub fn watch<'a>(
+            &'a self,
+            req: &'a super::HealthCheckRequest,
+        ) -> crate::client::StreamingRequest<'a, T, HealthWatchMethod> {
+            crate::client::StreamingRequest::new(&self.0, req)
+        }
+    }
+
+    impl<T: HealthServer> crate::server::NamedService for HealthServerAdapter<T> {
+        const NAME: &'static str = <Health as crate::ServiceDef>::NAME;
+    }
+
+    impl<T: HealthServer> crate::Service<crate::server::ServerRequest> for HealthServerAdapter<T> {
+        type Response = crate::server::ServerResponse;
+        type Error = crate::server::ServerError;
+
+        async fn call(
+            &self,
+            mut req: crate::server::ServerRequest,
+            _: crate::ServiceCtx<'_, Self>,
+        ) -> ::std::result::Result<Self::Response, Self::Error> {
+            use crate::{MethodDef, ServiceDef};
+
+            match Health::method_by_name(&req.name) {
+                Some(HealthMethods::Check(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    crate::server::MethodResponse::into_response(
+                        HealthServer::check(&self.0, req).await,
+                        &method,
+                    )
+                }
+                Some(HealthMethods::Watch(method)) => {
+                    let message = method.server_request(&mut req).await?;
+                    let req = req.into_request(message);
+                    crate::server::MethodResponse::into_response(
+                        HealthServer::watch(&self.0, req).await,
+                        &method,
+                    )
+                }
+                None => Err(crate::server::ServerError::new(
+                    crate::GrpcStatus::Unimplemented,
+                    crate::HeaderValue::from_str(&format!(
+                        "Service method is not found: {}",
+                        req.name
+                    ))
+                    .unwrap_or(crate::HeaderValue::from_static(
+                        "Service method is not found",
+                    )),
+                    None,
+                )),
+            }
+        }
+    }
+
+    impl<T: HealthServer + Clone + 'static>
+        crate::ServiceFactory<crate::server::ServerRequest, crate::SharedCfg>
+        for HealthServerAdapter<T>
+    {
+        type Response = crate::server::ServerResponse;
+        type Error = crate::server::ServerError;
+        type InitError = ();
+        type Service = HealthServerAdapter<T>;
+
+        async fn create(
+            &self,
+            _: crate::SharedCfg,
+        ) -> ::std::result::Result<Self::Service, Self::InitError> {
+            Ok(self.clone())
+        }
+    }
+}
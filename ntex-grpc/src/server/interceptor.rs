@@ -0,0 +1,203 @@
+#![allow(async_fn_in_trait)]
+
+use std::{rc::Rc, time};
+
+use ntex_bytes::ByteString;
+use ntex_service::{Middleware, Service, ServiceCtx, cfg::SharedCfg};
+
+use super::{ServerError, ServerRequest, ServerResponse};
+
+/// Server calls interceptor
+///
+/// Interceptor is called for every request before dispatching it to the service,
+/// it could inspect or modify request metadata or reject the call.
+pub trait Interceptor {
+    /// Inspect request before dispatch
+    ///
+    /// Returned error rejects the call, service is not called.
+    async fn request(&self, req: &mut ServerRequest) -> Result<(), ServerError> {
+        let _ = req;
+        Ok(())
+    }
+
+    /// Observe call result
+    ///
+    /// Called for rejected calls as well. For streaming methods result is
+    /// available as soon as service returns response stream.
+    fn response(&self, info: &CallInfo, res: &mut Result<ServerResponse, ServerError>) {
+        let _ = (info, res);
+    }
+}
+
+/// Information about intercepted call
+#[derive(Clone, Debug)]
+pub struct CallInfo {
+    /// Full service name, `package.Service`
+    pub service: ByteString,
+    /// Method name
+    pub name: ByteString,
+    /// Call duration
+    pub elapsed: time::Duration,
+}
+
+/// Middleware that applies interceptor to the service
+///
+/// ```rust,ignore
+/// GrpcServer::new(apply(Intercept::new(Auth), Router::new().service(GreeterServer)))
+/// ```
+#[derive(Debug)]
+pub struct Intercept<I>(Rc<I>);
+
+impl<I> Intercept<I> {
+    /// Create middleware for interceptor
+    pub fn new(interceptor: I) -> Self {
+        Self(Rc::new(interceptor))
+    }
+}
+
+impl<I> Clone for Intercept<I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I, S> Middleware<S, SharedCfg> for Intercept<I> {
+    type Service = InterceptService<I, S>;
+
+    fn create(&self, service: S, _: SharedCfg) -> Self::Service {
+        InterceptService {
+            service,
+            interceptor: self.0.clone(),
+        }
+    }
+}
+
+/// Service with applied interceptor
+#[derive(Debug)]
+pub struct InterceptService<I, S> {
+    service: S,
+    interceptor: Rc<I>,
+}
+
+impl<I, S: Clone> Clone for InterceptService<I, S> {
+    fn clone(&self) -> Self {
+        Self {
+            service: self.service.clone(),
+            interceptor: self.interceptor.clone(),
+        }
+    }
+}
+
+impl<I, S> Service<ServerRequest> for InterceptService<I, S>
+where
+    I: Interceptor,
+    S: Service<ServerRequest, Response = ServerResponse, Error = ServerError>,
+{
+    type Response = ServerResponse;
+    type Error = ServerError;
+
+    ntex_service::forward_ready!(service);
+    ntex_service::forward_poll!(service);
+    ntex_service::forward_shutdown!(service);
+
+    async fn call(
+        &self,
+        mut req: ServerRequest,
+        ctx: ServiceCtx<'_, Self>,
+    ) -> Result<Self::Response, Self::Error> {
+        let start = time::Instant::now();
+        let service = req.service.clone();
+        let name = req.name.clone();
+
+        let mut res = match self.interceptor.request(&mut req).await {
+            Ok(()) => ctx.call(&self.service, req).await,
+            Err(err) => Err(err),
+        };
+
+        let info = CallInfo {
+            service,
+            name,
+            elapsed: start.elapsed(),
+        };
+        self.interceptor.response(&info, &mut res);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use ntex_bytes::Bytes;
+    use ntex_http::{HeaderMap, HeaderValue, header};
+    use ntex_service::{Pipeline, ServiceFactory, apply, fn_factory, fn_service};
+
+    use super::*;
+    use crate::GrpcStatus;
+
+    #[derive(Default)]
+    struct Auth(RefCell<Vec<(ByteString, Option<GrpcStatus>)>>);
+
+    impl Interceptor for Rc<Auth> {
+        async fn request(&self, req: &mut ServerRequest) -> Result<(), ServerError> {
+            if req.headers.contains_key(header::AUTHORIZATION) {
+                Ok(())
+            } else {
+                Err(ServerError::new(
+                    GrpcStatus::Unauthenticated,
+                    HeaderValue::from_static("Not authorized"),
+                    None,
+                ))
+            }
+        }
+
+        fn response(&self, info: &CallInfo, res: &mut Result<ServerResponse, ServerError>) {
+            let status = res.as_ref().err().map(|err| err.status);
+            self.0.borrow_mut().push((info.name.clone(), status));
+        }
+    }
+
+    #[ntex::test]
+    async fn intercept() {
+        let auth = Rc::new(Auth::default());
+        let factory = apply(
+            Intercept::new(auth.clone()),
+            fn_factory(|| async {
+                Ok::<_, ()>(fn_service(|mut req: ServerRequest| async move {
+                    Ok::<_, ServerError>(ServerResponse::new(req.message().await?))
+                }))
+            }),
+        );
+        let srv = Pipeline::new(factory.create(SharedCfg::default()).await.unwrap());
+
+        let req = |name: &'static str, headers: HeaderMap| {
+            ServerRequest::new(
+                ByteString::from_static("test.Service"),
+                ByteString::from_static(name),
+                Bytes::from_static(b"data"),
+                headers,
+            )
+        };
+        let err = srv
+            .call(req("First", HeaderMap::default()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, GrpcStatus::Unauthenticated);
+
+        let mut headers = HeaderMap::default();
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("token"));
+        let res = srv.call(req("Second", headers)).await.unwrap();
+        assert_eq!(res.payload, Bytes::from_static(b"data"));
+
+        assert_eq!(
+            &*auth.0.borrow(),
+            &[
+                (
+                    ByteString::from_static("First"),
+                    Some(GrpcStatus::Unauthenticated)
+                ),
+                (ByteString::from_static("Second"), None)
+            ]
+        );
+    }
+}
@@ -1,26 +1,146 @@
-use std::ops;
+use std::{fmt, mem, net::SocketAddr, ops, pin::Pin, rc::Rc, time};
 
 use ntex_bytes::{ByteString, Bytes};
 use ntex_http::{HeaderMap, HeaderName, HeaderValue};
+use ntex_util::{Stream, services::Extensions};
 
+mod cancel;
 mod error;
+mod extract;
+mod interceptor;
+mod propagate;
+mod router;
 mod service;
+mod streaming;
 
-pub use self::error::{MethodResult, ServerError};
+pub mod health;
+pub mod reflection;
+
+pub use self::cancel::Cancellation;
+pub use self::error::{MethodResponse, MethodResult, ServerError};
+pub use self::extract::{
+    Deadline, FromRequest, Metadata, MetadataValue, PeerAddr, ReqData, State,
+};
+pub use self::interceptor::{CallInfo, Intercept, InterceptService, Interceptor};
+pub use self::propagate::Propagation;
+pub use self::router::{NamedService, Router, RouterService};
 pub use self::service::{GrpcServer, GrpcService};
+pub use self::streaming::{ResponseSender, ResponseStream, Streaming};
 pub use crate::GrpcStatus;
 
+type BodyStream = Pin<Box<dyn Stream<Item = Result<Bytes, ServerError>>>>;
+
+const ERR_NO_MESSAGE: HeaderValue = HeaderValue::from_static("Request message is missing");
+const ERR_MANY_MESSAGES: HeaderValue =
+    HeaderValue::from_static("Unary request contains more than one message");
+
 #[derive(Debug)]
 pub struct ServerRequest {
+    /// Full service name, `package.Service`
+    pub service: ByteString,
+    /// Method name
     pub name: ByteString,
-    pub payload: Bytes,
     pub headers: HeaderMap,
+    /// Request data, available for handlers with `ReqData` extractor
+    pub extensions: Extensions,
+    pub(crate) peer_addr: Option<SocketAddr>,
+    pub(crate) deadline: Option<time::Instant>,
+    pub(crate) cancellation: Cancellation,
+    pub(crate) state: Rc<Extensions>,
+    pub(crate) stream: Option<streaming::Payload>,
+}
+
+impl ServerRequest {
+    /// Create request with single message payload
+    pub fn new(service: ByteString, name: ByteString, payload: Bytes, headers: HeaderMap) -> Self {
+        let (tx, rx) = streaming::Payload::channel();
+        tx.send(Ok(streaming::Chunk::Message(payload)));
+        Self {
+            service,
+            name,
+            headers,
+            extensions: Extensions::new(),
+            peer_addr: None,
+            deadline: None,
+            cancellation: Cancellation::new(),
+            state: Rc::default(),
+            stream: Some(rx),
+        }
+    }
+
+    /// Peer address
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        self.peer_addr
+    }
+
+    /// Call deadline
+    pub fn deadline(&self) -> Option<time::Instant> {
+        self.deadline
+    }
+
+    /// Call cancellation signal
+    pub fn cancellation(&self) -> &Cancellation {
+        &self.cancellation
+    }
+
+    /// Convert to typed request
+    pub fn into_request<T>(self, message: T) -> Request<T> {
+        Request {
+            message,
+            name: self.name,
+            headers: self.headers,
+            peer_addr: self.peer_addr,
+            deadline: self.deadline,
+            cancellation: self.cancellation,
+            extensions: Rc::new(self.extensions),
+            state: self.state,
+        }
+    }
+
+    /// Receive request message of unary or server streaming method
+    ///
+    /// Waits for the end of request stream, request trailers are appended
+    /// to request headers. Fails with `Internal` status if request
+    /// contains no messages or more than one message.
+    pub async fn message(&mut self) -> Result<Bytes, ServerError> {
+        let Some(stream) = self.stream.take() else {
+            return Err(ServerError::new(GrpcStatus::Internal, ERR_NO_MESSAGE, None));
+        };
+
+        let mut message = None;
+        while let Some(chunk) = stream.recv().await {
+            match chunk? {
+                streaming::Chunk::Message(msg) => {
+                    if message.replace(msg).is_some() {
+                        return Err(ServerError::new(
+                            GrpcStatus::Internal,
+                            ERR_MANY_MESSAGES,
+                            None,
+                        ));
+                    }
+                }
+                streaming::Chunk::Trailers(trailers) => {
+                    for (name, val) in &trailers {
+                        self.headers.append(name.clone(), val.clone());
+                    }
+                }
+            }
+        }
+        message.ok_or(ServerError::new(GrpcStatus::Internal, ERR_NO_MESSAGE, None))
+    }
+
+    /// Take stream of request messages
+    ///
+    /// Stream contains all request messages of the call.
+    pub fn take_stream<T: crate::Message>(&mut self) -> Streaming<T> {
+        Streaming::new(mem::take(&mut self.stream))
+    }
 }
 
-#[derive(Debug)]
 pub struct ServerResponse {
     pub payload: Bytes,
     pub headers: Vec<(HeaderName, HeaderValue)>,
+    pub(crate) stream: Option<BodyStream>,
 }
 
 impl ServerResponse {
@@ -34,38 +154,116 @@ impl ServerResponse {
         payload: Bytes,
         headers: Vec<(HeaderName, HeaderValue)>,
     ) -> ServerResponse {
-        ServerResponse { payload, headers }
+        ServerResponse {
+            payload,
+            headers,
+            stream: None,
+        }
+    }
+
+    /// Create streaming response
+    ///
+    /// Each stream item is encoded message, headers are sent as trailers.
+    pub fn with_stream<S>(stream: S, headers: Vec<(HeaderName, HeaderValue)>) -> ServerResponse
+    where
+        S: Stream<Item = Result<Bytes, ServerError>> + 'static,
+    {
+        ServerResponse {
+            headers,
+            payload: Bytes::new(),
+            stream: Some(Box::pin(stream)),
+        }
+    }
+
+    #[inline]
+    /// Check if response is streaming response
+    pub fn is_stream(&self) -> bool {
+        self.stream.is_some()
     }
 }
 
-pub trait FromRequest<T> {
-    fn from(input: Request<T>) -> Self;
+impl fmt::Debug for ServerResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServerResponse")
+            .field("payload", &self.payload)
+            .field("headers", &self.headers)
+            .field("stream", &self.stream.is_some())
+            .finish()
+    }
 }
 
 pub struct Request<T> {
     pub name: ByteString,
     pub headers: HeaderMap,
     pub message: T,
+    peer_addr: Option<SocketAddr>,
+    deadline: Option<time::Instant>,
+    cancellation: Cancellation,
+    extensions: Rc<Extensions>,
+    state: Rc<Extensions>,
 }
 
-impl<T> FromRequest<T> for T {
-    #[inline]
-    fn from(input: Request<T>) -> T {
-        input.message
-    }
-}
-
-impl<T> FromRequest<T> for Request<T> {
-    #[inline]
-    fn from(input: Request<T>) -> Request<T> {
-        input
+impl<T> Request<T> {
+    /// Create request without call metadata
+    pub fn new(name: ByteString, headers: HeaderMap, message: T) -> Self {
+        Self {
+            name,
+            headers,
+            message,
+            peer_addr: None,
+            deadline: None,
+            cancellation: Cancellation::default(),
+            extensions: Rc::default(),
+            state: Rc::default(),
+        }
     }
-}
 
-impl<T> Request<T> {
     pub fn into_inner(self) -> T {
         self.message
     }
+
+    /// Peer address
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        self.peer_addr
+    }
+
+    /// Call deadline
+    pub fn deadline(&self) -> Option<time::Instant> {
+        self.deadline
+    }
+
+    /// Time remaining until call deadline, `None` if deadline is not set
+    pub fn remaining(&self) -> Option<time::Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(time::Instant::now()))
+    }
+
+    /// Call cancellation signal
+    ///
+    /// Handler could use it to abort work early, if client is gone
+    /// or deadline is exceeded.
+    pub fn cancellation(&self) -> &Cancellation {
+        &self.cancellation
+    }
+
+    /// Request data
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Create request with same metadata and new message
+    pub(crate) fn with_message<U>(&self, message: U) -> Request<U> {
+        Request {
+            message,
+            name: self.name.clone(),
+            headers: self.headers.clone(),
+            peer_addr: self.peer_addr,
+            deadline: self.deadline,
+            cancellation: self.cancellation.clone(),
+            extensions: self.extensions.clone(),
+            state: self.state.clone(),
+        }
+    }
 }
 
 impl<T> ops::Deref for Request<T> {
@@ -0,0 +1,127 @@
+use std::time;
+
+use ntex_http::HeaderName;
+
+use super::Request;
+use crate::client::RequestContext;
+
+/// Minimal timeout of outgoing call, zero timeout means no deadline
+const MIN_TIMEOUT: time::Duration = time::Duration::from_millis(1);
+
+/// Call context propagation settings
+///
+/// Describes how context of incoming call is propagated to outgoing
+/// client calls, see `Request::client_context()`.
+#[derive(Clone, Debug, Default)]
+pub struct Propagation {
+    margin: time::Duration,
+    headers: Vec<HeaderName>,
+}
+
+impl Propagation {
+    /// Create propagation settings
+    ///
+    /// By default only call deadline is propagated.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set deadline margin
+    ///
+    /// Margin is subtracted from remaining deadline, it reserves time for
+    /// processing of outgoing call response. Default is zero.
+    #[must_use]
+    pub fn margin<U>(mut self, margin: U) -> Self
+    where
+        time::Duration: From<U>,
+    {
+        self.margin = margin.into();
+        self
+    }
+
+    /// Propagate request metadata
+    #[must_use]
+    pub fn header(mut self, name: HeaderName) -> Self {
+        self.headers.push(name);
+        self
+    }
+}
+
+impl<T> Request<T> {
+    /// Create context for outgoing client call
+    ///
+    /// Outgoing call timeout is set to remaining call deadline minus
+    /// configured margin, selected metadata is copied from request.
+    ///
+    /// ```rust,ignore
+    /// let ctx = req.client_context(&Propagation::new().margin(Millis(50)));
+    /// let res = client.say_hello(&msg).context(ctx).await?;
+    /// ```
+    pub fn client_context(&self, cfg: &Propagation) -> RequestContext {
+        let mut ctx = RequestContext::new();
+        if let Some(remaining) = self.remaining() {
+            ctx.timeout(remaining.saturating_sub(cfg.margin).max(MIN_TIMEOUT));
+        }
+        for name in &cfg.headers {
+            for value in self.headers.get_all(name) {
+                ctx.header(name.clone(), value.clone());
+            }
+        }
+        ctx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex_bytes::{ByteString, Bytes};
+    use ntex_http::{HeaderMap, HeaderValue};
+
+    use super::*;
+    use crate::consts;
+    use crate::server::ServerRequest;
+
+    #[test]
+    fn client_context() {
+        let mut headers = HeaderMap::default();
+        headers.insert(
+            HeaderName::from_static("x-request-id"),
+            HeaderValue::from_static("1"),
+        );
+        headers.insert(
+            HeaderName::from_static("x-other"),
+            HeaderValue::from_static("2"),
+        );
+        let mut req = ServerRequest::new(
+            ByteString::from_static("test.Service"),
+            ByteString::from_static("Method"),
+            Bytes::new(),
+            headers,
+        )
+        .into_request(());
+
+        let cfg = Propagation::new()
+            .margin(time::Duration::from_millis(100))
+            .header(HeaderName::from_static("x-request-id"));
+
+        // no deadline
+        let ctx = req.client_context(&cfg);
+        assert_eq!(ctx.get_timeout(), None);
+
+        req.deadline = Some(time::Instant::now() + time::Duration::from_secs(10));
+        let ctx = req.client_context(&cfg);
+        let timeout = ctx.get_timeout().unwrap();
+        assert!(timeout <= time::Duration::from_millis(9900));
+        assert!(timeout > time::Duration::from_secs(9));
+        let names: Vec<_> = ctx
+            .headers()
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(names, [consts::GRPC_TIMEOUT.as_str(), "x-request-id"]);
+
+        // margin is larger than remaining deadline
+        req.deadline = Some(time::Instant::now() + time::Duration::from_millis(50));
+        let ctx = req.client_context(&cfg);
+        assert_eq!(ctx.get_timeout(), Some(MIN_TIMEOUT));
+    }
+}
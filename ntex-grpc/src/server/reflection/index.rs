@@ -0,0 +1,259 @@
+use ntex_bytes::{ByteString, Bytes};
+use ntex_util::HashMap;
+
+use crate::encoding::{WireType, decode_key, skip_field};
+use crate::{DecodeError, NativeType};
+
+/// Index of the registered proto files
+#[derive(Clone, Default)]
+pub(super) struct Index {
+    /// Encoded `FileDescriptorProto` by file name
+    files: HashMap<ByteString, File>,
+    /// Fully-qualified symbol name to file name
+    symbols: HashMap<ByteString, ByteString>,
+    /// Extended type name to extension numbers with file name
+    extensions: HashMap<ByteString, Vec<(i32, ByteString)>>,
+    services: Vec<ByteString>,
+}
+
+#[derive(Clone)]
+struct File {
+    proto: Bytes,
+    dependencies: Vec<ByteString>,
+}
+
+impl Index {
+    /// Add all files of encoded `FileDescriptorSet`
+    pub(super) fn add_file_descriptor_set(&mut self, src: &[u8]) -> Result<(), DecodeError> {
+        let mut files = Vec::<Bytes>::new();
+        decode_fields(Bytes::copy_from_slice(src), |tag, wire_type, src| {
+            if tag == 1 {
+                files.deserialize(tag, wire_type, src)?;
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        })?;
+
+        for proto in files {
+            self.add_file(proto)?;
+        }
+        Ok(())
+    }
+
+    /// Add encoded `FileDescriptorProto`
+    fn add_file(&mut self, proto: Bytes) -> Result<(), DecodeError> {
+        let mut name = ByteString::new();
+        let mut package = ByteString::new();
+        let mut dependencies = Vec::new();
+        let mut messages = Vec::<Bytes>::new();
+        let mut enums = Vec::<Bytes>::new();
+        let mut services = Vec::<Bytes>::new();
+        let mut extensions = Vec::<Bytes>::new();
+
+        decode_fields(proto.clone(), |tag, wire_type, src| {
+            match tag {
+                1 => name.deserialize(tag, wire_type, src)?,
+                2 => package.deserialize(tag, wire_type, src)?,
+                3 => dependencies.deserialize(tag, wire_type, src)?,
+                4 => messages.deserialize(tag, wire_type, src)?,
+                5 => enums.deserialize(tag, wire_type, src)?,
+                6 => services.deserialize(tag, wire_type, src)?,
+                7 => extensions.deserialize(tag, wire_type, src)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        let mut extensions: Vec<_> = extensions
+            .into_iter()
+            .map(|ext| (package.clone(), ext))
+            .collect();
+
+        let mut symbols = Vec::new();
+        for msg in messages {
+            message_symbols(&package, msg, &mut symbols, &mut extensions)?;
+        }
+        for item in enums {
+            symbols.push(full_name(&package, &decode_name(item)?));
+        }
+        for item in services {
+            let mut srv_name = ByteString::new();
+            let mut methods = Vec::<Bytes>::new();
+            decode_fields(item, |tag, wire_type, src| {
+                match tag {
+                    1 => srv_name.deserialize(tag, wire_type, src)?,
+                    2 => methods.deserialize(tag, wire_type, src)?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+
+            let srv_name = full_name(&package, &srv_name);
+            for method in methods {
+                symbols.push(full_name(&srv_name, &decode_name(method)?));
+            }
+            if !self.services.contains(&srv_name) {
+                self.services.push(srv_name.clone());
+            }
+            symbols.push(srv_name);
+        }
+        for (scope, item) in extensions {
+            let mut ext_name = ByteString::new();
+            let mut extendee = ByteString::new();
+            let mut number = 0i32;
+            decode_fields(item, |tag, wire_type, src| {
+                match tag {
+                    1 => ext_name.deserialize(tag, wire_type, src)?,
+                    2 => extendee.deserialize(tag, wire_type, src)?,
+                    3 => number.deserialize(tag, wire_type, src)?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+
+            let extendee = ByteString::from(extendee.trim_start_matches('.'));
+            let entries = self.extensions.entry(extendee).or_default();
+            entries.retain(|(n, _)| *n != number);
+            entries.push((number, name.clone()));
+            symbols.push(full_name(&scope, &ext_name));
+        }
+
+        for symbol in symbols {
+            self.symbols.insert(symbol, name.clone());
+        }
+        self.files.insert(
+            name,
+            File {
+                proto,
+                dependencies,
+            },
+        );
+        Ok(())
+    }
+
+    /// Full names of registered services
+    pub(super) fn services(&self) -> &[ByteString] {
+        &self.services
+    }
+
+    /// Encoded file with transitive dependencies
+    pub(super) fn file_by_name(&self, name: &str) -> Option<Vec<Bytes>> {
+        if !self.files.contains_key(name) {
+            return None;
+        }
+
+        let mut result = Vec::new();
+        let mut visited = Vec::<&str>::new();
+        let mut queue = vec![name];
+        while let Some(name) = queue.pop() {
+            if visited.contains(&name) {
+                continue;
+            }
+            visited.push(name);
+            // missing dependencies are not reported
+            if let Some(file) = self.files.get(name) {
+                result.push(file.proto.clone());
+                queue.extend(file.dependencies.iter().rev().map(AsRef::as_ref));
+            }
+        }
+        Some(result)
+    }
+
+    /// Encoded file that declares the symbol, with transitive dependencies
+    pub(super) fn file_by_symbol(&self, symbol: &str) -> Option<Vec<Bytes>> {
+        self.symbols
+            .get(symbol)
+            .and_then(|name| self.file_by_name(name))
+    }
+
+    /// Encoded file that declares the extension, with transitive dependencies
+    pub(super) fn file_by_extension(&self, extendee: &str, number: i32) -> Option<Vec<Bytes>> {
+        self.extensions
+            .get(extendee)?
+            .iter()
+            .find(|(n, _)| *n == number)
+            .and_then(|(_, name)| self.file_by_name(name))
+    }
+
+    /// Extension numbers of the type
+    pub(super) fn extension_numbers(&self, extendee: &str) -> Option<Vec<i32>> {
+        if let Some(entries) = self.extensions.get(extendee) {
+            Some(entries.iter().map(|(n, _)| *n).collect())
+        } else if self.symbols.contains_key(extendee) {
+            Some(Vec::new())
+        } else {
+            None
+        }
+    }
+}
+
+/// Collect symbols of `DescriptorProto` and its nested types
+fn message_symbols(
+    scope: &str,
+    proto: Bytes,
+    symbols: &mut Vec<ByteString>,
+    extensions: &mut Vec<(ByteString, Bytes)>,
+) -> Result<(), DecodeError> {
+    let mut name = ByteString::new();
+    let mut nested = Vec::<Bytes>::new();
+    let mut enums = Vec::<Bytes>::new();
+    let mut exts = Vec::<Bytes>::new();
+
+    decode_fields(proto, |tag, wire_type, src| {
+        match tag {
+            1 => name.deserialize(tag, wire_type, src)?,
+            3 => nested.deserialize(tag, wire_type, src)?,
+            4 => enums.deserialize(tag, wire_type, src)?,
+            6 => exts.deserialize(tag, wire_type, src)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    let name = full_name(scope, &name);
+    extensions.extend(exts.into_iter().map(|ext| (name.clone(), ext)));
+    for msg in nested {
+        message_symbols(&name, msg, symbols, extensions)?;
+    }
+    for item in enums {
+        symbols.push(full_name(&name, &decode_name(item)?));
+    }
+    symbols.push(name);
+    Ok(())
+}
+
+/// Decode `name` field of the descriptor
+fn decode_name(proto: Bytes) -> Result<ByteString, DecodeError> {
+    let mut name = ByteString::new();
+    decode_fields(proto, |tag, wire_type, src| {
+        if tag == 1 {
+            name.deserialize(tag, wire_type, src)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    Ok(name)
+}
+
+fn full_name(scope: &str, name: &str) -> ByteString {
+    if scope.is_empty() {
+        ByteString::from(name)
+    } else {
+        ByteString::from(format!("{scope}.{name}"))
+    }
+}
+
+/// Decode message fields, unhandled fields are skipped
+fn decode_fields<F>(mut src: Bytes, mut f: F) -> Result<(), DecodeError>
+where
+    F: FnMut(u32, WireType, &mut Bytes) -> Result<bool, DecodeError>,
+{
+    while !src.is_empty() {
+        let (tag, wire_type) = decode_key(&mut src)?;
+        if !f(tag, wire_type, &mut src)? {
+            skip_field(wire_type, tag, &mut src)?;
+        }
+    }
+    Ok(())
+}
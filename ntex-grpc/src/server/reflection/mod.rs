@@ -0,0 +1,360 @@
+//! `grpc.reflection.v1.ServerReflection` service implementation
+//!
+//! Service is built from encoded `FileDescriptorSet`, generated code embeds it
+//! as `FILE_DESCRIPTOR_SET` constant if `ntex-grpc-codegen` is called with
+//! `--descriptor-set` option.
+//!
+//! ```rust,ignore
+//! let reflection = ReflectionServer::new()
+//!     .register_file_descriptor_set(helloworld::FILE_DESCRIPTOR_SET)?;
+//!
+//! Router::new()
+//!     .service(GreeterServer)
+//!     .service(reflection.clone())
+//!     .named_service(reflection::V1ALPHA, reflection)
+//! ```
+use std::task::{Context, Poll};
+use std::{fmt, pin::Pin, sync::Arc};
+
+use ntex_bytes::ByteString;
+use ntex_http::HeaderValue;
+use ntex_service::{Service, ServiceCtx, ServiceFactory, cfg::SharedCfg};
+use ntex_util::Stream;
+
+use super::{
+    MethodResponse, NamedService, ResponseStream, ServerError, ServerRequest, ServerResponse,
+    Streaming,
+};
+use crate::{DecodeError, GrpcStatus, ServiceDef};
+
+mod index;
+mod proto;
+
+use self::index::Index;
+use self::proto::server_reflection_request::MessageRequest;
+use self::proto::server_reflection_response::MessageResponse;
+
+pub use self::proto::{
+    ErrorResponse, ExtensionNumberResponse, ExtensionRequest, FileDescriptorResponse,
+    ListServiceResponse, ServerReflection, ServerReflectionClient, ServerReflectionMethods,
+    ServerReflectionRequest, ServerReflectionResponse, ServerReflectionServerReflectionInfoMethod,
+    ServiceResponse, server_reflection_request, server_reflection_response,
+};
+
+/// Service name of `v1alpha` reflection protocol
+///
+/// Protocol is identical to `v1`, service could be registered under
+/// both names with [`Router::named_service`](super::Router::named_service).
+pub const V1ALPHA: &str = "grpc.reflection.v1alpha.ServerReflection";
+
+/// `grpc.reflection.v1.ServerReflection` service
+///
+/// Server is cheap to clone and could be shared between worker threads.
+#[derive(Clone, Default)]
+pub struct ReflectionServer {
+    index: Arc<Index>,
+}
+
+impl ReflectionServer {
+    /// Create reflection service without registered files
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register files of encoded `FileDescriptorSet`
+    ///
+    /// All services declared in files are reported by `list_services` request.
+    pub fn register_file_descriptor_set(mut self, set: &[u8]) -> Result<Self, DecodeError> {
+        Arc::make_mut(&mut self.index).add_file_descriptor_set(set)?;
+        Ok(self)
+    }
+
+    fn reply(&self, req: ServerReflectionRequest) -> ServerReflectionResponse {
+        let files = |files: Option<_>, msg: &str, name: &str| {
+            if let Some(file_descriptor_proto) = files {
+                MessageResponse::FileDescriptorResponse(FileDescriptorResponse {
+                    file_descriptor_proto,
+                })
+            } else {
+                error(GrpcStatus::NotFound, format!("{msg} not found: {name}"))
+            }
+        };
+
+        let response = match req.message_request {
+            Some(MessageRequest::FileByFilename(ref name)) => {
+                files(self.index.file_by_name(name), "File", name)
+            }
+            Some(MessageRequest::FileContainingSymbol(ref name)) => {
+                files(self.index.file_by_symbol(name), "Symbol", name)
+            }
+            Some(MessageRequest::FileContainingExtension(ref ext)) => files(
+                self.index
+                    .file_by_extension(&ext.containing_type, ext.extension_number),
+                "Extension",
+                &format!("{}({})", ext.containing_type, ext.extension_number),
+            ),
+            Some(MessageRequest::AllExtensionNumbersOfType(ref name)) => {
+                if let Some(extension_number) = self.index.extension_numbers(name) {
+                    MessageResponse::AllExtensionNumbersResponse(ExtensionNumberResponse {
+                        base_type_name: name.clone(),
+                        extension_number,
+                    })
+                } else {
+                    error(GrpcStatus::NotFound, format!("Type not found: {name}"))
+                }
+            }
+            Some(MessageRequest::ListServices(_)) => {
+                MessageResponse::ListServicesResponse(ListServiceResponse {
+                    service: self
+                        .index
+                        .services()
+                        .iter()
+                        .map(|name| ServiceResponse { name: name.clone() })
+                        .collect(),
+                })
+            }
+            None => error(
+                GrpcStatus::InvalidArgument,
+                "Message request is not set".to_string(),
+            ),
+        };
+
+        ServerReflectionResponse {
+            valid_host: req.host.clone(),
+            original_request: req,
+            message_response: Some(response),
+        }
+    }
+}
+
+fn error(status: GrpcStatus, error_message: String) -> MessageResponse {
+    MessageResponse::ErrorResponse(ErrorResponse {
+        error_code: i32::from(status.code()),
+        error_message: ByteString::from(error_message),
+    })
+}
+
+impl fmt::Debug for ReflectionServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectionServer")
+            .field("services", &self.index.services())
+            .finish()
+    }
+}
+
+impl NamedService for ReflectionServer {
+    const NAME: &'static str = <ServerReflection as ServiceDef>::NAME;
+}
+
+impl ServiceFactory<ServerRequest, SharedCfg> for ReflectionServer {
+    type Response = ServerResponse;
+    type Error = ServerError;
+    type InitError = ();
+    type Service = ReflectionServer;
+
+    async fn create(&self, _: SharedCfg) -> Result<Self::Service, Self::InitError> {
+        Ok(self.clone())
+    }
+}
+
+impl Service<ServerRequest> for ReflectionServer {
+    type Response = ServerResponse;
+    type Error = ServerError;
+
+    async fn call(
+        &self,
+        mut req: ServerRequest,
+        _: ServiceCtx<'_, Self>,
+    ) -> Result<Self::Response, Self::Error> {
+        match ServerReflection::method_by_name(&req.name) {
+            Some(ServerReflectionMethods::ServerReflectionInfo(method)) => {
+                ResponseStream::new(Info {
+                    server: self.clone(),
+                    requests: req.take_stream(),
+                })
+                .into_response(&method)
+            }
+            None => Err(ServerError::new(
+                GrpcStatus::Unimplemented,
+                HeaderValue::from_str(&format!("Service method is not found: {}", req.name))
+                    .unwrap_or(HeaderValue::from_static("Service method is not found")),
+                None,
+            )),
+        }
+    }
+}
+
+/// Stream of replies to reflection requests
+struct Info {
+    server: ReflectionServer,
+    requests: Streaming<ServerReflectionRequest>,
+}
+
+impl Stream for Info {
+    type Item = Result<ServerReflectionResponse, ServerError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        this.requests
+            .poll_recv(cx)
+            .map(|item| item.map(|res| res.map(|req| this.server.reply(req))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex_bytes::{Bytes, BytesMut};
+    use ntex_http::HeaderMap;
+    use ntex_service::Pipeline;
+
+    use super::*;
+    use crate::encoding::{WireType, encode_key, encode_varint};
+
+    fn field(tag: u32, value: &[u8], dst: &mut BytesMut) {
+        encode_key(tag, WireType::LengthDelimited, dst);
+        encode_varint(value.len() as u64, dst);
+        dst.extend_from_slice(value);
+    }
+
+    fn message(fields: &[(u32, &[u8])]) -> Bytes {
+        let mut buf = BytesMut::new();
+        for (tag, value) in fields {
+            field(*tag, value, &mut buf);
+        }
+        buf.freeze()
+    }
+
+    fn request(message_request: MessageRequest) -> ServerReflectionRequest {
+        ServerReflectionRequest {
+            host: ByteString::new(),
+            message_request: Some(message_request),
+        }
+    }
+
+    fn files(res: ServerReflectionResponse) -> Vec<Bytes> {
+        match res.message_response {
+            Some(MessageResponse::FileDescriptorResponse(res)) => res.file_descriptor_proto,
+            res => panic!("unexpected response: {res:?}"),
+        }
+    }
+
+    #[test]
+    fn reflection() {
+        let mut ext = BytesMut::from(&message(&[(1, b"ext"), (2, b".base.Base")])[..]);
+        encode_key(3, WireType::Varint, &mut ext);
+        encode_varint(100, &mut ext);
+
+        let base = message(&[
+            (1, b"base.proto"),
+            (2, b"base"),
+            (
+                4,
+                &message(&[(1, b"Base"), (3, &message(&[(1, b"Nested")]))]),
+            ),
+        ]);
+        let greeter = message(&[
+            (1, b"greeter.proto"),
+            (2, b"hello"),
+            (3, b"base.proto"),
+            (4, &message(&[(1, b"Request")])),
+            (5, &message(&[(1, b"Kind")])),
+            (
+                6,
+                &message(&[(1, b"Greeter"), (2, &message(&[(1, b"SayHello")]))]),
+            ),
+            (7, &ext),
+        ]);
+        let set = message(&[(1, &base), (1, &greeter)]);
+
+        let srv = ReflectionServer::new()
+            .register_file_descriptor_set(&set)
+            .unwrap();
+        assert_eq!(srv.index.services(), &["hello.Greeter"]);
+
+        let res = srv.reply(request(MessageRequest::ListServices(ByteString::new())));
+        assert_eq!(
+            res.message_response,
+            Some(MessageResponse::ListServicesResponse(ListServiceResponse {
+                service: vec![ServiceResponse {
+                    name: ByteString::from_static("hello.Greeter")
+                }]
+            }))
+        );
+
+        let symbol = |name: &'static str| {
+            MessageRequest::FileContainingSymbol(ByteString::from_static(name))
+        };
+        assert_eq!(
+            files(srv.reply(request(symbol("hello.Greeter")))),
+            vec![greeter.clone(), base.clone()]
+        );
+        assert_eq!(
+            files(srv.reply(request(symbol("hello.Greeter.SayHello")))),
+            vec![greeter.clone(), base.clone()]
+        );
+        assert_eq!(
+            files(srv.reply(request(symbol("hello.Kind")))),
+            vec![greeter.clone(), base.clone()]
+        );
+        assert_eq!(
+            files(srv.reply(request(symbol("base.Base.Nested")))),
+            vec![base.clone()]
+        );
+        assert_eq!(
+            files(srv.reply(request(MessageRequest::FileByFilename(
+                ByteString::from_static("base.proto")
+            )))),
+            vec![base.clone()]
+        );
+        assert_eq!(
+            files(srv.reply(request(MessageRequest::FileContainingExtension(
+                ExtensionRequest {
+                    containing_type: ByteString::from_static("base.Base"),
+                    extension_number: 100,
+                }
+            )))),
+            vec![greeter.clone(), base.clone()]
+        );
+
+        let res = srv.reply(request(MessageRequest::AllExtensionNumbersOfType(
+            ByteString::from_static("base.Base"),
+        )));
+        assert_eq!(
+            res.message_response,
+            Some(MessageResponse::AllExtensionNumbersResponse(
+                ExtensionNumberResponse {
+                    base_type_name: ByteString::from_static("base.Base"),
+                    extension_number: vec![100],
+                }
+            ))
+        );
+
+        let res = srv.reply(request(symbol("hello.Unknown")));
+        assert!(matches!(
+            res.message_response,
+            Some(MessageResponse::ErrorResponse(ErrorResponse {
+                error_code: 5,
+                ..
+            }))
+        ));
+        assert_eq!(res.original_request, request(symbol("hello.Unknown")));
+    }
+
+    #[ntex::test]
+    async fn unknown_method() {
+        let srv = Pipeline::new(
+            ReflectionServer::new()
+                .create(SharedCfg::default())
+                .await
+                .unwrap(),
+        );
+        let req = ServerRequest::new(
+            ByteString::from_static(V1ALPHA),
+            ByteString::from_static("Unknown"),
+            Bytes::new(),
+            HeaderMap::default(),
+        );
+        let err = srv.call(req).await.unwrap_err();
+        assert_eq!(err.status, GrpcStatus::Unimplemented);
+    }
+}
@@ -0,0 +1,165 @@
+use std::rc::Rc;
+
+use ntex_bytes::ByteString;
+use ntex_http::HeaderValue;
+use ntex_service::boxed::{self, BoxService, BoxServiceFactory};
+use ntex_service::{Service, ServiceCtx, ServiceFactory, cfg::SharedCfg};
+use ntex_util::HashMap;
+
+use super::{ServerError, ServerRequest, ServerResponse};
+use crate::status::GrpcStatus;
+
+type BoxFactory = BoxServiceFactory<SharedCfg, ServerRequest, ServerResponse, ServerError, ()>;
+
+/// Service with grpc service name
+///
+/// Implemented by `#[server]` macro.
+pub trait NamedService {
+    /// Full service name, `package.Service`
+    const NAME: &'static str;
+}
+
+/// Grpc services router
+///
+/// Dispatches requests to services by the service part of request path.
+/// Requests for unknown services are rejected with `Unimplemented` status.
+///
+/// ```rust,ignore
+/// GrpcServer::new(Router::new().service(GreeterServer).service(HealthServer::default()))
+/// ```
+#[derive(Default)]
+pub struct Router {
+    services: Vec<(ByteString, BoxFactory)>,
+}
+
+impl Router {
+    /// Create empty router
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register service factory
+    ///
+    /// Service name is taken from [`NamedService`] implementation.
+    #[must_use]
+    pub fn service<T>(self, factory: T) -> Self
+    where
+        T: ServiceFactory<
+                ServerRequest,
+                SharedCfg,
+                Response = ServerResponse,
+                Error = ServerError,
+                InitError = (),
+            > + 'static,
+        T::Service: NamedService + 'static,
+    {
+        self.named_service(<T::Service as NamedService>::NAME, factory)
+    }
+
+    /// Register service factory with explicit service name
+    ///
+    /// Previously registered service with the same name is replaced.
+    #[must_use]
+    pub fn named_service<N, T>(mut self, name: N, factory: T) -> Self
+    where
+        N: Into<ByteString>,
+        T: ServiceFactory<
+                ServerRequest,
+                SharedCfg,
+                Response = ServerResponse,
+                Error = ServerError,
+                InitError = (),
+            > + 'static,
+        T::Service: 'static,
+    {
+        let name = name.into();
+        self.services.retain(|(n, _)| *n != name);
+        self.services.push((name, boxed::factory(factory)));
+        self
+    }
+}
+
+impl ServiceFactory<ServerRequest, SharedCfg> for Router {
+    type Response = ServerResponse;
+    type Error = ServerError;
+    type InitError = ();
+    type Service = RouterService;
+
+    async fn create(&self, cfg: SharedCfg) -> Result<Self::Service, Self::InitError> {
+        let mut services = HashMap::default();
+        for (name, factory) in &self.services {
+            services.insert(name.clone(), factory.create(cfg.clone()).await?);
+        }
+        Ok(RouterService {
+            services: Rc::new(services),
+        })
+    }
+}
+
+/// Grpc services router service
+#[derive(Clone)]
+pub struct RouterService {
+    services: Rc<HashMap<ByteString, BoxService<ServerRequest, ServerResponse, ServerError>>>,
+}
+
+impl Service<ServerRequest> for RouterService {
+    type Response = ServerResponse;
+    type Error = ServerError;
+
+    async fn call(
+        &self,
+        req: ServerRequest,
+        ctx: ServiceCtx<'_, Self>,
+    ) -> Result<Self::Response, Self::Error> {
+        if let Some(srv) = self.services.get(&req.service) {
+            ctx.call(srv, req).await
+        } else {
+            Err(ServerError::new(
+                GrpcStatus::Unimplemented,
+                HeaderValue::from_str(&format!("Service is not implemented: {}", req.service))
+                    .unwrap_or(HeaderValue::from_static("Service is not implemented")),
+                None,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex_bytes::Bytes;
+    use ntex_http::HeaderMap;
+    use ntex_service::{Pipeline, fn_factory, fn_service};
+
+    use super::*;
+
+    #[ntex::test]
+    async fn routing() {
+        let factory = |msg: &'static str| {
+            fn_factory(move || async move {
+                Ok::<_, ()>(fn_service(move |_: ServerRequest| async move {
+                    Ok::<_, ServerError>(ServerResponse::new(Bytes::from_static(msg.as_bytes())))
+                }))
+            })
+        };
+        let router = Router::new()
+            .named_service("test.First", factory("first"))
+            .named_service("test.Second", factory("second"));
+        let srv = Pipeline::new(router.create(SharedCfg::default()).await.unwrap());
+
+        let req = |service: &'static str| {
+            ServerRequest::new(
+                ByteString::from_static(service),
+                ByteString::from_static("Method"),
+                Bytes::new(),
+                HeaderMap::default(),
+            )
+        };
+        let res = srv.call(req("test.Second")).await.unwrap();
+        assert_eq!(res.payload, Bytes::from_static(b"second"));
+        let res = srv.call(req("test.First")).await.unwrap();
+        assert_eq!(res.payload, Bytes::from_static(b"first"));
+
+        let err = srv.call(req("test.Third")).await.unwrap_err();
+        assert_eq!(err.status, GrpcStatus::Unimplemented);
+    }
+}
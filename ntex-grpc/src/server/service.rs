@@ -1,22 +1,25 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, net::SocketAddr, rc::Rc, time};
 
-use ntex_bytes::{Buf, BufMut, ByteString, BytesMut};
-use ntex_h2::{self as h2, StreamRef, frame::Reason, frame::StreamId};
+use ntex_bytes::{Bytes, BytesMut};
+use ntex_h2::{self as h2, StreamRef, frame::StreamId};
 use ntex_http::{HeaderMap, HeaderValue, StatusCode, header::CONTENT_TYPE};
-use ntex_io::{Filter, Io, IoBoxed};
+use ntex_io::{Filter, Io, IoBoxed, types::PeerAddr};
 use ntex_service::{Service, ServiceCtx, ServiceFactory, cfg::SharedCfg};
-use ntex_util::{HashMap, time::Millis, time::timeout_checked};
+use ntex_util::time::{Millis, timeout_checked};
+use ntex_util::{HashMap, future::stream_recv, services::Extensions};
 
-use crate::{consts, status::GrpcStatus, utils::Data};
+use crate::utils::{DEFAULT_MAX_MESSAGE_SIZE, FrameDecoder, encode_frame};
+use crate::{CompressionEncoding, consts, status::GrpcStatus};
 
-use super::{ServerError, ServerRequest, ServerResponse};
+use super::streaming::{Chunk, Payload, PayloadSender};
+use super::{Cancellation, ServerError, ServerRequest, ServerResponse, State};
 
 const ERR_DECODE: HeaderValue =
     HeaderValue::from_static("Cannot decode request message: not enough data provided");
-const ERR_DATA_DECODE: HeaderValue =
-    HeaderValue::from_static("Cannot decode request message: not enough data provided");
 const ERR_DECODE_TIMEOUT: HeaderValue =
     HeaderValue::from_static("Cannot decode grpc-timeout header");
+const ERR_ENCODING: HeaderValue = HeaderValue::from_static("Message encoding is not supported");
+const ERR_STREAM: HeaderValue = HeaderValue::from_static("Request stream is disconnected");
 const ERR_DEADLINE: HeaderValue = HeaderValue::from_static("Deadline exceeded");
 const HDR_APP_GRPC: HeaderValue = HeaderValue::from_static("application/grpc");
 
@@ -26,6 +29,8 @@ const MILLIS_IN_MINUTE: u64 = 60 * 1000;
 /// Grpc server
 pub struct GrpcServer<T> {
     factory: Rc<T>,
+    state: Rc<Extensions>,
+    max_message_size: usize,
 }
 
 impl<T> GrpcServer<T> {
@@ -33,8 +38,35 @@ impl<T> GrpcServer<T> {
     pub fn new(factory: T) -> Self {
         Self {
             factory: Rc::new(factory),
+            state: Rc::default(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
         }
     }
+
+    /// Set max size of received message
+    ///
+    /// Calls with larger messages fail with `ResourceExhausted` status.
+    /// By default max size is 4 MiB.
+    #[must_use]
+    pub fn max_message_size(mut self, size: usize) -> Self {
+        self.max_message_size = size;
+        self
+    }
+
+    /// Set application state
+    ///
+    /// State is available for handlers with `State<T>` extractor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if server is already started.
+    #[must_use]
+    pub fn state<S: 'static>(mut self, state: S) -> Self {
+        Rc::get_mut(&mut self.state)
+            .expect("State must be set before server is started")
+            .insert(State::new(state));
+        self
+    }
 }
 
 impl<T> GrpcServer<T>
@@ -49,6 +81,8 @@ where
         GrpcService {
             cfg,
             factory: self.factory.clone(),
+            state: self.state.clone(),
+            max_message_size: self.max_message_size,
         }
     }
 }
@@ -73,6 +107,8 @@ where
 pub struct GrpcService<T> {
     cfg: SharedCfg,
     factory: Rc<T>,
+    state: Rc<Extensions>,
+    max_message_size: usize,
 }
 
 impl<T, F> Service<Io<F>> for GrpcService<T>
@@ -87,10 +123,17 @@ where
     async fn call(&self, io: Io<F>, _: ServiceCtx<'_, Self>) -> Result<(), Self::Error> {
         // init server
         let service = self.factory.create(self.cfg.clone()).await?;
+        let peer_addr = io.query::<PeerAddr>().get().map(|addr| addr.0);
 
         let _ = h2::server::handle_one(
             io.into(),
-            PublishService::new(service, self.cfg.clone()),
+            PublishService::new(
+                service,
+                self.cfg.clone(),
+                peer_addr,
+                self.state.clone(),
+                self.max_message_size,
+            ),
             ControlService,
         )
         .await;
@@ -110,10 +153,17 @@ where
     async fn call(&self, io: IoBoxed, _: ServiceCtx<'_, Self>) -> Result<(), Self::Error> {
         // init server
         let service = self.factory.create(self.cfg.clone()).await?;
+        let peer_addr = io.query::<PeerAddr>().get().map(|addr| addr.0);
 
         let _ = h2::server::handle_one(
             io,
-            PublishService::new(service, self.cfg.clone()),
+            PublishService::new(
+                service,
+                self.cfg.clone(),
+                peer_addr,
+                self.state.clone(),
+                self.max_message_size,
+            ),
             ControlService,
         )
         .await;
@@ -141,25 +191,180 @@ impl Service<h2::Control<h2::StreamError>> for ControlService {
 struct PublishService<S: Service<ServerRequest>> {
     cfg: SharedCfg,
     service: S,
+    peer_addr: Option<SocketAddr>,
+    state: Rc<Extensions>,
+    max_message_size: usize,
     streams: RefCell<HashMap<StreamId, Inflight>>,
+    cancels: RefCell<HashMap<StreamId, Cancellation>>,
 }
 
 struct Inflight {
-    name: ByteString,
-    service: ByteString,
-    data: Data,
-    headers: HeaderMap,
+    data: FrameDecoder,
+    encoding: CompressionEncoding,
+    payload: PayloadSender,
+}
+
+impl Inflight {
+    /// Decode complete messages and send them to the request stream
+    fn decode(&mut self, eof: bool) -> Result<(), ServerError> {
+        while let Some(frame) = self.data.decode()? {
+            let msg = if frame.compressed {
+                self.encoding
+                    .decompress(&frame.payload, self.data.max_size())?
+            } else {
+                frame.payload
+            };
+            self.payload.send(Ok(Chunk::Message(msg)));
+        }
+        if eof {
+            self.data
+                .finish()
+                .map_err(|_| ServerError::new(GrpcStatus::InvalidArgument, ERR_DECODE, None))?;
+        }
+        Ok(())
+    }
+
+    /// Terminate request stream with error
+    fn fail(&self, err: ServerError) {
+        self.payload.fail(err);
+    }
 }
 
 impl<S> PublishService<S>
 where
     S: Service<ServerRequest, Response = ServerResponse, Error = ServerError>,
 {
-    fn new(service: S, cfg: SharedCfg) -> Self {
+    fn new(
+        service: S,
+        cfg: SharedCfg,
+        peer_addr: Option<SocketAddr>,
+        state: Rc<Extensions>,
+        max_message_size: usize,
+    ) -> Self {
         Self {
             cfg,
             service,
+            peer_addr,
+            state,
+            max_message_size,
             streams: RefCell::new(HashMap::default()),
+            cancels: RefCell::new(HashMap::default()),
+        }
+    }
+
+    /// Cancel in-flight service call
+    fn cancel(&self, id: StreamId) {
+        if let Some(cancellation) = self.cancels.borrow_mut().remove(&id) {
+            log::debug!("{}: Service call is cancelled", self.cfg.tag());
+            cancellation.cancel();
+        }
+    }
+
+    async fn dispatch(
+        &self,
+        stream: StreamRef,
+        mut req: ServerRequest,
+        ctx: ServiceCtx<'_, Self>,
+    ) {
+        log::debug!(
+            "{}: Call service {} method {}",
+            self.cfg.tag(),
+            req.service,
+            req.name
+        );
+        // Reply with request encoding if client accepts it
+        let encoding = CompressionEncoding::from_headers(&req.headers)
+            .unwrap_or_default()
+            .accepted(&req.headers);
+        let mut headers = hdrs();
+        if encoding != CompressionEncoding::Identity {
+            headers.insert(consts::GRPC_ENCODING, encoding.header_value());
+        }
+        if stream
+            .send_response(StatusCode::OK, headers, false)
+            .is_err()
+        {
+            return;
+        }
+
+        // GRPC Timeout
+        let to = if let Some(to) = req.headers.get(consts::GRPC_TIMEOUT) {
+            if let Ok(to) = try_parse_grpc_timeout(to) {
+                to
+            } else {
+                send_error(&stream, GrpcStatus::InvalidArgument, ERR_DECODE_TIMEOUT);
+                return;
+            }
+        } else {
+            Millis::ZERO
+        };
+        if !to.is_zero() {
+            req.deadline = Some(time::Instant::now() + time::Duration::from(to));
+        }
+        req.peer_addr = self.peer_addr;
+        req.state = self.state.clone();
+
+        // client could reset stream during service call
+        let cancellation = req.cancellation.clone();
+        let mut guard = CancelGuard::new(&self.cancels, stream.id(), &cancellation);
+
+        let fut = async {
+            let res = ctx.call(&self.service, req).await?;
+            log::debug!("{}: Response is received {res:?}", self.cfg.tag());
+
+            if let Some(mut body) = res.stream {
+                while let Some(item) = stream_recv(&mut body).await {
+                    if stream
+                        .send_payload(frame(&item?, encoding), false)
+                        .await
+                        .is_err()
+                    {
+                        cancellation.cancel();
+                        break;
+                    }
+                }
+            } else if stream
+                .send_payload(frame(&res.payload, encoding), false)
+                .await
+                .is_err()
+            {
+                cancellation.cancel();
+            }
+            Ok::<_, ServerError>(res.headers)
+        };
+
+        let result = timeout_checked(to, fut).await;
+        guard.completed = true;
+
+        match result {
+            Ok(Ok(headers)) => {
+                let mut trailers = HeaderMap::default();
+                trailers.insert(consts::GRPC_STATUS, GrpcStatus::Ok.into());
+                for (name, val) in headers {
+                    trailers.append(name, val);
+                }
+
+                stream.send_trailers(trailers);
+            }
+            Ok(Err(err)) => {
+                log::debug!(
+                    "{}: Failure during service call: {:?}",
+                    self.cfg.tag(),
+                    err.message
+                );
+                let mut trailers = err.headers;
+                trailers.insert(consts::GRPC_STATUS, err.status.into());
+                trailers.insert(consts::GRPC_MESSAGE, err.message);
+                stream.send_trailers(trailers);
+            }
+            Err(()) => {
+                log::debug!(
+                    "{}: Deadline exceeded failure during service call",
+                    self.cfg.tag()
+                );
+                cancellation.cancel();
+                send_error(&stream, GrpcStatus::DeadlineExceeded, ERR_DEADLINE);
+            }
         }
     }
 }
@@ -171,7 +376,7 @@ where
     type Response = ();
     type Error = h2::StreamError;
 
-    #[allow(clippy::await_holding_refcell_ref, clippy::too_many_lines)]
+    #[allow(clippy::too_many_lines)]
     async fn call(
         &self,
         msg: h2::Message,
@@ -179,7 +384,6 @@ where
     ) -> Result<Self::Response, Self::Error> {
         let id = msg.id();
         let h2::Message { stream, kind } = msg;
-        let mut streams = self.streams.borrow_mut();
 
         match kind {
             h2::MessageKind::Headers {
@@ -196,13 +400,24 @@ where
                     return Ok(());
                 };
 
-                // stream eof, cannot do anything
-                if eof {
-                    if stream.send_response(StatusCode::OK, hdrs(), false).is_ok() {
-                        send_error(&stream, GrpcStatus::InvalidArgument, ERR_DECODE);
+                let encoding = match CompressionEncoding::from_headers(&headers) {
+                    Ok(encoding) => encoding,
+                    Err(name) => {
+                        if stream.send_response(StatusCode::OK, hdrs(), false).is_ok() {
+                            let mut trailers = HeaderMap::default();
+                            trailers.insert(consts::GRPC_STATUS, GrpcStatus::Unimplemented.into());
+                            trailers.insert(
+                                consts::GRPC_MESSAGE,
+                                HeaderValue::from_str(&format!(
+                                    "Message encoding is not supported: {name}"
+                                ))
+                                .unwrap_or(ERR_ENCODING),
+                            );
+                            stream.send_trailers(trailers);
+                        }
+                        return Ok(());
                     }
-                    return Ok(());
-                }
+                };
 
                 let mut path = path.split_off(1);
                 let methodname = if let Some(n) = path.find('/') {
@@ -211,129 +426,150 @@ where
                     path
                 };
 
-                let _ = streams.insert(
-                    stream.id(),
-                    Inflight {
-                        headers,
-                        data: Data::Empty,
-                        name: methodname,
-                        service: srvname,
-                    },
-                );
+                // request messages are delivered to the running service call,
+                // stream without payload is closed immediately
+                let (tx, rx) = Payload::channel();
+                if !eof {
+                    let _ = self.streams.borrow_mut().insert(
+                        stream.id(),
+                        Inflight {
+                            data: FrameDecoder::new(self.max_message_size),
+                            encoding,
+                            payload: tx,
+                        },
+                    );
+                }
+
+                let req = ServerRequest {
+                    service: srvname,
+                    name: methodname,
+                    headers,
+                    extensions: Extensions::new(),
+                    peer_addr: None,
+                    deadline: None,
+                    cancellation: Cancellation::new(),
+                    state: Rc::default(),
+                    stream: Some(rx),
+                };
+                self.dispatch(stream, req, ctx).await;
+
+                // request messages received after the call completion are dropped
+                let _ = self.streams.borrow_mut().remove(&id);
             }
-            h2::MessageKind::Data(data, _cap) => {
-                if let Some(inflight) = streams.get_mut(&stream.id()) {
+            h2::MessageKind::Data(data, cap) => {
+                // stream window is held until handler consumes
+                // received messages
+                let mut streams = self.streams.borrow_mut();
+                if let Some(inflight) = streams.get_mut(&id) {
                     inflight.data.push(data);
+
+                    if let Err(err) = inflight.decode(false) {
+                        inflight.fail(err);
+                        let _ = streams.remove(&id);
+                    } else {
+                        inflight.payload.received(cap);
+                    }
                 }
             }
             h2::MessageKind::Eof(data) => {
-                if let Some(mut inflight) = streams.remove(&id) {
-                    match data {
-                        h2::StreamEof::Data(chunk) => inflight.data.push(chunk),
-                        h2::StreamEof::Trailers(hdrs) => {
-                            for (name, val) in &hdrs {
-                                inflight.headers.insert(name.clone(), val.clone());
+                let inflight = self.streams.borrow_mut().remove(&id);
+
+                match data {
+                    h2::StreamEof::Data(chunk) => {
+                        if let Some(mut inflight) = inflight {
+                            inflight.data.push(chunk);
+                            if let Err(err) = inflight.decode(true) {
+                                inflight.fail(err);
                             }
                         }
-                        h2::StreamEof::Error(err) => return Err(err.into_error()),
-                    }
-
-                    let mut data = inflight.data.get();
-                    let _compressed = data.get_u8();
-                    let len = data.get_u32();
-                    if (len as usize) > data.len() {
-                        if stream.send_response(StatusCode::OK, hdrs(), false).is_ok() {
-                            send_error(&stream, GrpcStatus::InvalidArgument, ERR_DATA_DECODE);
-                        }
-                        return Ok(());
                     }
-                    let data = data
-                        .split_to_checked(len as usize)
-                        .ok_or(h2::StreamError::Reset(Reason::PROTOCOL_ERROR))?;
-
-                    log::debug!(
-                        "{}: Call service {} method {}",
-                        self.cfg.tag(),
-                        inflight.service,
-                        inflight.name
-                    );
-                    let req = ServerRequest {
-                        payload: data,
-                        name: inflight.name,
-                        headers: inflight.headers,
-                    };
-                    if stream.send_response(StatusCode::OK, hdrs(), false).is_err() {
-                        return Ok(());
-                    }
-                    drop(streams);
-
-                    // GRPC Timeout
-                    let to = if let Some(to) = req.headers.get(consts::GRPC_TIMEOUT) {
-                        if let Ok(to) = try_parse_grpc_timeout(to) {
-                            to
-                        } else {
-                            send_error(&stream, GrpcStatus::InvalidArgument, ERR_DECODE_TIMEOUT);
-                            return Ok(());
-                        }
-                    } else {
-                        Millis::ZERO
-                    };
-
-                    match timeout_checked(to, ctx.call(&self.service, req)).await {
-                        Ok(Ok(res)) => {
-                            log::debug!("{}: Response is received {res:?}", self.cfg.tag());
-                            let mut buf = BytesMut::with_capacity(res.payload.len() + 5);
-                            buf.put_u8(0); // compression
-                            buf.put_u32(res.payload.len() as u32); // length
-                            buf.extend_from_slice(&res.payload);
-
-                            let _ = stream.send_payload(buf.freeze(), false).await;
-
-                            let mut trailers = HeaderMap::default();
-                            trailers.insert(consts::GRPC_STATUS, GrpcStatus::Ok.into());
-                            for (name, val) in res.headers {
-                                trailers.append(name, val);
+                    h2::StreamEof::Trailers(hdrs) => {
+                        if let Some(mut inflight) = inflight {
+                            match inflight.decode(true) {
+                                Ok(()) => {
+                                    inflight.payload.send(Ok(Chunk::Trailers(hdrs)));
+                                }
+                                Err(err) => inflight.fail(err),
                             }
-
-                            stream.send_trailers(trailers);
                         }
-                        Ok(Err(err)) => {
-                            log::debug!(
-                                "{}: Failure during service call: {:?}",
-                                self.cfg.tag(),
-                                err.message
-                            );
-                            let mut trailers = err.headers;
-                            trailers.insert(consts::GRPC_STATUS, err.status.into());
-                            trailers.insert(consts::GRPC_MESSAGE, err.message);
-                            stream.send_trailers(trailers);
-                        }
-                        Err(()) => {
-                            log::debug!(
-                                "{}: Deadline exceeded failure during service call",
-                                self.cfg.tag()
-                            );
-                            send_error(&stream, GrpcStatus::DeadlineExceeded, ERR_DEADLINE);
+                    }
+                    h2::StreamEof::Error(err) => {
+                        self.cancel(id);
+                        if let Some(inflight) = inflight {
+                            inflight.fail(ServerError::new(
+                                GrpcStatus::Cancelled,
+                                ERR_STREAM,
+                                None,
+                            ));
                         }
+                        return Err(err.into_error());
                     }
-
-                    return Ok(());
                 }
             }
             h2::MessageKind::Disconnect(_) => {
-                streams.remove(&id);
+                self.cancel(id);
+                if let Some(inflight) = self.streams.borrow_mut().remove(&id) {
+                    inflight.fail(ServerError::new(GrpcStatus::Cancelled, ERR_STREAM, None));
+                }
             }
         }
         Ok(())
     }
 }
 
+/// Registers call cancellation for the duration of service call
+///
+/// Call is cancelled if dispatch is dropped before completion,
+/// for example if stream is reset by client.
+struct CancelGuard<'a> {
+    id: StreamId,
+    cancels: &'a RefCell<HashMap<StreamId, Cancellation>>,
+    cancellation: Cancellation,
+    completed: bool,
+}
+
+impl<'a> CancelGuard<'a> {
+    fn new(
+        cancels: &'a RefCell<HashMap<StreamId, Cancellation>>,
+        id: StreamId,
+        cancellation: &Cancellation,
+    ) -> Self {
+        cancels.borrow_mut().insert(id, cancellation.clone());
+        Self {
+            id,
+            cancels,
+            cancellation: cancellation.clone(),
+            completed: false,
+        }
+    }
+}
+
+impl Drop for CancelGuard<'_> {
+    fn drop(&mut self) {
+        self.cancels.borrow_mut().remove(&self.id);
+        if !self.completed {
+            self.cancellation.cancel();
+        }
+    }
+}
+
 fn hdrs() -> HeaderMap {
     let mut hdrs = HeaderMap::default();
     hdrs.insert(CONTENT_TYPE, HDR_APP_GRPC);
+    hdrs.insert(
+        consts::GRPC_ACCEPT_ENCODING,
+        CompressionEncoding::accept_encoding(),
+    );
     hdrs
 }
 
+/// Encode length-prefixed message
+fn frame(payload: &Bytes, encoding: CompressionEncoding) -> Bytes {
+    let mut buf = BytesMut::new();
+    encode_frame(payload, encoding, &mut buf);
+    buf.freeze()
+}
+
 fn send_error(stream: &StreamRef, st: GrpcStatus, msg: HeaderValue) {
     let mut trailers = HeaderMap::default();
     trailers.insert(consts::GRPC_STATUS, st.into());
@@ -376,3 +612,387 @@ fn try_parse_grpc_timeout(val: &HeaderValue) -> Result<Millis, ()> {
 
     Ok(duration)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, atomic::AtomicBool, atomic::AtomicUsize, atomic::Ordering};
+
+    use ntex::server::TestServerBuilder;
+    use ntex_h2::client::{ClientBuilder, SimpleClient};
+    use ntex_http::{HeaderName, Method};
+    use ntex_service::fn_service;
+    use ntex_util::channel::mpsc;
+
+    use super::*;
+
+    /// Counts request messages, count is returned in trailers
+    async fn count(mut req: ServerRequest) -> Result<ServerResponse, ServerError> {
+        let stream = req.stream.take().unwrap();
+        let mut count = 0;
+        while let Some(msg) = stream.recv().await {
+            if let Chunk::Message(_) = msg? {
+                count += 1;
+            }
+        }
+        Ok(ServerResponse::with_headers(
+            Bytes::new(),
+            vec![(HeaderName::from_static("x-count"), HeaderValue::from(count))],
+        ))
+    }
+
+    /// Replies to unary call with request message, bidirectional call is
+    /// greeted before the first request message
+    async fn echo(mut req: ServerRequest) -> Result<ServerResponse, ServerError> {
+        if req.name == "Bidi" {
+            let (tx, rx) = mpsc::channel();
+            let _ = tx.send(Ok(Bytes::from_static(b"hello")));
+            let stream = req.stream.take().unwrap();
+            ntex::rt::spawn(async move {
+                while let Some(Ok(Chunk::Message(msg))) = stream.recv().await {
+                    let _ = tx.send(Ok(msg));
+                }
+            });
+            Ok(ServerResponse::with_stream(rx, Vec::new()))
+        } else {
+            let msg = req.message().await?;
+            let headers = req
+                .headers
+                .get("x-trailer")
+                .map(|val| (HeaderName::from_static("x-trailer"), val.clone()));
+            Ok(ServerResponse::with_headers(
+                msg,
+                headers.into_iter().collect(),
+            ))
+        }
+    }
+
+    async fn client(port: u16) -> SimpleClient {
+        ClientBuilder::with_default(format!("127.0.0.1:{port}"))
+            .build(SharedCfg::default())
+            .await
+            .unwrap()
+            .client()
+            .await
+            .unwrap()
+    }
+
+    fn messages(sizes: &[usize]) -> Vec<Bytes> {
+        sizes
+            .iter()
+            .map(|size| frame(&Bytes::from(vec![0; *size]), CompressionEncoding::Identity))
+            .collect()
+    }
+
+    /// Send request payload, returns response trailers
+    async fn call(client: &SimpleClient, payload: Vec<Bytes>) -> HeaderMap {
+        call_method(client, "Count", payload, None).await.1
+    }
+
+    /// Send request payload and optional trailers to the method,
+    /// returns response messages and trailers
+    async fn call_method(
+        client: &SimpleClient,
+        method: &str,
+        payload: Vec<Bytes>,
+        request_trailers: Option<HeaderMap>,
+    ) -> (Vec<Bytes>, HeaderMap) {
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(CONTENT_TYPE, HDR_APP_GRPC);
+        let (snd, rcv) = client
+            .send(
+                Method::POST,
+                format!("/test.Service/{method}").into(),
+                hdrs,
+                false,
+            )
+            .await
+            .unwrap();
+        for chunk in payload {
+            if snd.send_payload(chunk, false).await.is_err() {
+                break;
+            }
+        }
+        if let Some(trailers) = request_trailers {
+            snd.send_trailers(trailers);
+        } else {
+            let _ = snd.send_payload(Bytes::new(), true).await;
+        }
+
+        let mut messages = Vec::new();
+        while let Some(msg) = rcv.recv().await {
+            match msg.kind {
+                h2::MessageKind::Data(data, _) => messages.push(data),
+                h2::MessageKind::Eof(h2::StreamEof::Trailers(trailers)) => {
+                    return (messages, trailers);
+                }
+                _ => (),
+            }
+        }
+        panic!("Response trailers are expected")
+    }
+
+    fn status(trailers: &HeaderMap) -> Option<GrpcStatus> {
+        let code = trailers.get(consts::GRPC_STATUS)?.to_str().ok()?;
+        GrpcStatus::try_from(code.parse::<u8>().ok()?).ok()
+    }
+
+    #[ntex::test]
+    async fn stream_window() {
+        const WINDOW: i32 = 16_384;
+
+        let srv = TestServerBuilder::new(async || GrpcServer::new(fn_service(count)))
+            .config(
+                SharedCfg::new("SRV")
+                    .add(h2::ServiceConfig::new().set_initial_window_size(WINDOW)),
+            )
+            .start();
+        let client = client(srv.addr().port()).await;
+        let trailers = call(&client, messages(&[10])).await;
+        assert_eq!(trailers.get("x-count"), Some(&HeaderValue::from(1)));
+
+        // first message fills stream window, handler waits for the next one
+        let payload = messages(&[WINDOW as usize - 5, 10, 10]);
+        let trailers = timeout_checked(Millis(5_000), call(&client, payload))
+            .await
+            .unwrap();
+        assert_eq!(status(&trailers), Some(GrpcStatus::Ok));
+        assert_eq!(trailers.get("x-count"), Some(&HeaderValue::from(3)));
+    }
+
+    #[ntex::test]
+    async fn stream_backpressure() {
+        const WINDOW: i32 = 16_384;
+
+        let reading = Arc::new(AtomicBool::new(false));
+        let flag = reading.clone();
+        let srv = TestServerBuilder::new(move || {
+            let flag = flag.clone();
+            async move {
+                GrpcServer::new(fn_service(move |req| {
+                    let flag = flag.clone();
+                    async move {
+                        // handler does not read request stream for a while
+                        ntex::time::sleep(Millis(300)).await;
+                        flag.store(true, Ordering::SeqCst);
+                        count(req).await
+                    }
+                }))
+            }
+        })
+        .config(
+            SharedCfg::new("SRV").add(h2::ServiceConfig::new().set_initial_window_size(WINDOW)),
+        )
+        .start();
+        let client = client(srv.addr().port()).await;
+        // wait for server settings
+        let _ = call(&client, messages(&[10])).await;
+        reading.store(false, Ordering::SeqCst);
+
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(CONTENT_TYPE, HDR_APP_GRPC);
+        let (snd, rcv) = client
+            .send(Method::POST, "/test.Service/Count".into(), hdrs, false)
+            .await
+            .unwrap();
+
+        // first message fills stream window, client cannot send next
+        // message until handler reads the first one
+        for chunk in messages(&[WINDOW as usize - 5, 10]) {
+            snd.send_payload(chunk, false).await.unwrap();
+        }
+        assert!(reading.load(Ordering::SeqCst));
+        snd.send_payload(Bytes::new(), true).await.unwrap();
+
+        while let Some(msg) = rcv.recv().await {
+            if let h2::MessageKind::Eof(h2::StreamEof::Trailers(trailers)) = msg.kind {
+                assert_eq!(trailers.get("x-count"), Some(&HeaderValue::from(2)));
+                return;
+            }
+        }
+        panic!("Response trailers are expected")
+    }
+
+    #[ntex::test]
+    async fn max_message_size() {
+        let srv = ntex::server::test_server(async || {
+            GrpcServer::new(fn_service(count)).max_message_size(1024)
+        });
+        let client = client(srv.addr().port()).await;
+
+        let trailers = call(&client, messages(&[1024, 10])).await;
+        assert_eq!(trailers.get("x-count"), Some(&HeaderValue::from(2)));
+
+        let trailers = call(&client, messages(&[1025])).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::ResourceExhausted));
+
+        // length prefix of huge message
+        let payload = vec![Bytes::from_static(b"\x00\xff\xff\xff\xff")];
+        let trailers = call(&client, payload).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::ResourceExhausted));
+
+        // streaming request
+        let trailers = call(&client, messages(&[10, 2048])).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::ResourceExhausted));
+    }
+
+    #[ntex::test]
+    async fn unary_messages() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(echo)));
+        let client = client(srv.addr().port()).await;
+
+        let (msgs, trailers) = call_method(&client, "Unary", messages(&[10]), None).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::Ok));
+        assert_eq!(msgs.concat(), messages(&[10])[0]);
+
+        let (_, trailers) = call_method(&client, "Unary", messages(&[10, 10]), None).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::Internal));
+
+        let (_, trailers) = call_method(&client, "Unary", Vec::new(), None).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::Internal));
+
+        // request trailers are appended to request headers
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(
+            HeaderName::from_static("x-trailer"),
+            HeaderValue::from_static("value"),
+        );
+        let (_, trailers) = call_method(&client, "Unary", messages(&[10]), Some(hdrs)).await;
+        assert_eq!(status(&trailers), Some(GrpcStatus::Ok));
+        assert_eq!(
+            trailers.get("x-trailer"),
+            Some(&HeaderValue::from_static("value"))
+        );
+    }
+
+    /// Server with never completing handler, counts cancelled calls
+    fn cancel_server(cancelled: &Arc<AtomicUsize>) -> ntex::server::TestServer {
+        let cancelled = cancelled.clone();
+        ntex::server::test_server(move || {
+            let cancelled = cancelled.clone();
+            async move {
+                GrpcServer::new(fn_service(move |req: ServerRequest| {
+                    let cancelled = cancelled.clone();
+                    async move {
+                        // handler future is dropped on cancellation,
+                        // signal is observed by spawned task
+                        let cancellation = req.cancellation().clone();
+                        ntex::rt::spawn(async move {
+                            cancellation.cancelled().await;
+                            cancelled.fetch_add(1, Ordering::SeqCst);
+                        });
+                        std::future::pending::<Result<ServerResponse, ServerError>>().await
+                    }
+                }))
+            }
+        })
+    }
+
+    async fn wait_cancelled(cancelled: &AtomicUsize, count: usize) {
+        timeout_checked(Millis(5_000), async {
+            while cancelled.load(Ordering::SeqCst) < count {
+                ntex::time::sleep(Millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    #[ntex::test]
+    async fn cancel_on_reset() {
+        let cancelled = Arc::new(AtomicUsize::new(0));
+        let srv = cancel_server(&cancelled);
+        let client = client(srv.addr().port()).await;
+
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(CONTENT_TYPE, HDR_APP_GRPC);
+        let (snd, rcv) = client
+            .send(Method::POST, "/test.Service/Wait".into(), hdrs, false)
+            .await
+            .unwrap();
+        snd.send_payload(messages(&[10])[0].clone(), false)
+            .await
+            .unwrap();
+
+        // wait for response headers, handler is running
+        let msg = rcv.recv().await.unwrap();
+        assert!(matches!(msg.kind, h2::MessageKind::Headers { .. }));
+        assert_eq!(cancelled.load(Ordering::SeqCst), 0);
+
+        snd.reset(h2::frame::Reason::CANCEL);
+        wait_cancelled(&cancelled, 1).await;
+    }
+
+    #[ntex::test]
+    async fn cancel_on_disconnect() {
+        let cancelled = Arc::new(AtomicUsize::new(0));
+        let srv = cancel_server(&cancelled);
+        let client = client(srv.addr().port()).await;
+
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(CONTENT_TYPE, HDR_APP_GRPC);
+        let mut calls = Vec::new();
+        for _ in 0..2 {
+            let (snd, rcv) = client
+                .send(
+                    Method::POST,
+                    "/test.Service/Wait".into(),
+                    hdrs.clone(),
+                    false,
+                )
+                .await
+                .unwrap();
+            let msg = rcv.recv().await.unwrap();
+            assert!(matches!(msg.kind, h2::MessageKind::Headers { .. }));
+            calls.push((snd, rcv));
+        }
+        assert_eq!(cancelled.load(Ordering::SeqCst), 0);
+
+        // every running call is cancelled
+        client.force_close();
+        wait_cancelled(&cancelled, 2).await;
+    }
+
+    #[ntex::test]
+    async fn server_first_bidi() {
+        let srv = ntex::server::test_server(async || GrpcServer::new(fn_service(echo)));
+        let client = client(srv.addr().port()).await;
+
+        let mut hdrs = HeaderMap::default();
+        hdrs.insert(CONTENT_TYPE, HDR_APP_GRPC);
+        let (snd, rcv) = client
+            .send(Method::POST, "/test.Service/Bidi".into(), hdrs, false)
+            .await
+            .unwrap();
+
+        // handler replies before the first request message
+        let greeting = timeout_checked(Millis(5_000), async {
+            loop {
+                if let h2::MessageKind::Data(data, _) = rcv.recv().await.unwrap().kind {
+                    return data;
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            greeting,
+            frame(&Bytes::from_static(b"hello"), CompressionEncoding::Identity)
+        );
+
+        snd.send_payload(messages(&[10])[0].clone(), true)
+            .await
+            .unwrap();
+        let mut msgs = Vec::new();
+        while let Some(msg) = rcv.recv().await {
+            match msg.kind {
+                h2::MessageKind::Data(data, _) => msgs.push(data),
+                h2::MessageKind::Eof(h2::StreamEof::Trailers(trailers)) => {
+                    assert_eq!(status(&trailers), Some(GrpcStatus::Ok));
+                    break;
+                }
+                _ => (),
+            }
+        }
+        assert_eq!(msgs.concat(), messages(&[10])[0]);
+    }
+}
@@ -0,0 +1,318 @@
+use std::task::{Context, Poll};
+use std::{cell::Cell, fmt, future::poll_fn, marker::PhantomData, pin::Pin, rc::Rc};
+
+use ntex_bytes::{Bytes, BytesMut};
+use ntex_h2::Capacity;
+use ntex_http::{HeaderMap, HeaderName, HeaderValue};
+use ntex_util::{Stream, channel::mpsc};
+
+use super::{ServerError, ServerResponse};
+use crate::Message;
+
+type BoxStream<T> = Pin<Box<dyn Stream<Item = Result<T, ServerError>>>>;
+
+/// Item of request stream
+#[derive(Debug)]
+pub(crate) enum Chunk {
+    Message(Bytes),
+    Trailers(HeaderMap),
+}
+
+/// Receiving side of request stream
+pub(crate) struct Payload {
+    rx: mpsc::Receiver<Result<Chunk, ServerError>>,
+    window: Rc<Window>,
+}
+
+/// Sending side of request stream
+pub(crate) struct PayloadSender {
+    tx: mpsc::Sender<Result<Chunk, ServerError>>,
+    window: Rc<Window>,
+}
+
+/// Request stream flow control
+///
+/// Received stream capacity is held while queued messages are not
+/// consumed, capacity is released once handler takes all queued messages.
+#[derive(Default)]
+struct Window {
+    queued: Cell<usize>,
+    held: Cell<Option<Capacity>>,
+    closed: Cell<bool>,
+}
+
+impl Payload {
+    pub(crate) fn channel() -> (PayloadSender, Payload) {
+        let (tx, rx) = mpsc::channel();
+        let window = Rc::new(Window::default());
+        (
+            PayloadSender {
+                tx,
+                window: window.clone(),
+            },
+            Payload { rx, window },
+        )
+    }
+
+    pub(crate) async fn recv(&self) -> Option<Result<Chunk, ServerError>> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    pub(crate) fn poll_recv(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Chunk, ServerError>>> {
+        let result = self.rx.poll_recv(cx);
+        if let Poll::Ready(Some(Ok(Chunk::Message(_)))) = result {
+            let queued = self.window.queued.get().saturating_sub(1);
+            self.window.queued.set(queued);
+            if queued == 0 {
+                self.window.held.take();
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Payload")
+            .field("queued", &self.window.queued.get())
+            .finish()
+    }
+}
+
+impl Drop for Payload {
+    fn drop(&mut self) {
+        self.window.closed.set(true);
+        self.window.held.take();
+    }
+}
+
+impl PayloadSender {
+    pub(crate) fn send(&self, item: Result<Chunk, ServerError>) {
+        if let Ok(Chunk::Message(_)) = item {
+            self.window.queued.set(self.window.queued.get() + 1);
+        }
+        let _ = self.tx.send(item);
+    }
+
+    /// Send error and close the stream
+    pub(crate) fn fail(&self, err: ServerError) {
+        let _ = self.tx.send(Err(err));
+        self.tx.close();
+    }
+
+    /// Release received capacity or hold it until queued messages are consumed
+    pub(crate) fn received(&self, cap: Capacity) {
+        if self.window.queued.get() > 0 && !self.window.closed.get() {
+            let held = match self.window.held.take() {
+                Some(held) => held + cap,
+                None => cap,
+            };
+            self.window.held.set(Some(held));
+        }
+    }
+}
+
+/// Stream of request messages for client and bidirectional streaming methods
+///
+/// Method handler is called as soon as request headers are received.
+/// Bidirectional handlers could consume the stream from a separate task
+/// and reply via [`ResponseStream::channel`].
+pub struct Streaming<T> {
+    payload: Option<Payload>,
+    trailers: Option<HeaderMap>,
+    _t: PhantomData<T>,
+}
+
+impl<T: Message> Streaming<T> {
+    pub(crate) fn new(payload: Option<Payload>) -> Self {
+        Self {
+            payload,
+            trailers: None,
+            _t: PhantomData,
+        }
+    }
+
+    /// Request trailers
+    ///
+    /// Trailers are available after the end of the stream.
+    pub fn trailers(&self) -> Option<&HeaderMap> {
+        self.trailers.as_ref()
+    }
+
+    /// Receive next message from the stream
+    ///
+    /// Returns `None` when the client has closed the stream.
+    pub async fn recv(&mut self) -> Option<Result<T, ServerError>> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Attempt to pull out the next message of the stream
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<T, ServerError>>> {
+        loop {
+            let Some(payload) = self.payload.as_ref() else {
+                return Poll::Ready(None);
+            };
+
+            return match payload.poll_recv(cx) {
+                Poll::Ready(Some(Ok(Chunk::Message(mut msg)))) => match T::read(&mut msg) {
+                    Ok(msg) => Poll::Ready(Some(Ok(msg))),
+                    Err(err) => {
+                        self.payload = None;
+                        Poll::Ready(Some(Err(err.into())))
+                    }
+                },
+                Poll::Ready(Some(Ok(Chunk::Trailers(trailers)))) => {
+                    self.trailers = Some(trailers);
+                    continue;
+                }
+                Poll::Ready(Some(Err(err))) => {
+                    self.payload = None;
+                    Poll::Ready(Some(Err(err)))
+                }
+                Poll::Ready(None) => {
+                    self.payload = None;
+                    Poll::Ready(None)
+                }
+                Poll::Pending => Poll::Pending,
+            };
+        }
+    }
+}
+
+impl<T: Message> Stream for Streaming<T> {
+    type Item = Result<T, ServerError>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+impl<T> Unpin for Streaming<T> {}
+
+impl<T> fmt::Debug for Streaming<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Streaming")
+            .field("eof", &self.payload.is_none())
+            .finish()
+    }
+}
+
+/// Stream of response messages for server streaming methods
+pub struct ResponseStream<T> {
+    pub(crate) stream: BoxStream<T>,
+    pub(crate) headers: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl<T: 'static> ResponseStream<T> {
+    /// Create response stream from the stream of messages
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, ServerError>> + 'static,
+    {
+        Self {
+            stream: Box::pin(stream),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Create response stream backed by a channel
+    ///
+    /// Stream terminates after all senders are dropped.
+    pub fn channel() -> (ResponseSender<T>, Self) {
+        let (tx, rx) = mpsc::channel();
+        (ResponseSender(tx), Self::new(rx))
+    }
+
+    #[must_use]
+    /// Append a header to the response trailers
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+}
+
+impl<T: Message + 'static> ResponseStream<T> {
+    /// Convert to server response with stream of encoded messages
+    pub(crate) fn into_server_response(self) -> ServerResponse {
+        ServerResponse::with_stream(Encoder(self.stream), self.headers)
+    }
+}
+
+impl<T> Stream for ResponseStream<T> {
+    type Item = Result<T, ServerError>;
+
+    #[inline]
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> fmt::Debug for ResponseStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseStream")
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+/// Sending part of the response stream
+pub struct ResponseSender<T>(mpsc::Sender<Result<T, ServerError>>);
+
+impl<T> ResponseSender<T> {
+    /// Send message to the peer
+    ///
+    /// Returns message back if response stream is closed.
+    pub fn send(&self, msg: T) -> Result<(), T> {
+        self.0
+            .send(Ok(msg))
+            .map_err(|e| e.into_inner().unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Terminate response stream with error
+    pub fn error(self, err: ServerError) {
+        let _ = self.0.send(Err(err));
+        self.0.close();
+    }
+
+    /// Check if response stream is closed
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+}
+
+impl<T> Clone for ResponseSender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> fmt::Debug for ResponseSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseSender")
+            .field("closed", &self.0.is_closed())
+            .finish()
+    }
+}
+
+/// Encode stream items
+struct Encoder<T>(BoxStream<T>);
+
+impl<T: Message> Stream for Encoder<T> {
+    type Item = Result<Bytes, ServerError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.as_mut().poll_next(cx).map(|item| {
+            item.map(|res| {
+                res.map(|msg| {
+                    let mut buf = BytesMut::with_capacity(msg.encoded_len());
+                    msg.write(&mut buf);
+                    buf.freeze()
+                })
+            })
+        })
+    }
+}
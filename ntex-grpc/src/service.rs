@@ -1,14 +1,26 @@
+#![allow(async_fn_in_trait)]
+
 use ntex_bytes::{ByteString, Bytes, BytesMut};
 
-use crate::{encoding::DecodeError, server::MethodResult, types::Message};
+use crate::google_types::{MethodOptions, ServiceOptions};
+use crate::server::{MethodResult, ServerError, ServerRequest, Streaming};
+use crate::{encoding::DecodeError, types::Message};
 
 /// Trait for service method definition
 pub trait ServiceDef {
     const NAME: &'static str;
 
+    /// Encoded `google.protobuf.ServiceOptions` of the service
+    const OPTIONS: &'static [u8] = &[];
+
     type Methods;
 
     fn method_by_name(name: &str) -> Option<Self::Methods>;
+
+    /// Decode service options, custom options are accessible as extensions
+    fn options() -> Result<ServiceOptions, DecodeError> {
+        ServiceOptions::read(&mut Bytes::from_static(Self::OPTIONS))
+    }
 }
 
 /// Trait for service method definition
@@ -17,10 +29,24 @@ pub trait MethodDef {
 
     const PATH: ByteString;
 
+    /// Encoded `google.protobuf.MethodOptions` of the method
+    const OPTIONS: &'static [u8] = &[];
+
     type Input: Message;
 
     type Output: Message;
 
+    /// Request of the method handler
+    ///
+    /// `Input` for unary and server streaming methods,
+    /// `Streaming<Input>` for client and bidirectional streaming methods.
+    type Request: MethodRequest<Self::Input>;
+
+    /// Decode method options, custom options are accessible as extensions
+    fn options() -> Result<MethodOptions, DecodeError> {
+        MethodOptions::read(&mut Bytes::from_static(Self::OPTIONS))
+    }
+
     #[inline]
     fn decode(&self, buf: &mut Bytes) -> Result<Self::Input, DecodeError> {
         Message::read(buf)
@@ -31,9 +57,45 @@ pub trait MethodDef {
         val.write(buf);
     }
 
+    #[doc(hidden)]
+    #[inline]
+    async fn server_request(&self, req: &mut ServerRequest) -> Result<Self::Request, ServerError>
+    where
+        Self: Sized,
+    {
+        Self::Request::from_server_request(self, req).await
+    }
+
     #[doc(hidden)]
     #[inline]
     fn server_result<T: MethodResult<Self::Output>>(&self, val: T) -> Self::Output {
         val.into()
     }
 }
+
+/// Handler request of the service method
+#[doc(hidden)]
+pub trait MethodRequest<T>: Sized {
+    async fn from_server_request<M: MethodDef<Input = T>>(
+        method: &M,
+        req: &mut ServerRequest,
+    ) -> Result<Self, ServerError>;
+}
+
+impl<T: Message> MethodRequest<T> for T {
+    async fn from_server_request<M: MethodDef<Input = T>>(
+        method: &M,
+        req: &mut ServerRequest,
+    ) -> Result<Self, ServerError> {
+        Ok(method.decode(&mut req.message().await?)?)
+    }
+}
+
+impl<T: Message> MethodRequest<T> for Streaming<T> {
+    async fn from_server_request<M: MethodDef<Input = T>>(
+        _: &M,
+        req: &mut ServerRequest,
+    ) -> Result<Self, ServerError> {
+        Ok(req.take_stream())
+    }
+}
@@ -3,7 +3,10 @@
     clippy::cast_sign_loss,
     clippy::cast_possible_wrap
 )]
-use std::{collections::HashMap, convert::TryFrom, fmt, hash::BuildHasher, hash::Hash, mem};
+use std::{
+    collections::HashMap, convert::TryFrom, fmt, hash::BuildHasher, hash::Hash,
+    marker::PhantomData, mem, ops,
+};
 
 use ntex_bytes::{Buf, BufMut, ByteString, Bytes, BytesMut};
 
@@ -15,6 +18,23 @@ pub trait Message: Default + Sized + fmt::Debug {
     /// Decodes an instance of the message from a buffer
     fn read(src: &mut Bytes) -> Result<Self, DecodeError>;
 
+    /// Decodes the message from a buffer and merges it into self
+    ///
+    /// Singular fields are replaced, repeated fields are extended and
+    /// embedded messages are merged recursively. Default implementation
+    /// replaces self with decoded message.
+    fn merge(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
+        *self = Self::read(src)?;
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Decodes the message and checks presence of required fields,
+    /// including required fields of embedded messages
+    fn merge_checked(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
+        self.merge(src)
+    }
+
     /// Encodes and writes the message to a buffer
     fn write(&self, dst: &mut BytesMut);
 
@@ -54,7 +74,7 @@ pub trait NativeType: PartialEq + Default + Sized + fmt::Debug {
     /// Encode field tag and length
     fn encode_type(&self, tag: u32, dst: &mut BytesMut) {
         encoding::encode_key(tag, Self::TYPE, dst);
-        if !matches!(Self::TYPE, WireType::Varint | WireType::SixtyFourBit) {
+        if Self::TYPE == WireType::LengthDelimited {
             encoding::encode_varint(self.value_len() as u64, dst);
         }
     }
@@ -103,7 +123,10 @@ pub trait NativeType: PartialEq + Default + Sized + fmt::Debug {
     ) -> Result<(), DecodeError> {
         encoding::check_wire_type(Self::TYPE, wtype)?;
 
-        if matches!(Self::TYPE, WireType::Varint | WireType::SixtyFourBit) {
+        if matches!(
+            Self::TYPE,
+            WireType::Varint | WireType::ThirtyTwoBit | WireType::SixtyFourBit
+        ) {
             self.merge(src)
         } else {
             let len = encoding::decode_varint(src)? as usize;
@@ -118,6 +141,18 @@ pub trait NativeType: PartialEq + Default + Sized + fmt::Debug {
         }
     }
 
+    #[doc(hidden)]
+    #[inline]
+    /// Deserialize protobuf field, required fields of embedded messages are checked
+    fn deserialize_checked(
+        &mut self,
+        tag: u32,
+        wtype: WireType,
+        src: &mut Bytes,
+    ) -> Result<(), DecodeError> {
+        self.deserialize(tag, wtype, src)
+    }
+
     #[inline]
     /// Deserialize protobuf field to default value
     fn deserialize_default(
@@ -142,6 +177,34 @@ impl Message for () {
     fn write(&self, _: &mut BytesMut) {}
 }
 
+/// Boxed message, used for recursive message fields
+impl<T: Message> Message for Box<T> {
+    #[inline]
+    fn read(src: &mut Bytes) -> Result<Self, DecodeError> {
+        T::read(src).map(Box::new)
+    }
+
+    #[inline]
+    fn merge(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
+        Message::merge(&mut **self, src)
+    }
+
+    #[inline]
+    fn merge_checked(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
+        Message::merge_checked(&mut **self, src)
+    }
+
+    #[inline]
+    fn write(&self, dst: &mut BytesMut) {
+        Message::write(&**self, dst);
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        Message::encoded_len(&**self)
+    }
+}
+
 impl<T: Message + PartialEq> NativeType for T {
     const TYPE: WireType = WireType::LengthDelimited;
 
@@ -157,8 +220,23 @@ impl<T: Message + PartialEq> NativeType for T {
 
     /// Deserialize from the input
     fn merge(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
-        *self = Message::read(src)?;
-        Ok(())
+        Message::merge(self, src)
+    }
+
+    /// Deserialize embedded message and check its required fields
+    fn deserialize_checked(
+        &mut self,
+        _: u32,
+        wtype: WireType,
+        src: &mut Bytes,
+    ) -> Result<(), DecodeError> {
+        encoding::check_wire_type(WireType::LengthDelimited, wtype)?;
+
+        let len = encoding::decode_varint(src)? as usize;
+        let mut buf = src
+            .split_to_checked(len)
+            .ok_or_else(DecodeError::incomplete)?;
+        Message::merge_checked(self, &mut buf)
     }
 }
 
@@ -279,10 +357,19 @@ impl<T: NativeType> NativeType for Option<T> {
         wtype: WireType,
         src: &mut Bytes,
     ) -> Result<(), DecodeError> {
-        let mut value: T = Default::default();
-        value.deserialize(tag, wtype, src)?;
-        *self = Some(value);
-        Ok(())
+        self.get_or_insert_with(Default::default)
+            .deserialize(tag, wtype, src)
+    }
+
+    #[inline]
+    fn deserialize_checked(
+        &mut self,
+        tag: u32,
+        wtype: WireType,
+        src: &mut Bytes,
+    ) -> Result<(), DecodeError> {
+        self.get_or_insert_with(Default::default)
+            .deserialize_checked(tag, wtype, src)
     }
 
     #[inline]
@@ -337,6 +424,14 @@ impl NativeType for Vec<u8> {
     }
 }
 
+/// Repeated scalar numeric values are packed
+fn is_packed<T: NativeType>() -> bool {
+    matches!(
+        T::TYPE,
+        WireType::Varint | WireType::ThirtyTwoBit | WireType::SixtyFourBit
+    )
+}
+
 impl<T: NativeType> NativeType for Vec<T> {
     const TYPE: WireType = WireType::LengthDelimited;
 
@@ -357,11 +452,17 @@ impl<T: NativeType> NativeType for Vec<T> {
         wtype: WireType,
         src: &mut Bytes,
     ) -> Result<(), DecodeError> {
-        if T::TYPE == WireType::Varint {
+        // scalar numeric values could be packed or unpacked
+        if is_packed::<T>() && wtype == WireType::LengthDelimited {
             let len = encoding::decode_varint(src)? as usize;
             let mut buf = src
                 .split_to_checked(len)
                 .ok_or_else(DecodeError::incomplete)?;
+            match T::TYPE {
+                WireType::ThirtyTwoBit => self.reserve(len / 4),
+                WireType::SixtyFourBit => self.reserve(len / 8),
+                _ => (),
+            }
             while !buf.is_empty() {
                 let mut value: T = Default::default();
                 value.merge(&mut buf)?;
@@ -375,17 +476,32 @@ impl<T: NativeType> NativeType for Vec<T> {
         Ok(())
     }
 
+    fn deserialize_checked(
+        &mut self,
+        tag: u32,
+        wtype: WireType,
+        src: &mut Bytes,
+    ) -> Result<(), DecodeError> {
+        if is_packed::<T>() {
+            self.deserialize(tag, wtype, src)
+        } else {
+            let mut value: T = Default::default();
+            value.deserialize_checked(tag, wtype, src)?;
+            self.push(value);
+            Ok(())
+        }
+    }
+
     /// Serialize protobuf field
     fn serialize(&self, tag: u32, _: DefaultValue<&Self>, dst: &mut BytesMut) {
         if self.is_empty() {
             return;
         }
-        if T::TYPE == WireType::Varint {
+        if is_packed::<T>() {
+            let len = self.iter().map(NativeType::value_len).sum::<usize>();
             encoding::encode_key(tag, WireType::LengthDelimited, dst);
-            encoding::encode_varint(
-                self.iter().map(NativeType::value_len).sum::<usize>() as u64,
-                dst,
-            );
+            encoding::encode_varint(len as u64, dst);
+            dst.reserve(len);
             for item in self {
                 item.encode_value(dst);
             }
@@ -403,17 +519,29 @@ impl<T: NativeType> NativeType for Vec<T> {
 
     /// Protobuf field length
     fn encoded_len(&self, tag: u32) -> usize {
-        if T::TYPE == WireType::Varint {
+        if is_packed::<T>() {
             let len = self.iter().map(NativeType::value_len).sum::<usize>();
-            self.iter().map(NativeType::value_len).sum::<usize>()
-                + encoding::key_len(tag)
-                + encoding::encoded_len_varint(len as u64)
+            len + encoding::key_len(tag) + encoding::encoded_len_varint(len as u64)
         } else {
             self.iter().map(|value| value.encoded_len(tag)).sum()
         }
     }
 }
 
+#[doc(hidden)]
+/// Serialize repeated scalar field without packing, `[packed = false]` fields
+pub fn serialize_unpacked<T: NativeType>(items: &[T], tag: u32, dst: &mut BytesMut) {
+    for item in items {
+        item.serialize(tag, DefaultValue::Unknown, dst);
+    }
+}
+
+#[doc(hidden)]
+/// Length of repeated scalar field without packing
+pub fn serialized_len_unpacked<T: NativeType>(items: &[T], tag: u32) -> usize {
+    items.iter().map(|value| value.encoded_len(tag)).sum()
+}
+
 impl<K: NativeType + Eq + Hash, V: NativeType, S: BuildHasher + Default> NativeType
     for HashMap<K, V, S>
 {
@@ -561,6 +689,82 @@ varint!(i64, 0i64);
 varint!(u32, 0u32);
 varint!(u64, 0u64);
 
+/// Macro which emits a wrapper type for a numeric protobuf type with
+/// dedicated wire representation.
+macro_rules! wrapper {
+    ($(#[$meta:meta])* $name:ident($ty:ty)) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name(pub $ty);
+
+        impl From<$ty> for $name {
+            #[inline]
+            fn from(val: $ty) -> Self {
+                $name(val)
+            }
+        }
+
+        impl From<$name> for $ty {
+            #[inline]
+            fn from(val: $name) -> Self {
+                val.0
+            }
+        }
+
+        impl PartialEq<$ty> for $name {
+            #[inline]
+            fn eq(&self, other: &$ty) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl ops::Deref for $name {
+            type Target = $ty;
+
+            #[inline]
+            fn deref(&self) -> &$ty {
+                &self.0
+            }
+        }
+
+        impl ops::DerefMut for $name {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut $ty {
+                &mut self.0
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+wrapper!(
+    /// `sint32` protobuf type, zigzag encoded varint
+    SInt32(i32)
+);
+wrapper!(
+    /// `sint64` protobuf type, zigzag encoded varint
+    SInt64(i64)
+);
+
+varint!(SInt32, SInt32(0),
+        to_uint64(self) u64::from(((self.0 << 1) ^ (self.0 >> 31)) as u32),
+        from_uint64(value) SInt32((((value as u32) >> 1) as i32) ^ -(((value as u32) & 1) as i32)));
+varint!(SInt64, SInt64(0),
+        to_uint64(self) ((self.0 << 1) ^ (self.0 >> 63)) as u64,
+        from_uint64(value) SInt64(((value >> 1) as i64) ^ -((value & 1) as i64)));
+
 /// Macro which emits a module containing a set of encoding functions for a
 /// fixed width numeric type.
 macro_rules! fixed_width {
@@ -622,6 +826,441 @@ fixed_width!(
     Buf::get_f64_le
 );
 
+wrapper!(
+    /// `fixed32` protobuf type, always four bytes
+    Fixed32(u32)
+);
+wrapper!(
+    /// `fixed64` protobuf type, always eight bytes
+    Fixed64(u64)
+);
+wrapper!(
+    /// `sfixed32` protobuf type, always four bytes
+    SFixed32(i32)
+);
+wrapper!(
+    /// `sfixed64` protobuf type, always eight bytes
+    SFixed64(i64)
+);
+
+fixed_width!(
+    Fixed32,
+    4,
+    WireType::ThirtyTwoBit,
+    Fixed32(0),
+    |dst: &mut BytesMut, val: Fixed32| dst.put_u32_le(val.0),
+    |src: &mut Bytes| Fixed32(src.get_u32_le())
+);
+fixed_width!(
+    Fixed64,
+    8,
+    WireType::SixtyFourBit,
+    Fixed64(0),
+    |dst: &mut BytesMut, val: Fixed64| dst.put_u64_le(val.0),
+    |src: &mut Bytes| Fixed64(src.get_u64_le())
+);
+fixed_width!(
+    SFixed32,
+    4,
+    WireType::ThirtyTwoBit,
+    SFixed32(0),
+    |dst: &mut BytesMut, val: SFixed32| dst.put_i32_le(val.0),
+    |src: &mut Bytes| SFixed32(src.get_i32_le())
+);
+fixed_width!(
+    SFixed64,
+    8,
+    WireType::SixtyFourBit,
+    SFixed64(0),
+    |dst: &mut BytesMut, val: SFixed64| dst.put_i64_le(val.0),
+    |src: &mut Bytes| SFixed64(src.get_i64_le())
+);
+
+/// Protobuf enumeration
+pub trait Enumeration: Copy + Default + PartialEq + fmt::Debug {
+    /// Convert numeric value to enum variant
+    fn from_i32(value: i32) -> Option<Self>;
+
+    /// Numeric value of enum variant
+    fn to_i32(self) -> i32;
+}
+
+/// Open enum value
+///
+/// Numeric values unknown to the enum definition are retained and
+/// encoded back as is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnumValue<T> {
+    Known(T),
+    Unknown(i32),
+}
+
+impl<T: Enumeration> EnumValue<T> {
+    /// Get enum variant, `None` if value is unknown
+    pub fn known(self) -> Option<T> {
+        match self {
+            EnumValue::Known(val) => Some(val),
+            EnumValue::Unknown(_) => None,
+        }
+    }
+
+    /// Numeric value
+    pub fn to_i32(self) -> i32 {
+        match self {
+            EnumValue::Known(val) => val.to_i32(),
+            EnumValue::Unknown(val) => val,
+        }
+    }
+}
+
+impl<T: Enumeration> Default for EnumValue<T> {
+    fn default() -> Self {
+        EnumValue::Known(T::default())
+    }
+}
+
+impl<T: Enumeration> From<T> for EnumValue<T> {
+    fn from(val: T) -> Self {
+        EnumValue::Known(val)
+    }
+}
+
+impl<T: Enumeration> From<i32> for EnumValue<T> {
+    fn from(val: i32) -> Self {
+        T::from_i32(val).map_or(EnumValue::Unknown(val), EnumValue::Known)
+    }
+}
+
+impl<T: Enumeration> NativeType for EnumValue<T> {
+    const TYPE: WireType = WireType::Varint;
+
+    #[inline]
+    fn merge(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
+        *self = encoding::decode_varint(src).map(|val| EnumValue::from(val as i32))?;
+        Ok(())
+    }
+
+    #[inline]
+    fn encode_value(&self, dst: &mut BytesMut) {
+        encoding::encode_varint(self.to_i32() as u64, dst);
+    }
+
+    #[inline]
+    fn encoded_len(&self, tag: u32) -> usize {
+        encoding::key_len(tag) + encoding::encoded_len_varint(self.to_i32() as u64)
+    }
+
+    #[inline]
+    fn value_len(&self) -> usize {
+        encoding::encoded_len_varint(self.to_i32() as u64)
+    }
+
+    #[inline]
+    fn is_default(&self) -> bool {
+        self.to_i32() == T::default().to_i32()
+    }
+}
+
+/// Protobuf group
+///
+/// Group content is encoded as a message delimited by start and end group keys.
+#[derive(Clone, Default, PartialEq)]
+pub struct Group<T>(pub T);
+
+impl<T> From<T> for Group<T> {
+    #[inline]
+    fn from(val: T) -> Self {
+        Group(val)
+    }
+}
+
+impl<T> ops::Deref for Group<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> ops::DerefMut for Group<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Group<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T: Message + PartialEq> NativeType for Group<T> {
+    const TYPE: WireType = WireType::StartGroup;
+
+    #[inline]
+    fn value_len(&self) -> usize {
+        Message::encoded_len(&self.0)
+    }
+
+    #[inline]
+    fn merge(&mut self, src: &mut Bytes) -> Result<(), DecodeError> {
+        Message::merge(&mut self.0, src)
+    }
+
+    #[inline]
+    fn encode_value(&self, dst: &mut BytesMut) {
+        self.0.write(dst);
+    }
+
+    #[inline]
+    fn encoded_len(&self, tag: u32) -> usize {
+        encoding::key_len(tag) * 2 + self.value_len()
+    }
+
+    #[inline]
+    fn serialize(&self, tag: u32, default: DefaultValue<&Self>, dst: &mut BytesMut) {
+        if let DefaultValue::Value(d) = default
+            && self == d
+        {
+            return;
+        }
+        encoding::encode_key(tag, WireType::StartGroup, dst);
+        self.encode_value(dst);
+        encoding::encode_key(tag, WireType::EndGroup, dst);
+    }
+
+    #[inline]
+    fn deserialize(
+        &mut self,
+        tag: u32,
+        wtype: WireType,
+        src: &mut Bytes,
+    ) -> Result<(), DecodeError> {
+        encoding::check_wire_type(Self::TYPE, wtype)?;
+        let mut group = encoding::decode_group(tag, src)?;
+        self.merge(&mut group)
+    }
+}
+
+/// Extension field of the `M` message with value of type `T`
+pub struct Extension<M, T> {
+    info: ExtensionInfo,
+    _t: PhantomData<fn(&M) -> T>,
+}
+
+impl<M, T> Extension<M, T> {
+    /// Create extension descriptor
+    pub const fn new(name: &'static str, extendee: &'static str, number: u32) -> Self {
+        Self {
+            info: ExtensionInfo {
+                name,
+                extendee,
+                number,
+            },
+            _t: PhantomData,
+        }
+    }
+
+    #[inline]
+    /// Full name of the extension
+    pub const fn name(&self) -> &'static str {
+        self.info.name
+    }
+
+    #[inline]
+    /// Field number of the extension
+    pub const fn number(&self) -> u32 {
+        self.info.number
+    }
+
+    #[inline]
+    /// Untyped extension descriptor
+    pub const fn info(&self) -> &ExtensionInfo {
+        &self.info
+    }
+}
+
+impl<M, T> Clone for Extension<M, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, T> Copy for Extension<M, T> {}
+
+impl<M, T> fmt::Debug for Extension<M, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extension")
+            .field("name", &self.info.name)
+            .field("extendee", &self.info.extendee)
+            .field("number", &self.info.number)
+            .finish()
+    }
+}
+
+/// Untyped extension descriptor
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExtensionInfo {
+    /// Full name of the extension
+    pub name: &'static str,
+    /// Full name of the extended message
+    pub extendee: &'static str,
+    /// Field number of the extension
+    pub number: u32,
+}
+
+/// Message with extension ranges
+///
+/// Extension fields are stored in unknown fields of the message.
+pub trait Extendable: Message {
+    /// Full name of the message
+    const NAME: &'static str;
+
+    /// Encoded unknown fields of the message
+    fn unknown_fields(&self) -> &Bytes;
+
+    /// Mutable reference to encoded unknown fields of the message
+    fn unknown_fields_mut(&mut self) -> &mut Bytes;
+
+    /// Check if extension field is set
+    fn has_extension<T>(&self, ext: &Extension<Self, T>) -> bool {
+        fields(self.unknown_fields()).any(|field| field.is_ok_and(|(tag, _)| tag == ext.number()))
+    }
+
+    /// Decode extension field value
+    ///
+    /// Returns `None` if extension field is not set. Repeated extension
+    /// fields are collected, singular fields are merged.
+    fn get_extension<T: NativeType>(
+        &self,
+        ext: &Extension<Self, T>,
+    ) -> Result<Option<T>, DecodeError> {
+        let mut value: Option<T> = None;
+        let mut src = self.unknown_fields().clone();
+        while !src.is_empty() {
+            let (tag, wire_type) = encoding::decode_key(&mut src)?;
+            if tag == ext.number() {
+                value
+                    .get_or_insert_with(T::default)
+                    .deserialize(tag, wire_type, &mut src)
+                    .map_err(|err| err.push(Self::NAME, ext.name()))?;
+            } else {
+                encoding::skip_field(wire_type, tag, &mut src)?;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Set extension field value, previous value is replaced
+    fn set_extension<T: NativeType>(&mut self, ext: &Extension<Self, T>, value: &T) {
+        let mut dst = retain_fields(self.unknown_fields(), |tag| tag != ext.number());
+        value.serialize(ext.number(), DefaultValue::Unknown, &mut dst);
+        *self.unknown_fields_mut() = dst.freeze();
+    }
+
+    /// Remove extension field
+    fn clear_extension<T>(&mut self, ext: &Extension<Self, T>) {
+        let dst = retain_fields(self.unknown_fields(), |tag| tag != ext.number());
+        *self.unknown_fields_mut() = dst.freeze();
+    }
+}
+
+/// Iterate over encoded fields, yields field tag and encoded field
+fn fields(buf: &Bytes) -> impl Iterator<Item = Result<(u32, Bytes), DecodeError>> + '_ {
+    let mut src = buf.clone();
+    std::iter::from_fn(move || {
+        if src.is_empty() {
+            return None;
+        }
+        let start = buf.len() - src.len();
+        let res = encoding::decode_key(&mut src).and_then(|(tag, wire_type)| {
+            encoding::skip_field(wire_type, tag, &mut src).map(|()| tag)
+        });
+        match res {
+            Ok(tag) => Some(Ok((tag, buf.slice(start..buf.len() - src.len())))),
+            Err(err) => {
+                src.clear();
+                Some(Err(err))
+            }
+        }
+    })
+}
+
+/// Copy encoded fields matching predicate, malformed tail is preserved as is
+fn retain_fields<F: Fn(u32) -> bool>(buf: &Bytes, f: F) -> BytesMut {
+    let mut dst = BytesMut::with_capacity(buf.len());
+    let mut consumed = 0;
+    for field in fields(buf) {
+        match field {
+            Ok((tag, field)) => {
+                consumed += field.len();
+                if f(tag) {
+                    dst.extend_from_slice(&field);
+                }
+            }
+            Err(_) => dst.extend_from_slice(&buf[consumed..]),
+        }
+    }
+    dst
+}
+
+/// Registry of known extensions
+///
+/// Could be used for discovering extension fields of decoded messages.
+#[derive(Clone, Debug, Default)]
+pub struct ExtensionRegistry {
+    extensions: crate::HashMap<&'static str, crate::HashMap<u32, ExtensionInfo>>,
+}
+
+impl ExtensionRegistry {
+    /// Create empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register extension
+    pub fn register<M, T>(&mut self, ext: &Extension<M, T>) -> &mut Self {
+        self.register_info(*ext.info())
+    }
+
+    /// Register untyped extension descriptor
+    pub fn register_info(&mut self, info: ExtensionInfo) -> &mut Self {
+        self.extensions
+            .entry(info.extendee)
+            .or_default()
+            .insert(info.number, info);
+        self
+    }
+
+    /// Find extension by extended message name and field number
+    pub fn find(&self, extendee: &str, number: u32) -> Option<&ExtensionInfo> {
+        self.extensions.get(extendee)?.get(&number)
+    }
+
+    /// Find extension by full name
+    pub fn find_by_name(&self, name: &str) -> Option<&ExtensionInfo> {
+        self.extensions
+            .values()
+            .flat_map(|exts| exts.values())
+            .find(|info| info.name == name)
+    }
+
+    /// Extensions set on the message
+    pub fn extensions_of<M: Extendable>(&self, msg: &M) -> Vec<&ExtensionInfo> {
+        let mut result: Vec<&ExtensionInfo> = Vec::new();
+        for (tag, _) in fields(msg.unknown_fields()).map_while(Result::ok) {
+            if let Some(info) = self.find(M::NAME, tag)
+                && !result.contains(&info)
+            {
+                result.push(info);
+            }
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -668,6 +1307,343 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_merge() {
+        use crate::google_types::{Any, Duration, RetryInfo, Status};
+
+        // default implementation replaces message
+        let mut msg = TestMessage {
+            b: true,
+            ..TestMessage::default()
+        };
+        let other = TestMessage {
+            f: 1.0,
+            ..TestMessage::default()
+        };
+        let mut buf = BytesMut::new();
+        other.write(&mut buf);
+        Message::merge(&mut msg, &mut buf.freeze()).unwrap();
+        assert_eq!(msg, other);
+
+        // embedded messages are merged
+        let retry = |seconds, nanos| RetryInfo {
+            retry_delay: Duration { seconds, nanos },
+        };
+        let mut buf = BytesMut::new();
+        retry(1, 0).write(&mut buf);
+        retry(0, 5).write(&mut buf);
+        assert_eq!(RetryInfo::read(&mut buf.freeze()).unwrap(), retry(1, 5));
+
+        // singular fields are replaced, repeated fields are extended
+        let any = |value| Any {
+            type_url: ByteString::from_static("type.googleapis.com/test.Msg"),
+            value: Bytes::from_static(value),
+        };
+        let mut status = Status {
+            code: 5,
+            message: ByteString::from_static("first"),
+            details: vec![any(b"1")],
+        };
+        let mut buf = BytesMut::new();
+        Status {
+            code: 13,
+            message: ByteString::from_static("second"),
+            details: vec![any(b"2")],
+        }
+        .write(&mut buf);
+        Message::merge(&mut status, &mut buf.freeze()).unwrap();
+        assert_eq!(status.code, 13);
+        assert_eq!(status.message, "second");
+        assert_eq!(status.details, vec![any(b"1"), any(b"2")]);
+    }
+
+    #[test]
+    fn test_zigzag() {
+        fn encode<T: NativeType>(val: &T) -> Bytes {
+            let mut buf = BytesMut::new();
+            val.encode_value(&mut buf);
+            buf.freeze()
+        }
+
+        assert_eq!(encode(&SInt32(0)), Bytes::from_static(&[0]));
+        assert_eq!(encode(&SInt32(-1)), Bytes::from_static(&[1]));
+        assert_eq!(encode(&SInt32(1)), Bytes::from_static(&[2]));
+        assert_eq!(encode(&SInt32(-2)), Bytes::from_static(&[3]));
+        assert_eq!(
+            encode(&SInt32(i32::MIN)),
+            Bytes::from_static(&[0xff, 0xff, 0xff, 0xff, 0x0f])
+        );
+        assert_eq!(encode(&SInt64(-1)), Bytes::from_static(&[1]));
+        assert_eq!(encode(&SInt64(i64::MAX)).len(), 10);
+
+        for val in [0, 1, -1, 63, -64, i32::MAX, i32::MIN] {
+            let mut buf = encode(&SInt32(val));
+            assert_eq!(SInt32(val).value_len(), buf.len());
+            let mut res = SInt32::default();
+            res.merge(&mut buf).unwrap();
+            assert_eq!(res, val);
+        }
+        for val in [0, 1, -1, i64::MAX, i64::MIN] {
+            let mut buf = encode(&SInt64(val));
+            let mut res = SInt64::default();
+            res.merge(&mut buf).unwrap();
+            assert_eq!(res, val);
+        }
+
+        let mut buf = BytesMut::new();
+        vec![SInt32(-1), SInt32(1)].serialize(1, DefaultValue::Default, &mut buf);
+        assert_eq!(&buf[..], &[0x0a, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_fixed_width() {
+        fn roundtrip<T: NativeType + Clone>(val: &T, expected: &[u8]) {
+            let mut buf = BytesMut::new();
+            val.serialize(1, DefaultValue::Unknown, &mut buf);
+            assert_eq!(&buf[..], expected);
+            assert_eq!(val.serialized_len(1, DefaultValue::Unknown), buf.len());
+
+            let mut buf = buf.freeze();
+            let (tag, wire_type) = encoding::decode_key(&mut buf).unwrap();
+            assert_eq!(
+                T::deserialize_default(tag, wire_type, &mut buf).unwrap(),
+                *val
+            );
+            assert!(buf.is_empty());
+        }
+
+        roundtrip(&Fixed32(1), &[0x0d, 1, 0, 0, 0]);
+        roundtrip(&SFixed32(-2), &[0x0d, 0xfe, 0xff, 0xff, 0xff]);
+        roundtrip(
+            &Fixed64(u64::MAX),
+            &[0x09, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        );
+        roundtrip(
+            &SFixed64(-1),
+            &[0x09, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        );
+        roundtrip(&1.5f32, &[0x0d, 0, 0, 0xc0, 0x3f]);
+
+        // packed
+        roundtrip(
+            &vec![Fixed32(1), Fixed32(2)],
+            &[0x0a, 8, 1, 0, 0, 0, 2, 0, 0, 0],
+        );
+        roundtrip(
+            &vec![SFixed64(-1)],
+            &[0x0a, 8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        );
+
+        // unpacked
+        let mut buf = Bytes::from_static(&[0x0d, 1, 0, 0, 0, 0x0d, 2, 0, 0, 0]);
+        let mut val = Vec::<Fixed32>::new();
+        while !buf.is_empty() {
+            let (tag, wire_type) = encoding::decode_key(&mut buf).unwrap();
+            val.deserialize(tag, wire_type, &mut buf).unwrap();
+        }
+        assert_eq!(val, vec![Fixed32(1), Fixed32(2)]);
+
+        let mut buf = Bytes::from_static(&[1, 0]);
+        assert!(Fixed32::default().merge(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_packed() {
+        fn decode<T: NativeType>(mut buf: Bytes) -> Result<Vec<T>, DecodeError> {
+            let mut val = Vec::new();
+            while !buf.is_empty() {
+                let (tag, wire_type) = encoding::decode_key(&mut buf)?;
+                val.deserialize(tag, wire_type, &mut buf)?;
+            }
+            Ok(val)
+        }
+
+        let values: Vec<f32> = (0..1000u16).map(|v| f32::from(v) * 0.5).collect();
+        let mut buf = BytesMut::new();
+        values.serialize(1, DefaultValue::Default, &mut buf);
+        assert_eq!(buf.len(), 4003);
+        assert_eq!(values.serialized_len(1, DefaultValue::Default), 4003);
+        assert_eq!(&buf[..3], &[0x0a, 0xa0, 0x1f]);
+        assert_eq!(decode::<f32>(buf.freeze()).unwrap(), values);
+
+        let mut buf = BytesMut::new();
+        vec![1.0f64, -2.0].serialize(1, DefaultValue::Default, &mut buf);
+        assert_eq!(buf.len(), 18);
+        assert_eq!(decode::<f64>(buf.freeze()).unwrap(), vec![1.0, -2.0]);
+
+        // unpacked and mixed input
+        let buf = Bytes::from_static(&[0x08, 1, 0x08, 0x96, 0x01, 0x0a, 2, 3, 4]);
+        assert_eq!(decode::<u32>(buf).unwrap(), vec![1, 150, 3, 4]);
+        let buf = Bytes::from_static(&[0x0d, 0, 0, 0x80, 0x3f, 0x0a, 4, 0, 0, 0, 0x40]);
+        assert_eq!(decode::<f32>(buf).unwrap(), vec![1.0, 2.0]);
+
+        // truncated packed values
+        let buf = Bytes::from_static(&[0x0a, 3, 0, 0, 0x80]);
+        assert!(decode::<f32>(buf).is_err());
+        let buf = Bytes::from_static(&[0x0a, 4, 0, 0, 0x80]);
+        assert!(decode::<f32>(buf).is_err());
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    enum Kind {
+        #[default]
+        First,
+        Second,
+    }
+
+    impl Enumeration for Kind {
+        fn from_i32(value: i32) -> Option<Self> {
+            match value {
+                0 => Some(Kind::First),
+                1 => Some(Kind::Second),
+                _ => None,
+            }
+        }
+
+        fn to_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    #[test]
+    fn test_enum_value() {
+        fn roundtrip(val: EnumValue<Kind>) -> EnumValue<Kind> {
+            let mut buf = BytesMut::new();
+            val.serialize(1, DefaultValue::Default, &mut buf);
+            assert_eq!(val.serialized_len(1, DefaultValue::Default), buf.len());
+            let mut buf = buf.freeze();
+            let mut res = EnumValue::default();
+            while !buf.is_empty() {
+                let (tag, wire_type) = encoding::decode_key(&mut buf).unwrap();
+                res.deserialize(tag, wire_type, &mut buf).unwrap();
+            }
+            res
+        }
+
+        assert!(EnumValue::<Kind>::default().is_default());
+        assert_eq!(
+            roundtrip(Kind::Second.into()),
+            EnumValue::Known(Kind::Second)
+        );
+        assert_eq!(roundtrip(EnumValue::Unknown(5)), EnumValue::Unknown(5));
+        assert_eq!(roundtrip(EnumValue::Unknown(-1)), EnumValue::Unknown(-1));
+        assert_eq!(EnumValue::<Kind>::from(1).known(), Some(Kind::Second));
+        assert_eq!(EnumValue::<Kind>::from(7).known(), None);
+        assert_eq!(EnumValue::<Kind>::from(7).to_i32(), 7);
+    }
+
+    #[test]
+    fn test_boxed() {
+        let msg = Some(Box::new(TestMessage {
+            b: true,
+            ..Default::default()
+        }));
+        let mut buf = BytesMut::new();
+        msg.serialize(1, DefaultValue::Default, &mut buf);
+        assert_eq!(msg.serialized_len(1, DefaultValue::Default), buf.len());
+
+        let mut buf = buf.freeze();
+        let (tag, wire_type) = encoding::decode_key(&mut buf).unwrap();
+        let mut res: Option<Box<TestMessage>> = None;
+        res.deserialize(tag, wire_type, &mut buf).unwrap();
+        assert_eq!(res, msg);
+    }
+
+    #[test]
+    fn test_group() {
+        let msg = TestMessage {
+            f: 1.5,
+            b: true,
+            ..Default::default()
+        };
+        let groups = vec![Group(msg.clone()), Group(TestMessage::default())];
+
+        let mut buf = BytesMut::new();
+        groups.serialize(2, DefaultValue::Default, &mut buf);
+        assert_eq!(groups.serialized_len(2, DefaultValue::Default), buf.len());
+        assert_eq!(&buf[..2], &[0x13, 0x09]);
+        assert_eq!(&buf[buf.len() - 3..], &[0x14, 0x13, 0x14]);
+
+        // unknown group with nested group is skipped
+        buf.extend_from_slice(&[0x1b, 0x23, 0x08, 0x01, 0x24, 0x1c]);
+
+        let mut buf = buf.freeze();
+        let mut res = Vec::<Group<TestMessage>>::new();
+        while !buf.is_empty() {
+            let (tag, wire_type) = encoding::decode_key(&mut buf).unwrap();
+            if tag == 2 {
+                res.deserialize(tag, wire_type, &mut buf).unwrap();
+            } else {
+                encoding::skip_field(wire_type, tag, &mut buf).unwrap();
+            }
+        }
+        assert_eq!(res, groups);
+        assert!(res[0].b);
+
+        // mismatched end group
+        let mut buf = Bytes::from_static(&[0x08, 0x01, 0x1c]);
+        assert!(encoding::decode_group(2, &mut buf).is_err());
+        let mut buf = Bytes::from_static(&[0x08, 0x01]);
+        assert!(encoding::decode_group(2, &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_extensions() {
+        use crate::google_types::MethodOptions;
+
+        const SCOPES: Extension<MethodOptions, Vec<ByteString>> =
+            Extension::new("test.scopes", "google.protobuf.MethodOptions", 50001);
+        const LEVEL: Extension<MethodOptions, u32> =
+            Extension::new("test.level", "google.protobuf.MethodOptions", 50002);
+        const OTHER: Extension<MethodOptions, bool> =
+            Extension::new("test.other", "google.protobuf.MethodOptions", 50003);
+
+        let mut opts = MethodOptions {
+            deprecated: true,
+            ..Default::default()
+        };
+        assert!(!opts.has_extension(&SCOPES));
+        assert_eq!(opts.get_extension(&SCOPES).unwrap(), None);
+
+        opts.set_extension(&LEVEL, &0);
+        opts.set_extension(&SCOPES, &vec!["read".into(), "write".into()]);
+        opts.set_extension(&LEVEL, &5);
+        assert!(opts.has_extension(&LEVEL));
+        assert_eq!(opts.get_extension(&LEVEL).unwrap(), Some(5));
+
+        let mut buf = BytesMut::new();
+        opts.write(&mut buf);
+        let opts = MethodOptions::read(&mut buf.freeze()).unwrap();
+        assert!(opts.deprecated);
+        assert_eq!(opts.get_extension(&LEVEL).unwrap(), Some(5));
+        assert_eq!(
+            opts.get_extension(&SCOPES).unwrap().unwrap(),
+            vec!["read", "write"]
+        );
+
+        let mut registry = ExtensionRegistry::new();
+        registry.register(&SCOPES).register(&LEVEL).register(&OTHER);
+        assert_eq!(
+            registry.find("google.protobuf.MethodOptions", 50002),
+            Some(LEVEL.info())
+        );
+        assert_eq!(registry.find("google.protobuf.ServiceOptions", 50002), None);
+        assert_eq!(registry.find_by_name("test.scopes"), Some(SCOPES.info()));
+        assert_eq!(
+            registry.extensions_of(&opts),
+            vec![SCOPES.info(), LEVEL.info()]
+        );
+
+        let mut opts = opts;
+        opts.clear_extension(&SCOPES);
+        assert!(!opts.has_extension(&SCOPES));
+        assert_eq!(opts.get_extension(&LEVEL).unwrap(), Some(5));
+
+        // wrong wire type
+        opts.unknown_fields = Bytes::from_static(&[0x92, 0xb5, 0x18, 0x01, 0x00]);
+        assert!(opts.get_extension(&LEVEL).is_err());
+    }
+
     #[allow(clippy::field_reassign_with_default)]
     #[test]
     fn test_hashmap_default_values() {
@@ -677,7 +1653,7 @@ mod tests {
         msg.b = true;
         msg.props.insert("test1".to_string(), 1);
         msg.props.insert("test2".to_string(), 0);
-        msg.props.insert("".to_string(), 0);
+        msg.props.insert(String::new(), 0);
 
         let mut buf = BytesMut::new();
         msg.write(&mut buf);
@@ -1,6 +1,8 @@
 use std::mem;
 
-use ntex_bytes::{Bytes, BytesMut};
+use ntex_bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::{CompressionEncoding, DecodeError};
 
 pub(crate) enum Data {
     Chunk(Bytes),
@@ -17,6 +19,14 @@ impl Data {
         }
     }
 
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            Data::Chunk(data) => data.is_empty(),
+            Data::MutChunk(data) => data.is_empty(),
+            Data::Empty => true,
+        }
+    }
+
     pub(crate) fn push(&mut self, data: Bytes) {
         if !data.is_empty() {
             *self = match mem::replace(self, Data::Empty) {
@@ -34,3 +44,171 @@ impl Data {
         }
     }
 }
+
+/// Encode length-prefixed message
+pub(crate) fn encode_frame(payload: &[u8], encoding: CompressionEncoding, buf: &mut BytesMut) {
+    if encoding == CompressionEncoding::Identity {
+        buf.reserve(payload.len() + 5);
+        buf.put_u8(0); // compression
+        buf.put_u32(payload.len() as u32); // length
+        buf.extend_from_slice(payload);
+    } else {
+        let start = buf.len();
+        buf.put_u8(1);
+        buf.put_u32(0);
+        encoding.compress(payload, buf);
+        let len = (buf.len() - start - 5) as u32;
+        buf[start + 1..start + 5].copy_from_slice(&len.to_be_bytes());
+    }
+}
+
+/// Default max size of received message, 4 MiB
+pub(crate) const DEFAULT_MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Length-prefixed grpc message
+#[derive(Debug)]
+pub(crate) struct Frame {
+    pub(crate) compressed: bool,
+    pub(crate) payload: Bytes,
+}
+
+/// Frame decoding error
+#[derive(Debug)]
+pub(crate) enum FrameError {
+    /// Malformed message
+    Decode(DecodeError),
+    /// Message is larger than max message size
+    TooLarge,
+    /// Compressed message without message encoding
+    Uncompressed,
+}
+
+impl From<DecodeError> for FrameError {
+    fn from(err: DecodeError) -> Self {
+        FrameError::Decode(err)
+    }
+}
+
+/// Incremental decoder for grpc length-prefixed messages
+pub(crate) struct FrameDecoder {
+    data: Data,
+    max_size: usize,
+}
+
+impl FrameDecoder {
+    pub(crate) fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            data: Data::Empty,
+        }
+    }
+
+    /// Add received chunk to the buffer
+    pub(crate) fn push(&mut self, chunk: Bytes) {
+        self.data.push(chunk);
+    }
+
+    /// Max message size
+    pub(crate) fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Drop buffered data
+    pub(crate) fn clear(&mut self) {
+        self.data = Data::Empty;
+    }
+
+    /// Take all buffered data
+    pub(crate) fn take(&mut self) -> Bytes {
+        self.data.get()
+    }
+
+    /// Decode next complete message from the buffer
+    ///
+    /// Message length is checked before message is buffered.
+    pub(crate) fn decode(&mut self) -> Result<Option<Frame>, FrameError> {
+        let mut buf = self.data.get();
+        if buf.len() < 5 {
+            self.data.push(buf);
+            return Ok(None);
+        }
+
+        let compressed = match buf[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(DecodeError::new("Invalid message compression flag").into()),
+        };
+        let len = (&buf[1..5]).get_u32() as usize;
+        if len > self.max_size {
+            return Err(FrameError::TooLarge);
+        }
+        if buf.len() < len + 5 {
+            self.data.push(buf);
+            return Ok(None);
+        }
+
+        let payload = buf.split_to(len + 5).split_off(5);
+        self.data.push(buf);
+        Ok(Some(Frame {
+            compressed,
+            payload,
+        }))
+    }
+
+    /// Check that all received data is decoded
+    ///
+    /// Must be called after the stream is finished.
+    pub(crate) fn finish(&mut self) -> Result<(), DecodeError> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            self.data = Data::Empty;
+            Err(DecodeError::new("Message is truncated"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_decoder() {
+        let mut dec = FrameDecoder::new(DEFAULT_MAX_MESSAGE_SIZE);
+        dec.push(Bytes::from_static(b"\x00\x00\x00"));
+        assert!(dec.decode().unwrap().is_none());
+        dec.push(Bytes::from_static(b"\x00\x02ab\x01\x00\x00\x00\x01c\x00"));
+
+        let frame = dec.decode().unwrap().unwrap();
+        assert!(!frame.compressed);
+        assert_eq!(frame.payload, Bytes::from_static(b"ab"));
+        let frame = dec.decode().unwrap().unwrap();
+        assert!(frame.compressed);
+        assert_eq!(frame.payload, Bytes::from_static(b"c"));
+        assert!(dec.decode().unwrap().is_none());
+        assert!(dec.finish().is_err());
+        assert!(dec.finish().is_ok());
+
+        dec.push(Bytes::from_static(b"\x00\x00\x00\x00\x00"));
+        assert_eq!(dec.decode().unwrap().unwrap().payload, Bytes::new());
+        assert!(dec.finish().is_ok());
+
+        dec.push(Bytes::from_static(b"\x05\x00\x00\x00\x00"));
+        assert!(matches!(dec.decode(), Err(FrameError::Decode(_))));
+    }
+
+    #[test]
+    fn frame_decoder_max_size() {
+        let mut dec = FrameDecoder::new(4);
+        dec.push(Bytes::from_static(b"\x00\x00\x00\x00\x04abcd"));
+        assert_eq!(dec.decode().unwrap().unwrap().payload, "abcd");
+
+        // length prefix is checked before payload is received
+        dec.push(Bytes::from_static(b"\x00\x00\x00\x00\x05"));
+        assert!(matches!(dec.decode(), Err(FrameError::TooLarge)));
+
+        let mut dec = FrameDecoder::new(DEFAULT_MAX_MESSAGE_SIZE);
+        dec.push(Bytes::from_static(b"\x00\xff\xff\xff\xff"));
+        assert!(matches!(dec.decode(), Err(FrameError::TooLarge)));
+    }
+}
@@ -21,7 +21,10 @@ impl Comments {
         where
             S: AsRef<str>,
         {
-            comments.as_ref().lines().map(str::to_owned).collect()
+            let lines = comments.as_ref().lines().map(str::to_owned).collect();
+            #[cfg(feature = "cleanup-markdown")]
+            let lines = cleanup_markdown(lines);
+            lines
         }
 
         let leading_detached = location
@@ -106,6 +109,79 @@ impl Comments {
     }
 }
 
+/// Converts code blocks to fenced blocks, so rustdoc does not try to compile them.
+///
+/// Indented blocks and blocks without language become `text` blocks, `rust`
+/// blocks are marked as `compile_fail`.
+#[cfg(feature = "cleanup-markdown")]
+fn cleanup_markdown(lines: Vec<String>) -> Vec<String> {
+    fn start_block(result: &mut Vec<String>) {
+        if result.last().is_none_or(|l| !l.trim().is_empty()) {
+            result.push(String::new());
+        }
+    }
+
+    fn end_block(result: &mut Vec<String>) {
+        // trailing empty lines do not belong to the block
+        let blanks = result.iter().rev().take_while(|l| l.is_empty()).count();
+        result.truncate(result.len() - blanks);
+        result.push("```".to_string());
+        result.extend(std::iter::repeat_n(String::new(), blanks));
+    }
+
+    let mut result = Vec::with_capacity(lines.len());
+    let mut fenced = false;
+    let mut indented = false;
+
+    for line in lines {
+        let trimmed = line.trim_start();
+        if fenced {
+            if trimmed.starts_with("```") {
+                result.push("```".to_string());
+                fenced = false;
+            } else {
+                result.push(line);
+            }
+            continue;
+        }
+        if indented {
+            if trimmed.is_empty() {
+                result.push(String::new());
+                continue;
+            } else if let Some(code) = line.strip_prefix("    ") {
+                result.push(code.to_string());
+                continue;
+            }
+            end_block(&mut result);
+            indented = false;
+        }
+
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            start_block(&mut result);
+            result.push(match lang.trim() {
+                "" => "```text".to_string(),
+                "rust" => "```compile_fail".to_string(),
+                lang => format!("```text,{lang}"),
+            });
+            fenced = true;
+        } else if !trimmed.is_empty()
+            && line.starts_with("    ")
+            && result.last().is_none_or(|l: &String| l.trim().is_empty())
+        {
+            start_block(&mut result);
+            result.push("```text".to_string());
+            result.push(line[4..].to_string());
+            indented = true;
+        } else {
+            result.push(line);
+        }
+    }
+    if indented {
+        end_block(&mut result);
+    }
+    result
+}
+
 /// A service descriptor.
 #[derive(Debug)]
 pub struct Service {
@@ -121,6 +197,8 @@ pub struct Service {
     pub methods: Vec<Method>,
     /// The service options.
     pub options: prost_types::ServiceOptions,
+    /// The encoded service options, including custom options.
+    pub encoded_options: Vec<u8>,
 }
 
 /// A service method descriptor.
@@ -142,6 +220,8 @@ pub struct Method {
     pub output_proto_type: String,
     /// The method options.
     pub options: prost_types::MethodOptions,
+    /// The encoded method options, including custom options.
+    pub encoded_options: Vec<u8>,
     /// Identifies if client streams multiple client messages.
     pub client_streaming: bool,
     /// Identifies if server streams multiple server messages.
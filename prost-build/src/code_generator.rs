@@ -13,7 +13,7 @@ use prost_types::{
 
 use crate::ast::{Comments, Method, Service};
 use crate::ident::{to_snake, to_upper_camel};
-use crate::{extern_paths::ExternPaths, Config};
+use crate::{extern_paths::ExternPaths, message_graph::MessageGraph, Config};
 
 #[derive(PartialEq)]
 enum Syntax {
@@ -28,6 +28,7 @@ pub struct CodeGenerator<'a> {
     source_info: SourceCodeInfo,
     syntax: Syntax,
     extern_paths: &'a ExternPaths,
+    message_graph: &'a MessageGraph,
     depth: u8,
     path: Vec<i32>,
     buf: &'a mut String,
@@ -44,6 +45,7 @@ impl CodeGenerator<'_> {
     pub fn generate(
         config: &mut Config,
         extern_paths: &ExternPaths,
+        message_graph: &MessageGraph,
         file: FileDescriptorProto,
         buf: &mut String,
     ) {
@@ -81,6 +83,7 @@ impl CodeGenerator<'_> {
             source_info,
             syntax,
             extern_paths,
+            message_graph,
             buf,
             depth: 0,
             path: Vec::new(),
@@ -110,6 +113,14 @@ impl CodeGenerator<'_> {
         }
         code_gen.path.pop();
 
+        code_gen.path.push(7);
+        for (idx, extension) in file.extension.into_iter().enumerate() {
+            code_gen.path.push(idx as i32);
+            code_gen.append_extension(extension);
+            code_gen.path.pop();
+        }
+        code_gen.path.pop();
+
         if code_gen.config.service_generator.is_some() {
             code_gen.path.push(6);
             for (idx, service) in file.service.into_iter().enumerate() {
@@ -197,6 +208,27 @@ impl CodeGenerator<'_> {
                 }
             });
 
+        let preserve_unknown = self
+            .config
+            .preserve_unknown_fields
+            .get(&fq_message_name)
+            .next()
+            .is_some()
+            || !message.extension_range.is_empty();
+        if preserve_unknown
+            && fields
+                .iter()
+                .map(|(field, _)| field.name())
+                .chain(message.oneof_decl.iter().map(|oneof| oneof.name()))
+                .any(|name| to_snake(name) == "unknown_fields")
+        {
+            panic!(
+                "{fq_message_name}: field `unknown_fields` conflicts with storage of preserved unknown fields"
+            );
+        }
+
+        let type_path = self.nested_path(&to_upper_camel(&message_name));
+
         self.append_doc(&fq_message_name, None);
         self.append_type_attributes(&fq_message_name);
         self.push_indent();
@@ -207,36 +239,66 @@ impl CodeGenerator<'_> {
         self.buf.push_str(" {\n");
 
         self.priv_buf.push_str("impl ::ntex_grpc::Message for ");
-        self.priv_buf.push_str(&to_upper_camel(&message_name));
+        self.priv_buf.push_str(&type_path);
         self.priv_buf.push_str(" {\n");
 
         let mut has_fields = false;
         let mut write = String::new();
-        let mut read = String::new();
+        let mut read = Vec::new();
         let mut encoded_len = String::new();
         let mut default = String::new();
+        let mut required = Vec::new();
 
         self.depth += 1;
         self.path.push(2);
         for (field, idx) in fields {
             let field_no = field.number();
             let field_name = to_snake(field.name());
+            let default_value = self.default_value(&field, &fq_message_name);
 
             self.path.push(idx as i32);
 
+            // required fields are always serialized
+            let default_arg = if field.label() == Label::Required {
+                required.push((field_no, field_name.clone()));
+                "Unknown".to_string()
+            } else if let Some(ref value) = default_value {
+                format!("Value(&{value})")
+            } else {
+                "Default".to_string()
+            };
+
             has_fields = true;
-            write.push_str(&format!(
-                "::ntex_grpc::NativeType::serialize(&self.{field_name}, {field_no}, ::ntex_grpc::types::DefaultValue::Default, dst);",
-            ));
-            read.push_str(&format!(
-                "{field_no} => ::ntex_grpc::NativeType::deserialize(&mut msg.{field_name}, tag, wire_type, src)
-                    .map_err(|err| err.push(STRUCT_NAME, \"{field_name}\"))?,",
-            ));
-            encoded_len.push_str(&format!(
-                " + ::ntex_grpc::NativeType::serialized_len(&self.{field_name}, {field_no}, ::ntex_grpc::types::DefaultValue::Default)",
+            let unpacked = self.unpacked(&field);
+            if unpacked {
+                write.push_str(&format!(
+                    "::ntex_grpc::types::serialize_unpacked(&self.{field_name}, {field_no}, dst);",
+                ));
+            } else {
+                write.push_str(&format!(
+                    "::ntex_grpc::NativeType::serialize(&self.{field_name}, {field_no}, ::ntex_grpc::types::DefaultValue::{default_arg}, dst);",
+                ));
+            }
+            read.push((
+                field_no.to_string(),
+                field_name.clone(),
+                field.label() == Label::Required,
+                field.r#type() == Type::Message && !map_types.contains_key(field.type_name()),
             ));
+            if unpacked {
+                encoded_len.push_str(&format!(
+                    " + ::ntex_grpc::types::serialized_len_unpacked(&self.{field_name}, {field_no})",
+                ));
+            } else {
+                encoded_len.push_str(&format!(
+                    " + ::ntex_grpc::NativeType::serialized_len(&self.{field_name}, {field_no}, ::ntex_grpc::types::DefaultValue::{default_arg})",
+                ));
+            }
             default.push_str(&format!(
-                "{field_name}: ::core::default::Default::default(),\n",
+                "{field_name}: {},\n",
+                default_value
+                    .as_deref()
+                    .unwrap_or("::core::default::Default::default()")
             ));
 
             match field
@@ -266,13 +328,11 @@ impl CodeGenerator<'_> {
                 "::ntex_grpc::NativeType::serialize(&self.{}, 0, ::ntex_grpc::types::DefaultValue::Default, dst);",
                 to_snake(oneof.name()),
             ));
-            read.push_str(&format!(
-                "
-               {} => ::ntex_grpc::NativeType::deserialize(&mut msg.{}, tag, wire_type, src)
-                        .map_err(|err| err.push(STRUCT_NAME, \"{}\"))?,",
+            read.push((
                 fields.iter().map(|(field, _)| field.number()).join("| "),
                 to_snake(oneof.name()),
-                to_snake(oneof.name()),
+                false,
+                false,
             ));
             encoded_len.push_str(&format!(
                 " + ::ntex_grpc::NativeType::serialized_len(&self.{}, 0, ::ntex_grpc::types::DefaultValue::Default)",
@@ -289,6 +349,19 @@ impl CodeGenerator<'_> {
         }
         self.path.pop();
 
+        if preserve_unknown {
+            self.push_indent();
+            self.buf
+                .push_str("/// Encoded fields unknown to the message definition\n");
+            self.push_indent();
+            self.buf
+                .push_str("pub unknown_fields: ::ntex_grpc::Bytes,\n");
+
+            write.push_str("dst.extend_from_slice(&self.unknown_fields);");
+            encoded_len.push_str(" + self.unknown_fields.len()");
+            default.push_str("unknown_fields: ::core::default::Default::default(),\n");
+        }
+
         self.depth -= 1;
         self.push_indent();
         self.buf.push_str("}\n\n");
@@ -301,30 +374,103 @@ impl CodeGenerator<'_> {
              }}\n\n"
         ));
 
-        let read = if has_fields {
+        // presence of required fields is checked by `merge_checked` which is used
+        // by `read` and for embedded messages, `merge` could be called with partial message
+        let checked = self.syntax == Syntax::Proto2
+            && read
+                .iter()
+                .any(|(_, _, is_required, is_message)| *is_required || *is_message);
+        let parse = |msg: &str, track_required: bool| {
+            let skip = if preserve_unknown {
+                "::ntex_grpc::encoding::skip_unknown_field(wire_type, tag, src, &mut unknown_fields)?"
+            } else {
+                "::ntex_grpc::encoding::skip_field(wire_type, tag, src)?"
+            };
+            let body = if has_fields {
+                let arms: String = read
+                    .iter()
+                    .map(|(tags, field_name, is_required, is_message)| {
+                        let method = if track_required && *is_message {
+                            "deserialize_checked"
+                        } else {
+                            "deserialize"
+                        };
+                        let deserialize = format!(
+                            "::ntex_grpc::NativeType::{method}(&mut {msg}.{field_name}, tag, wire_type, src)
+                                .map_err(|err| err.push(STRUCT_NAME, \"{field_name}\"))"
+                        );
+                        if track_required && *is_required {
+                            format!("{tags} => {{ {deserialize}?; required_{tags} = true; }}")
+                        } else {
+                            format!("{tags} => {deserialize}?,")
+                        }
+                    })
+                    .collect();
+                format!(
+                    "match tag {{
+                     {arms}
+                     _ => {skip},
+                 }}"
+                )
+            } else {
+                format!("{skip};")
+            };
+            let (mut start, mut end) = if preserve_unknown {
+                (
+                    format!("let mut unknown_fields = ::ntex_grpc::BytesMut::from(&{msg}.unknown_fields[..]);"),
+                    format!("{msg}.unknown_fields = unknown_fields.freeze();"),
+                )
+            } else {
+                (String::new(), String::new())
+            };
+            if track_required {
+                for (field_no, field_name) in &required {
+                    start.push_str(&format!("let mut required_{field_no} = false;"));
+                    end.push_str(&format!(
+                        "if !required_{field_no} {{
+                            return Err(::ntex_grpc::DecodeError::new(\"missing required field\").push(STRUCT_NAME, \"{field_name}\"));
+                        }}"
+                    ));
+                }
+            }
             format!(
-                "match tag {{
-                 {read}
-                 _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
-             }}"
+                "const STRUCT_NAME: &str = \"{}\";
+                 {start}
+                 while !src.is_empty() {{
+                    let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                    {body}
+                 }}
+                 {end}",
+                to_upper_camel(&message_name)
             )
-        } else {
-            "::ntex_grpc::encoding::skip_field(wire_type, tag, src)?;".to_string()
         };
 
+        let merge = if checked { "merge_checked" } else { "merge" };
         self.priv_buf.push_str(&format!(
             "#[inline]
              fn read(src: &mut ::ntex_grpc::Bytes) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {{
-                 const STRUCT_NAME: &str = \"{}\";
                  let mut msg = Self::default();
-                 while !src.is_empty() {{
-                    let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
-                    {read}
-                 }}
+                 ::ntex_grpc::Message::{merge}(&mut msg, src)?;
                  Ok(msg)
+             }}
+
+             #[inline]
+             fn merge(&mut self, src: &mut ::ntex_grpc::Bytes) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {{
+                 {}
+                 Ok(())
              }}\n\n",
-            to_upper_camel(&message_name)
+            parse("self", false)
         ));
+        if checked {
+            self.priv_buf.push_str(&format!(
+                "#[inline]
+                 fn merge_checked(&mut self, src: &mut ::ntex_grpc::Bytes) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {{
+                     {}
+                     Ok(())
+                 }}\n\n",
+                parse("self", true)
+            ));
+        }
         self.priv_buf.push_str(&format!(
             "#[inline]
              fn encoded_len(&self) -> usize {{
@@ -342,11 +488,34 @@ impl CodeGenerator<'_> {
                  }}
              }}\n\n
         ",
-            to_upper_camel(&message_name)
+            type_path
         ));
+
+        if !message.extension_range.is_empty() {
+            self.priv_buf.push_str(&format!(
+                "impl ::ntex_grpc::types::Extendable for {type_path} {{
+                     const NAME: &'static str = \"{}\";
+
+                     #[inline]
+                     fn unknown_fields(&self) -> &::ntex_grpc::Bytes {{
+                         &self.unknown_fields
+                     }}
+
+                     #[inline]
+                     fn unknown_fields_mut(&mut self) -> &mut ::ntex_grpc::Bytes {{
+                         &mut self.unknown_fields
+                     }}
+                 }}\n\n",
+                &fq_message_name[1..]
+            ));
+        }
         // ==========================================
 
-        if !message.enum_type.is_empty() || !nested_types.is_empty() || !oneof_fields.is_empty() {
+        if !message.enum_type.is_empty()
+            || !nested_types.is_empty()
+            || !oneof_fields.is_empty()
+            || !message.extension.is_empty()
+        {
             self.push_mod(&message_name);
             self.path.push(3);
             for (nested_type, idx) in nested_types {
@@ -356,6 +525,14 @@ impl CodeGenerator<'_> {
             }
             self.path.pop();
 
+            self.path.push(6);
+            for (idx, extension) in message.extension.into_iter().enumerate() {
+                self.path.push(idx as i32);
+                self.append_extension(extension);
+                self.path.pop();
+            }
+            self.path.pop();
+
             self.path.push(4);
             for (idx, nested_enum) in message.enum_type.into_iter().enumerate() {
                 self.path.push(idx as i32);
@@ -406,12 +583,9 @@ impl CodeGenerator<'_> {
     }
 
     fn append_field(&mut self, fq_message_name: &str, field: FieldDescriptorProto) {
-        let type_ = field.r#type();
-        if type_ == Type::Group {
-            panic!("protobuf group is not supported: {}", field.name());
-        }
         let repeated = field.label == Some(Label::Repeated as i32);
-        let optional = self.optional(&field);
+        let boxed = !repeated && self.boxed(&field, fq_message_name);
+        let optional = boxed || self.optional(&field);
         let ty = self.resolve_type(&field, fq_message_name);
 
         debug!("    field: {:?}, type: {:?}", field.name(), ty);
@@ -429,7 +603,11 @@ impl CodeGenerator<'_> {
         } else if optional {
             self.buf.push_str("Option<");
         }
-        self.buf.push_str(&ty);
+        if boxed {
+            self.buf.push_str(&self.boxed_type(&field));
+        } else {
+            self.buf.push_str(&ty);
+        }
         if repeated || optional {
             self.buf.push('>');
         }
@@ -538,9 +716,23 @@ impl CodeGenerator<'_> {
             write.push_str(&format!(
                 "{name}::{field_name}(ref value) => ::ntex_grpc::NativeType::serialize(value, {field_no}, ::ntex_grpc::types::DefaultValue::Unknown, dst),",
             ));
-            read.push_str(&format!(
-                "{field_no} => {name}::{field_name}(::ntex_grpc::NativeType::deserialize_default({field_no}, wire_type, src)?),\n",
-            ));
+            // the same variant is merged, other variant replaces value
+            if fields.len() == 1 {
+                read.push_str(&format!(
+                    "{field_no} => {{
+                        let {name}::{field_name}(value) = self;
+                        ::ntex_grpc::NativeType::deserialize(value, {field_no}, wire_type, src)?;
+                    }}\n",
+                ));
+            } else {
+                read.push_str(&format!(
+                    "{field_no} => if let {name}::{field_name}(value) = self {{
+                        ::ntex_grpc::NativeType::deserialize(value, {field_no}, wire_type, src)?;
+                    }} else {{
+                        *self = {name}::{field_name}(::ntex_grpc::NativeType::deserialize_default({field_no}, wire_type, src)?);
+                    }},\n",
+                ));
+            }
             encoded_len.push_str(&format!(
                 "{name}::{field_name}(ref value) => ::ntex_grpc::NativeType::serialized_len(value, {field_no}, ::ntex_grpc::types::DefaultValue::Unknown),",
             ));
@@ -553,7 +745,11 @@ impl CodeGenerator<'_> {
             self.append_field_attributes(&oneof_name, field.name());
 
             self.push_indent();
-            let ty = self.resolve_type(field, fq_message_name);
+            let ty = if self.boxed(field, fq_message_name) {
+                self.boxed_type(field)
+            } else {
+                self.resolve_type(field, fq_message_name)
+            };
 
             debug!("    oneof: {:?}, type: {ty:?}", field.name());
 
@@ -591,10 +787,10 @@ impl CodeGenerator<'_> {
             #[inline]
             /// Decodes an instance of the message from a buffer, and merges it into self.
             fn deserialize(&mut self, tag: u32, wire_type: ::ntex_grpc::WireType, src: &mut ::ntex_grpc::Bytes) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {{
-                *self = match tag {{
+                match tag {{
                     {}
                     _ => unreachable!(\"invalid {}, tag: {{}}\", tag),
-                }};
+                }}
                 Ok(())
             }}\n", read.trim_end(), to_upper_camel(oneof.name())));
 
@@ -645,6 +841,38 @@ impl CodeGenerator<'_> {
         }
     }
 
+    fn append_extension(&mut self, field: FieldDescriptorProto) {
+        debug!("  extension: {:?}", field.name());
+
+        let fq_scope = if self.package.is_empty() {
+            String::new()
+        } else {
+            format!(".{}", self.package)
+        };
+        let ty = self.resolve_type(&field, &fq_scope);
+        let ty = if field.label() == Label::Repeated {
+            format!("Vec<{ty}>")
+        } else {
+            ty
+        };
+        let extendee = field.extendee();
+        let name = if self.package.is_empty() {
+            field.name().to_string()
+        } else {
+            format!("{}.{}", self.package, field.name())
+        };
+
+        self.append_doc(&fq_scope, Some(field.name()));
+        self.push_indent();
+        self.buf.push_str(&format!(
+            "pub const {}: ::ntex_grpc::types::Extension<{}, {ty}> = ::ntex_grpc::types::Extension::new(\"{name}\", \"{}\", {});\n",
+            to_snake(field.name()).to_uppercase(),
+            self.resolve_ident(extendee),
+            &extendee[1..],
+            field.number(),
+        ));
+    }
+
     fn append_enum(&mut self, full_name: String, desc: EnumDescriptorProto) {
         debug!("  enum: {:?}", desc.name());
 
@@ -846,13 +1074,27 @@ impl CodeGenerator<'_> {
                 fn default() -> Self {{
                     {}::{}
                 }}
+            }}
+
+            impl ::ntex_grpc::types::Enumeration for {} {{
+                #[inline]
+                fn from_i32(value: i32) -> ::std::option::Option<Self> {{
+                    {}::from_i32(value)
+                }}
+
+                #[inline]
+                fn to_i32(self) -> i32 {{
+                    self as i32
+                }}
             }}\n\n",
             full_name,
             full_name,
             &variant_mappings[0].generated_variant_name,
             full_name,
             full_name,
-            &variant_mappings[0].generated_variant_name
+            &variant_mappings[0].generated_variant_name,
+            full_name,
+            full_name,
         ));
     }
 
@@ -861,6 +1103,11 @@ impl CodeGenerator<'_> {
         debug!("  service: {name:?}");
 
         let comments = Comments::from_location(self.location());
+        let fq_service_name = if self.package.is_empty() {
+            format!(".{name}")
+        } else {
+            format!(".{}.{name}", self.package)
+        };
 
         self.path.push(2);
         let methods = service
@@ -881,11 +1128,16 @@ impl CodeGenerator<'_> {
                 let output_type = self.resolve_ident(&output_proto_type);
                 let client_streaming = method.client_streaming();
                 let server_streaming = method.server_streaming();
+                let encoded_options = self.encoded_options(
+                    &format!("{fq_service_name}.{name}"),
+                    method.options.as_ref(),
+                );
 
                 Method {
                     name: to_snake(&name),
                     proto_name: name,
                     options: method.options.unwrap_or_default(),
+                    encoded_options,
                     comments,
                     input_type,
                     output_type,
@@ -899,6 +1151,7 @@ impl CodeGenerator<'_> {
             .collect();
         self.path.pop();
 
+        let encoded_options = self.encoded_options(&fq_service_name, service.options.as_ref());
         let service = Service {
             name: to_upper_camel(&name),
             proto_name: name,
@@ -906,6 +1159,7 @@ impl CodeGenerator<'_> {
             comments,
             methods,
             options: service.options.unwrap_or_default(),
+            encoded_options,
         };
 
         if let Some(service_generator) = self.config.service_generator.as_mut() {
@@ -913,6 +1167,15 @@ impl CodeGenerator<'_> {
         }
     }
 
+    /// Encoded options as they appear in the descriptor set
+    fn encoded_options<M: prost::Message>(&self, fq_name: &str, options: Option<&M>) -> Vec<u8> {
+        self.config
+            .encoded_options
+            .get(fq_name)
+            .cloned()
+            .unwrap_or_else(|| options.map(M::encode_to_vec).unwrap_or_default())
+    }
+
     fn push_indent(&mut self) {
         push_indent(self.buf, self.depth);
     }
@@ -934,6 +1197,19 @@ impl CodeGenerator<'_> {
         self.depth += 1;
     }
 
+    /// Path of the type relative to the file module
+    fn nested_path(&self, name: &str) -> String {
+        let mut path: Vec<_> = self
+            .package
+            .rsplit('.')
+            .take(self.depth as usize)
+            .map(to_snake)
+            .collect();
+        path.reverse();
+        path.push(name.to_string());
+        path.join("::")
+    }
+
     fn pop_mod(&mut self) {
         self.depth -= 1;
 
@@ -953,12 +1229,85 @@ impl CodeGenerator<'_> {
             tp.clone()
         } else {
             match field.r#type() {
-                Type::Group | Type::Message | Type::Enum => self.resolve_ident(field.type_name()),
+                Type::Enum
+                    if self
+                        .config
+                        .open_enums
+                        .get_first_field(fq_message_name, field.name())
+                        .is_some() =>
+                {
+                    format!(
+                        "::ntex_grpc::types::EnumValue<{}>",
+                        self.resolve_ident(field.type_name())
+                    )
+                }
+                Type::Group => format!(
+                    "::ntex_grpc::types::Group<{}>",
+                    self.resolve_ident(field.type_name())
+                ),
+                Type::Message | Type::Enum => self.resolve_ident(field.type_name()),
                 _ => to_rust_type(field.r#type()),
             }
         }
     }
 
+    /// Rust expression for `[default = ...]` field option
+    fn default_value(
+        &self,
+        field: &FieldDescriptorProto,
+        fq_message_name: &str,
+    ) -> Option<String> {
+        let value = field.default_value.as_deref()?;
+        if self
+            .config
+            .types_map
+            .get_first_field(fq_message_name, field.name())
+            .is_some()
+        {
+            return None;
+        }
+
+        let ty = to_rust_type(match field.r#type() {
+            Type::Group | Type::Message => return None,
+            Type::Enum => {
+                let enum_name = to_upper_camel(field.type_name().rsplit('.').next().unwrap());
+                let mut variant = to_upper_camel(value);
+                if self.config.strip_enum_prefix {
+                    variant = strip_enum_prefix(&enum_name, &variant);
+                }
+                let value = format!("{}::{variant}", self.resolve_ident(field.type_name()));
+                return Some(
+                    if self
+                        .resolve_type(field, fq_message_name)
+                        .starts_with("::ntex_grpc::types::EnumValue<")
+                    {
+                        format!("::ntex_grpc::types::EnumValue::Known({value})")
+                    } else {
+                        value
+                    },
+                );
+            }
+            tp => tp,
+        });
+
+        Some(match field.r#type() {
+            Type::Float | Type::Double => match value {
+                "inf" => format!("{ty}::INFINITY"),
+                "-inf" => format!("{ty}::NEG_INFINITY"),
+                "nan" => format!("{ty}::NAN"),
+                _ => format!("{value}{ty}"),
+            },
+            Type::Bool => value.to_string(),
+            Type::Int32 | Type::Int64 | Type::Uint32 | Type::Uint64 => format!("{value}{ty}"),
+            Type::String => format!("::ntex_grpc::ByteString::from_static({value:?})"),
+            Type::Bytes => format!(
+                "::ntex_grpc::Bytes::from_static(b\"{}\")",
+                unescape_c_escape_string(value).escape_ascii()
+            ),
+            _ => format!("{ty}({value})"),
+        })
+    }
+
     fn is_extern_ident(&self, pb_ident: &str) -> bool {
         self.extern_paths.is_extern_ident(pb_ident)
     }
@@ -997,17 +1346,60 @@ impl CodeGenerator<'_> {
             .join("::")
     }
 
+    /// Recursive message fields are boxed
+    fn boxed(&self, field: &FieldDescriptorProto, fq_message_name: &str) -> bool {
+        matches!(field.r#type(), Type::Message | Type::Group)
+            && self
+                .config
+                .types_map
+                .get_first_field(fq_message_name, field.name())
+                .is_none()
+            && self
+                .message_graph
+                .is_nested(field.type_name(), fq_message_name)
+    }
+
+    fn boxed_type(&self, field: &FieldDescriptorProto) -> String {
+        let ty = self.resolve_ident(field.type_name());
+        if field.r#type() == Type::Group {
+            format!("::ntex_grpc::types::Group<Box<{ty}>>")
+        } else {
+            format!("Box<{ty}>")
+        }
+    }
+
+    /// Repeated scalar numeric fields are packed by default in proto3,
+    /// `packed` option overrides syntax default
+    fn unpacked(&self, field: &FieldDescriptorProto) -> bool {
+        if field.label() != Label::Repeated {
+            return false;
+        }
+        match field.r#type() {
+            Type::String | Type::Bytes | Type::Group | Type::Message => false,
+            _ => !field
+                .options
+                .as_ref()
+                .and_then(|options| options.packed)
+                .unwrap_or(self.syntax == Syntax::Proto3),
+        }
+    }
+
     fn optional(&self, field: &FieldDescriptorProto) -> bool {
         if field.proto3_optional.unwrap_or(false) {
             return true;
         }
 
+        // fields with explicit default value are not optional
+        if field.default_value.is_some() {
+            return false;
+        }
+
         if field.label() != Label::Optional {
             return false;
         }
 
         match field.r#type() {
-            Type::Message => false,
+            Type::Group | Type::Message => false,
             _ => self.syntax == Syntax::Proto2,
         }
     }
@@ -1119,14 +1511,70 @@ fn build_enum_value_mappings<'a>(
     mappings
 }
 
+/// Unescape C-escaped string, `default_value` of bytes fields
+fn unescape_c_escape_string(s: &str) -> Vec<u8> {
+    let src = s.as_bytes();
+    let mut dst = Vec::with_capacity(src.len());
+    let mut idx = 0;
+    while idx < src.len() {
+        if src[idx] != b'\\' || idx + 1 == src.len() {
+            dst.push(src[idx]);
+            idx += 1;
+            continue;
+        }
+
+        idx += 1;
+        match src[idx] {
+            b'a' => dst.push(0x07),
+            b'b' => dst.push(0x08),
+            b'f' => dst.push(0x0c),
+            b'n' => dst.push(b'\n'),
+            b'r' => dst.push(b'\r'),
+            b't' => dst.push(b'\t'),
+            b'v' => dst.push(0x0b),
+            b'0'..=b'7' => {
+                let len = src[idx..]
+                    .iter()
+                    .take(3)
+                    .take_while(|c| (b'0'..=b'7').contains(c))
+                    .count();
+                let octal = std::str::from_utf8(&src[idx..idx + len]).unwrap();
+                dst.push(u8::from_str_radix(octal, 8).expect("invalid octal escape"));
+                idx += len;
+                continue;
+            }
+            b'x' => {
+                let len = src[idx + 1..]
+                    .iter()
+                    .take(2)
+                    .take_while(|c| c.is_ascii_hexdigit())
+                    .count();
+                let hex = std::str::from_utf8(&src[idx + 1..idx + 1 + len]).unwrap();
+                dst.push(u8::from_str_radix(hex, 16).expect("invalid hex escape"));
+                idx += len + 1;
+                continue;
+            }
+            c => dst.push(c),
+        }
+        idx += 1;
+    }
+    dst
+}
+
 fn to_rust_type(tp: Type) -> String {
     match tp {
         Type::Double => String::from("f64"),
         Type::Float => String::from("f32"),
-        Type::Uint32 | Type::Fixed32 => String::from("u32"),
-        Type::Uint64 | Type::Fixed64 => String::from("u64"),
-        Type::Int32 | Type::Sfixed32 | Type::Sint32 => String::from("i32"),
-        Type::Int64 | Type::Sfixed64 | Type::Sint64 => String::from("i64"),
+        Type::Uint32 => String::from("u32"),
+        Type::Uint64 => String::from("u64"),
+        Type::Int32 => String::from("i32"),
+        Type::Int64 => String::from("i64"),
+        Type::Sint32 => String::from("::ntex_grpc::types::SInt32"),
+        Type::Sint64 => String::from("::ntex_grpc::types::SInt64"),
+        Type::Fixed32 => String::from("::ntex_grpc::types::Fixed32"),
+        Type::Fixed64 => String::from("::ntex_grpc::types::Fixed64"),
+        Type::Sfixed32 => String::from("::ntex_grpc::types::SFixed32"),
+        Type::Sfixed64 => String::from("::ntex_grpc::types::SFixed64"),
         Type::Bool => String::from("bool"),
         Type::String => String::from("::ntex_grpc::ByteString"),
         Type::Bytes => String::from("::ntex_grpc::Bytes"),
@@ -1137,7 +1585,770 @@ fn to_rust_type(tp: Type) -> String {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    use std::{env, fs, path::Path};
+
     use super::*;
+    use prost_types::{descriptor_proto::ExtensionRange, FieldOptions};
+
+    fn field(name: &str, number: i32, tp: Type) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(tp as i32),
+            ..Default::default()
+        }
+    }
+
+    fn generate(config: &mut Config, syntax: &str, messages: Vec<DescriptorProto>) -> String {
+        let file = FileDescriptorProto {
+            name: Some("test.proto".to_string()),
+            package: Some("test".to_string()),
+            syntax: Some(syntax.to_string()),
+            message_type: messages,
+            ..Default::default()
+        };
+        generate_file(config, file)
+    }
+
+    fn generate_file(config: &mut Config, mut file: FileDescriptorProto) -> String {
+        file.source_code_info = Some(SourceCodeInfo::default());
+        config.disable_comments(["."]);
+
+        let mut buf = String::new();
+        let extern_paths = ExternPaths::new(&[], false).unwrap();
+        let message_graph = MessageGraph::new(iter::once(&file));
+        CodeGenerator::generate(config, &extern_paths, &message_graph, file, &mut buf);
+        buf
+    }
+
+    /// Compare generated code with checked-in fixture
+    ///
+    /// Fixtures are compiled by `fixtures` module, set `UPDATE_FIXTURES`
+    /// env variable to regenerate them.
+    fn check_fixture(name: &str, buf: &str) {
+        let mut fmt = Command::new("rustfmt")
+            .args(["--edition", "2021"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        fmt.stdin.take().unwrap().write_all(buf.as_bytes()).unwrap();
+        let out = fmt.wait_with_output().unwrap();
+        assert!(out.status.success());
+        let code = String::from_utf8(out.stdout).unwrap();
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(format!("{name}.rs"));
+        if env::var_os("UPDATE_FIXTURES").is_some() {
+            fs::write(&path, code).unwrap();
+        } else {
+            assert!(
+                fs::read_to_string(&path).unwrap() == code,
+                "{} is outdated, regenerate with UPDATE_FIXTURES=1",
+                path.display()
+            );
+        }
+    }
+
+    #[allow(dead_code, unused_mut, unused_variables, clippy::all)]
+    mod fixtures {
+        pub mod unknown_fields {
+            include!("../tests/fixtures/unknown_fields.rs");
+        }
+        pub mod proto2 {
+            include!("../tests/fixtures/proto2.rs");
+        }
+        pub mod groups {
+            include!("../tests/fixtures/groups.rs");
+        }
+        pub mod extensions {
+            include!("../tests/fixtures/extensions.rs");
+        }
+        pub mod recursive {
+            include!("../tests/fixtures/recursive.rs");
+        }
+        pub mod fixed {
+            include!("../tests/fixtures/fixed.rs");
+        }
+        pub mod merge {
+            include!("../tests/fixtures/merge.rs");
+        }
+        pub mod packed {
+            include!("../tests/fixtures/packed.rs");
+        }
+        pub mod packed_proto2 {
+            include!("../tests/fixtures/packed_proto2.rs");
+        }
+        pub mod sint {
+            include!("../tests/fixtures/sint.rs");
+        }
+    }
+
+    #[test]
+    fn test_preserve_unknown_fields() {
+        let name = field("name", 1, Type::String);
+        let message = |msg: &str, fields: Vec<FieldDescriptorProto>| DescriptorProto {
+            name: Some(msg.to_string()),
+            field: fields,
+            ..Default::default()
+        };
+        let buf = generate(
+            Config::new().preserve_unknown_fields([".test.Msg"]),
+            "proto3",
+            vec![
+                message("Msg", vec![name.clone()]),
+                message("Plain", vec![name.clone()]),
+                message("MsgV2", vec![name, field("id", 2, Type::Int32)]),
+            ],
+        );
+        check_fixture("unknown_fields", &buf);
+
+        use fixtures::unknown_fields::{Msg, MsgV2, Plain};
+        use ntex_grpc::{BytesMut, Message};
+
+        let v2 = MsgV2 {
+            name: "name".into(),
+            id: 5,
+        };
+        let mut buf = BytesMut::new();
+        v2.write(&mut buf);
+        let data = buf.freeze();
+
+        let msg = Msg::read(&mut data.clone()).unwrap();
+        assert_eq!(msg.name, "name");
+        assert!(!msg.unknown_fields.is_empty());
+        assert_eq!(msg.encoded_len(), data.len());
+
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(MsgV2::read(&mut buf.freeze()).unwrap(), v2);
+
+        let plain = Plain::read(&mut data.clone()).unwrap();
+        let mut buf = BytesMut::new();
+        plain.write(&mut buf);
+        assert_eq!(MsgV2::read(&mut buf.freeze()).unwrap().id, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "field `unknown_fields` conflicts")]
+    fn test_unknown_fields_conflict() {
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![field("unknown_fields", 1, Type::Bytes)],
+            ..Default::default()
+        };
+        generate(
+            Config::new().preserve_unknown_fields([".test"]),
+            "proto3",
+            vec![message],
+        );
+    }
+
+    #[test]
+    fn test_proto2_defaults() {
+        let mut name = field("name", 1, Type::String);
+        name.label = Some(Label::Required as i32);
+        let mut count = field("count", 2, Type::Int32);
+        count.default_value = Some("-5".to_string());
+        let mut ratio = field("ratio", 3, Type::Float);
+        ratio.default_value = Some("inf".to_string());
+        let mut data = field("data", 4, Type::Bytes);
+        data.default_value = Some("a\\001\\\"".to_string());
+        let mut title = field("title", 5, Type::String);
+        title.default_value = Some("say \"hi\"".to_string());
+        let mut id = field("id", 6, Type::Fixed32);
+        id.default_value = Some("7".to_string());
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![
+                name,
+                count,
+                ratio,
+                data,
+                title,
+                id,
+                field("opt", 7, Type::Int32),
+            ],
+            ..Default::default()
+        };
+
+        let partial = DescriptorProto {
+            name: Some("Partial".to_string()),
+            field: vec![field("count", 2, Type::Int32)],
+            ..Default::default()
+        };
+
+        let mut inner = field("inner", 1, Type::Message);
+        inner.type_name = Some(".test.Msg".to_string());
+        let mut items = field("items", 2, Type::Message);
+        items.label = Some(Label::Repeated as i32);
+        items.type_name = Some(".test.Msg".to_string());
+        let outer = DescriptorProto {
+            name: Some("Outer".to_string()),
+            field: vec![inner, items],
+            ..Default::default()
+        };
+
+        let buf = generate(&mut Config::new(), "proto2", vec![message, partial, outer]);
+        check_fixture("proto2", &buf);
+
+        use fixtures::proto2::{Msg, Outer, Partial};
+        use ntex_grpc::{types::Fixed32, Bytes, BytesMut, Message};
+
+        let msg = Msg::default();
+        assert_eq!(msg.count, -5);
+        assert_eq!(msg.ratio, f32::INFINITY);
+        assert_eq!(msg.data, Bytes::from_static(b"a\x01\""));
+        assert_eq!(msg.title, "say \"hi\"");
+        assert_eq!(msg.id, Fixed32(7));
+        assert_eq!(msg.opt, None);
+
+        // fields with default values are skipped, required field is always written
+        let msg = Msg {
+            count: 10,
+            ..Msg::default()
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(&buf[..], b"\x0a\x00\x10\x0a");
+        assert_eq!(Msg::read(&mut buf.freeze()).unwrap(), msg);
+
+        let err = Msg::read(&mut Bytes::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to decode Protobuf message: Msg.name: missing required field"
+        );
+
+        // partial message could be merged into complete one
+        let mut buf = BytesMut::new();
+        Partial { count: Some(1) }.write(&mut buf);
+        let partial = buf.freeze();
+        assert!(Msg::read(&mut partial.clone()).is_err());
+
+        let mut msg = Msg {
+            name: "name".into(),
+            ..Msg::default()
+        };
+        msg.merge(&mut partial.clone()).unwrap();
+        assert_eq!(msg.name, "name");
+        assert_eq!(msg.count, 1);
+
+        // required fields of embedded messages are checked
+        let outer = Outer {
+            inner: msg.clone(),
+            items: vec![msg.clone(), msg],
+        };
+        let mut buf = BytesMut::new();
+        outer.write(&mut buf);
+        assert_eq!(Outer::read(&mut buf.freeze()).unwrap(), outer);
+
+        for (tag, field) in [(b'\x0a', "inner"), (b'\x12', "items")] {
+            let mut buf = BytesMut::new();
+            buf.extend_from_slice(&[tag, partial.len() as u8]);
+            buf.extend_from_slice(&partial);
+            let mut buf = buf.freeze();
+
+            let err = Outer::read(&mut buf.clone()).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("failed to decode Protobuf message: Msg.name: Outer.{field}: missing required field")
+            );
+            let mut outer = Outer::default();
+            outer.merge(&mut buf).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_recursive_messages() {
+        let message = |name: &str, type_name: &str| {
+            let mut fld = field("value", 1, Type::Message);
+            fld.type_name = Some(type_name.to_string());
+            DescriptorProto {
+                name: Some(name.to_string()),
+                field: vec![fld],
+                ..Default::default()
+            }
+        };
+        let node_field = |name: &str, number: i32| {
+            let mut fld = field(name, number, Type::Message);
+            fld.type_name = Some(".test.Node".to_string());
+            fld
+        };
+        let mut children = node_field("children", 3);
+        children.label = Some(Label::Repeated as i32);
+        let mut next = node_field("next", 4);
+        next.oneof_index = Some(0);
+        let node = DescriptorProto {
+            name: Some("Node".to_string()),
+            field: vec![
+                field("id", 1, Type::Int32),
+                node_field("left", 2),
+                children,
+                next,
+            ],
+            oneof_decl: vec![OneofDescriptorProto {
+                name: Some("kind".to_string()),
+                options: None,
+            }],
+            ..Default::default()
+        };
+
+        let buf = generate(
+            &mut Config::new(),
+            "proto3",
+            vec![
+                node,
+                message("A", ".test.B"),
+                message("B", ".test.A"),
+                message("C", ".test.Node"),
+            ],
+        );
+        check_fixture("recursive", &buf);
+
+        use fixtures::recursive::{node, Node, A, B, C};
+        use ntex_grpc::{BytesMut, Message};
+
+        let leaf = |id| Node {
+            id,
+            ..Node::default()
+        };
+        let tree = C {
+            value: Node {
+                id: 1,
+                left: Some(Box::new(Node {
+                    id: 2,
+                    left: Some(Box::new(leaf(3))),
+                    ..Node::default()
+                })),
+                children: vec![leaf(4), leaf(5)],
+                kind: Some(node::Kind::Next(Box::new(leaf(6)))),
+            },
+        };
+        let mut buf = BytesMut::new();
+        tree.write(&mut buf);
+        assert_eq!(tree.encoded_len(), buf.len());
+        assert_eq!(C::read(&mut buf.freeze()).unwrap(), tree);
+
+        let cycle = A {
+            value: Some(Box::new(B {
+                value: Some(Box::new(A::default())),
+            })),
+        };
+        let mut buf = BytesMut::new();
+        cycle.write(&mut buf);
+        assert_eq!(A::read(&mut buf.freeze()).unwrap(), cycle);
+    }
+
+    #[test]
+    fn test_groups() {
+        let mut result = field("Result", 1, Type::Group);
+        result.type_name = Some(".test.Msg.Result".to_string());
+        result.label = Some(Label::Repeated as i32);
+        let mut header = field("Header", 2, Type::Group);
+        header.type_name = Some(".test.Msg.Header".to_string());
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![result, header],
+            nested_type: vec![
+                DescriptorProto {
+                    name: Some("Result".to_string()),
+                    field: vec![field("url", 1, Type::String)],
+                    ..Default::default()
+                },
+                DescriptorProto {
+                    name: Some("Header".to_string()),
+                    field: vec![field("id", 1, Type::Int32)],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let buf = generate(&mut Config::new(), "proto2", vec![message]);
+        check_fixture("groups", &buf);
+
+        use fixtures::groups::{msg, Msg};
+        use ntex_grpc::{types::Group, BytesMut, Message};
+
+        let msg = Msg {
+            result: vec![
+                Group(msg::Result {
+                    url: Some("a".into()),
+                }),
+                Group(msg::Result { url: None }),
+            ],
+            header: Group(msg::Header { id: Some(1) }),
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(&buf[..], b"\x0b\x0a\x01a\x0c\x0b\x0c\x13\x08\x01\x14");
+        assert_eq!(msg.encoded_len(), buf.len());
+        assert_eq!(Msg::read(&mut buf.freeze()).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut tag = field("tag", 100, Type::Int32);
+        tag.extendee = Some(".test.Msg".to_string());
+        let mut scopes = field("scopes", 101, Type::String);
+        scopes.extendee = Some(".test.Msg".to_string());
+        scopes.label = Some(Label::Repeated as i32);
+        let file = FileDescriptorProto {
+            name: Some("test.proto".to_string()),
+            package: Some("test".to_string()),
+            syntax: Some("proto2".to_string()),
+            message_type: vec![
+                DescriptorProto {
+                    name: Some("Msg".to_string()),
+                    field: vec![field("name", 1, Type::String)],
+                    extension_range: vec![ExtensionRange {
+                        start: Some(100),
+                        end: Some(200),
+                        options: None,
+                    }],
+                    ..Default::default()
+                },
+                DescriptorProto {
+                    name: Some("Other".to_string()),
+                    extension: vec![tag],
+                    ..Default::default()
+                },
+            ],
+            extension: vec![scopes],
+            ..Default::default()
+        };
+
+        let buf = generate_file(&mut Config::new(), file);
+        check_fixture("extensions", &buf);
+
+        use fixtures::extensions::{other, Msg, SCOPES};
+        use ntex_grpc::types::{Extendable, ExtensionRegistry};
+        use ntex_grpc::{ByteString, BytesMut, Message};
+
+        let scopes = vec![ByteString::from("read"), ByteString::from("write")];
+        let mut msg = Msg {
+            name: Some("name".into()),
+            ..Msg::default()
+        };
+        msg.set_extension(&SCOPES, &scopes);
+        msg.set_extension(&other::TAG, &7);
+
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        let msg = Msg::read(&mut buf.freeze()).unwrap();
+        assert_eq!(msg.name.as_deref(), Some("name"));
+        assert_eq!(msg.get_extension(&SCOPES).unwrap(), Some(scopes));
+        assert_eq!(msg.get_extension(&other::TAG).unwrap(), Some(7));
+
+        let mut registry = ExtensionRegistry::new();
+        registry.register(&SCOPES).register(&other::TAG);
+        let names: Vec<_> = registry
+            .extensions_of(&msg)
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        assert_eq!(names, ["test.scopes", "test.Other.tag"]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let message_field = |name: &str, number: i32| {
+            let mut fld = field(name, number, Type::Message);
+            fld.type_name = Some(".test.Inner".to_string());
+            fld
+        };
+        let mut tags = field("tags", 2, Type::String);
+        tags.label = Some(Label::Repeated as i32);
+        let mut first = message_field("first", 4);
+        first.oneof_index = Some(0);
+        let mut name = field("name", 5, Type::String);
+        name.oneof_index = Some(0);
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![
+                field("id", 1, Type::Int32),
+                tags,
+                message_field("inner", 3),
+                first,
+                name,
+            ],
+            oneof_decl: vec![OneofDescriptorProto {
+                name: Some("kind".to_string()),
+                options: None,
+            }],
+            ..Default::default()
+        };
+        let inner = DescriptorProto {
+            name: Some("Inner".to_string()),
+            field: vec![field("a", 1, Type::Int32), field("b", 2, Type::Int32)],
+            ..Default::default()
+        };
+
+        let buf = generate(&mut Config::new(), "proto3", vec![message, inner]);
+        check_fixture("merge", &buf);
+
+        use fixtures::merge::{msg::Kind, Inner, Msg};
+        use ntex_grpc::{BytesMut, Message};
+
+        let part = |id, tag: &str, inner: Inner, kind| Msg {
+            id,
+            tags: vec![tag.into()],
+            inner,
+            kind: Some(kind),
+        };
+        let mut buf = BytesMut::new();
+        part(
+            1,
+            "a",
+            Inner { a: 1, b: 0 },
+            Kind::First(Inner { a: 1, b: 0 }),
+        )
+        .write(&mut buf);
+        part(
+            2,
+            "b",
+            Inner { a: 0, b: 2 },
+            Kind::First(Inner { a: 0, b: 2 }),
+        )
+        .write(&mut buf);
+
+        // singular fields are replaced, repeated fields are extended,
+        // embedded messages and same oneof variant are merged
+        let mut msg = Msg::read(&mut buf.freeze()).unwrap();
+        assert_eq!(
+            msg,
+            Msg {
+                id: 2,
+                tags: vec!["a".into(), "b".into()],
+                inner: Inner { a: 1, b: 2 },
+                kind: Some(Kind::First(Inner { a: 1, b: 2 })),
+            }
+        );
+
+        // other oneof variant replaces current one
+        let mut buf = BytesMut::new();
+        part(3, "c", Inner { a: 3, b: 0 }, Kind::Name("name".into())).write(&mut buf);
+        Message::merge(&mut msg, &mut buf.freeze()).unwrap();
+        assert_eq!(
+            msg,
+            Msg {
+                id: 3,
+                tags: vec!["a".into(), "b".into(), "c".into()],
+                inner: Inner { a: 3, b: 2 },
+                kind: Some(Kind::Name("name".into())),
+            }
+        );
+    }
+
+    #[test]
+    fn test_sint_fields() {
+        let mut items = field("items", 3, Type::Sint32);
+        items.label = Some(Label::Repeated as i32);
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![
+                field("small", 1, Type::Sint32),
+                field("large", 2, Type::Sint64),
+                items,
+            ],
+            ..Default::default()
+        };
+
+        let buf = generate(&mut Config::new(), "proto3", vec![message]);
+        check_fixture("sint", &buf);
+
+        use fixtures::sint::Msg;
+        use ntex_grpc::types::{SInt32, SInt64};
+        use ntex_grpc::{BytesMut, Message};
+
+        // values are zigzag encoded
+        let msg = Msg {
+            small: SInt32(-1),
+            large: SInt64(-2),
+            items: vec![SInt32(1), SInt32(-64)],
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(&buf[..], b"\x08\x01\x10\x03\x1a\x02\x02\x7f");
+        assert_eq!(msg.encoded_len(), buf.len());
+        assert_eq!(Msg::read(&mut buf.freeze()).unwrap(), msg);
+
+        let msg = Msg {
+            small: SInt32(i32::MIN),
+            large: SInt64(i64::MAX),
+            items: Vec::new(),
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(Msg::read(&mut buf.freeze()).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_fixed_fields() {
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![
+                field("a", 1, Type::Fixed32),
+                field("b", 2, Type::Fixed64),
+                field("c", 3, Type::Sfixed32),
+                field("d", 4, Type::Sfixed64),
+            ],
+            ..Default::default()
+        };
+
+        let buf = generate(&mut Config::new(), "proto3", vec![message]);
+        check_fixture("fixed", &buf);
+
+        use fixtures::fixed::Msg;
+        use ntex_grpc::types::{Fixed32, Fixed64, SFixed32, SFixed64};
+        use ntex_grpc::{BytesMut, Message};
+
+        // values are encoded as little-endian fixed width values
+        let msg = Msg {
+            a: Fixed32(1),
+            b: Fixed64(2),
+            c: SFixed32(-1),
+            d: SFixed64(-2),
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(
+            &buf[..],
+            b"\x0d\x01\x00\x00\x00\x11\x02\x00\x00\x00\x00\x00\x00\x00\
+              \x1d\xff\xff\xff\xff\x21\xfe\xff\xff\xff\xff\xff\xff\xff"
+        );
+        assert_eq!(msg.encoded_len(), buf.len());
+        assert_eq!(Msg::read(&mut buf.freeze()).unwrap(), msg);
+
+        // default values are skipped
+        let mut buf = BytesMut::new();
+        Msg::default().write(&mut buf);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_packed_fields() {
+        let repeated = |name: &str, number: i32, tp: Type, packed: Option<bool>| {
+            let mut fld = field(name, number, tp);
+            fld.label = Some(Label::Repeated as i32);
+            fld.options = packed.map(|packed| FieldOptions {
+                packed: Some(packed),
+                ..Default::default()
+            });
+            fld
+        };
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![
+                repeated("values", 1, Type::Double, None),
+                repeated("ids", 2, Type::Int32, Some(false)),
+                repeated("kinds", 3, Type::Fixed32, None),
+            ],
+            ..Default::default()
+        };
+        let buf = generate(&mut Config::new(), "proto3", vec![message]);
+        check_fixture("packed", &buf);
+
+        // proto2 fields are unpacked by default
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![
+                repeated("ids", 1, Type::Int32, None),
+                repeated("packed_ids", 2, Type::Int32, Some(true)),
+            ],
+            ..Default::default()
+        };
+        let buf = generate(&mut Config::new(), "proto2", vec![message]);
+        check_fixture("packed_proto2", &buf);
+
+        use ntex_grpc::{types::Fixed32, Bytes, BytesMut, Message};
+
+        let msg = fixtures::packed::Msg {
+            values: vec![1.0],
+            ids: vec![1, 2],
+            kinds: vec![Fixed32(3)],
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(
+            &buf[..],
+            b"\x0a\x08\x00\x00\x00\x00\x00\x00\xf0\x3f\x10\x01\x10\x02\x1a\x04\x03\x00\x00\x00"
+        );
+        assert_eq!(msg.encoded_len(), buf.len());
+        assert_eq!(fixtures::packed::Msg::read(&mut buf.freeze()).unwrap(), msg);
+
+        // packed and unpacked forms are accepted
+        let mut data = Bytes::from_static(
+            b"\x09\x00\x00\x00\x00\x00\x00\xf0\x3f\x12\x02\x01\x02\x1d\x03\x00\x00\x00",
+        );
+        assert_eq!(fixtures::packed::Msg::read(&mut data).unwrap(), msg);
+
+        let msg = fixtures::packed_proto2::Msg {
+            ids: vec![1, 2],
+            packed_ids: vec![3, 4],
+        };
+        let mut buf = BytesMut::new();
+        msg.write(&mut buf);
+        assert_eq!(&buf[..], b"\x08\x01\x08\x02\x12\x02\x03\x04");
+        assert_eq!(msg.encoded_len(), buf.len());
+        assert_eq!(
+            fixtures::packed_proto2::Msg::read(&mut buf.freeze()).unwrap(),
+            msg
+        );
+    }
+
+    #[test]
+    fn test_unescape_c_escape_string() {
+        assert_eq!(unescape_c_escape_string("abc"), b"abc");
+        assert_eq!(unescape_c_escape_string(r"\n\t\\\'"), b"\n\t\\'");
+        assert_eq!(
+            unescape_c_escape_string(r"\0\001\177\x7f\xA"),
+            b"\0\x01\x7f\x7f\x0a"
+        );
+        assert_eq!(unescape_c_escape_string(r"\3779"), b"\xff9");
+    }
+
+    #[test]
+    fn test_open_enums() {
+        let mut kind = field("kind", 1, Type::Enum);
+        kind.type_name = Some(".test.Msg.Kind".to_string());
+        let message = DescriptorProto {
+            name: Some("Msg".to_string()),
+            field: vec![kind],
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Kind".to_string()),
+                value: vec![
+                    EnumValueDescriptorProto {
+                        name: Some("FIRST".to_string()),
+                        number: Some(0),
+                        ..Default::default()
+                    },
+                    EnumValueDescriptorProto {
+                        name: Some("SECOND".to_string()),
+                        number: Some(1),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let buf = generate(&mut Config::new(), "proto3", vec![message.clone()]);
+        assert!(buf.contains("pub kind: msg::Kind,"));
+        assert!(buf.contains("impl ::ntex_grpc::types::Enumeration for msg::Kind"));
+
+        let buf = generate(
+            Config::new().open_enums(["Msg.kind"]),
+            "proto3",
+            vec![message],
+        );
+        assert!(buf.contains("pub kind: ::ntex_grpc::types::EnumValue<msg::Kind>,"));
+    }
 
     #[test]
     fn test_strip_enum_prefix() {
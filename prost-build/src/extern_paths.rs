@@ -135,7 +135,7 @@ impl ExternPaths {
                                 to_snake(segment)
                             }
                         })
-                        .chain(ident_type.into_iter())
+                        .chain(ident_type)
                         .join("::"),
                 );
             }
@@ -192,8 +192,11 @@ mod tests {
             assert_eq!(paths.resolve_ident(proto_ident).unwrap(), resolved_ident);
         };
 
-        case(".google.protobuf.Value", "::prost_types::Value");
-        case(".google.protobuf.Duration", "::prost_types::Duration");
+        case(".google.protobuf.Value", "::ntex_grpc::google_types::Value");
+        case(
+            ".google.protobuf.Duration",
+            "::ntex_grpc::google_types::Duration",
+        );
         case(".google.protobuf.Empty", "()");
     }
 }
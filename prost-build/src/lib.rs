@@ -53,7 +53,7 @@
 //! ```rust,no_run
 //! use std::io::Result;
 //! fn main() -> Result<()> {
-//!     prost_build::compile_protos(&["src/items.proto"], &["src/"])?;
+//!     ntex_prost_build::compile_protos(&["src/items.proto"], &["src/"])?;
 //!     Ok(())
 //! }
 //! ```
@@ -136,6 +136,7 @@ mod ast;
 mod code_generator;
 mod extern_paths;
 mod ident;
+mod message_graph;
 mod path;
 
 use std::ffi::{OsStr, OsString};
@@ -152,6 +153,7 @@ pub use crate::ast::{Comments, Method, Service};
 use crate::code_generator::CodeGenerator;
 use crate::extern_paths::ExternPaths;
 use crate::ident::to_snake;
+use crate::message_graph::MessageGraph;
 use crate::path::PathMap;
 
 /// A service generator takes a service descriptor and generates Rust code.
@@ -204,6 +206,7 @@ pub trait ServiceGenerator {
 /// This configuration builder can be used to set non-default code generation options.
 pub struct Config {
     file_descriptor_set_path: Option<PathBuf>,
+    embed_file_descriptor_set: bool,
     service_generator: Option<Box<dyn ServiceGenerator>>,
     types_map: PathMap<String>,
     type_attributes: PathMap<String>,
@@ -215,6 +218,9 @@ pub struct Config {
     default_package_filename: String,
     protoc_args: Vec<OsString>,
     disable_comments: PathMap<()>,
+    preserve_unknown_fields: PathMap<()>,
+    open_enums: PathMap<()>,
+    encoded_options: HashMap<String, Vec<u8>>,
     skip_protoc_run: bool,
     include_file: Option<PathBuf>,
 }
@@ -242,7 +248,7 @@ impl Config {
     /// # Examples
     ///
     /// ```rust
-    /// # let mut config = prost_build::Config::new();
+    /// # let mut config = ntex_prost_build::Config::new();
     /// // Match a specific field in a message type.
     /// config.map_field_type(&[".my_messages.MyMessageType.my_bytes_field"], "MyCustomType");
     ///
@@ -256,8 +262,8 @@ impl Config {
     /// // package or nesting.
     /// config.map_field_type(&["MyMessageType.my_bytes_field"], "Bytes");
     ///
-    /// // Match all fields named 'my_bytes_field', and all fields in the 'foo.bar' package.
-    /// config.map_field_type(&["my_field", "::foo::bar::CustomType"]);
+    /// // Match all fields named 'my_field', and all fields in the 'foo.bar' package.
+    /// config.map_field_type(&["my_field", ".foo.bar"], "::foo::bar::CustomType");
     /// ```
     pub fn map_field_type<I, S>(&mut self, paths: I, tp: &str) -> &mut Self
     where
@@ -289,7 +295,7 @@ impl Config {
     /// # Examples
     ///
     /// ```rust
-    /// # let mut config = prost_build::Config::new();
+    /// # let mut config = ntex_prost_build::Config::new();
     /// // Prost renames fields named `in` to `in_`. But if serialized through serde,
     /// // they should as `in`.
     /// config.field_attribute("in", "#[serde(rename = \"in\")]");
@@ -324,7 +330,7 @@ impl Config {
     /// # Examples
     ///
     /// ```rust
-    /// # let mut config = prost_build::Config::new();
+    /// # let mut config = ntex_prost_build::Config::new();
     /// // Nothing around uses floats, so we can derive real `Eq` in addition to `PartialEq`.
     /// config.type_attribute(".", "#[derive(Eq)]");
     /// // Some messages want to be serializable with serde as well.
@@ -379,7 +385,7 @@ impl Config {
     /// are possible, then omit comments on generated code during doctest builds:
     ///
     /// ```rust,ignore
-    /// let mut config = prost_build::Config::new();
+    /// let mut config = ntex_prost_build::Config::new();
     /// config.disable_comments(".");
     /// config.compile_protos(&["src/frontend.proto", "src/backend.proto"], &["src"])?;
     /// ```
@@ -402,6 +408,73 @@ impl Config {
         self
     }
 
+    /// Preserve unknown fields of messages.
+    ///
+    /// Generated messages get `unknown_fields` field which contains encoded fields that are
+    /// not known to the message definition. Unknown fields are written back on encoding, so
+    /// newer fields survive decode/encode round trip, for example in a proxy.
+    ///
+    /// # Arguments
+    ///
+    /// **`paths`** - paths to specific messages or packages. Paths are matched the same way as
+    /// in `map_field_type`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # let mut config = ntex_prost_build::Config::new();
+    /// // Preserve unknown fields of all messages.
+    /// config.preserve_unknown_fields(&["."]);
+    ///
+    /// // Preserve unknown fields of messages in the 'my_messages' package.
+    /// config.preserve_unknown_fields(&[".my_messages"]);
+    /// ```
+    pub fn preserve_unknown_fields<I, S>(&mut self, paths: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.preserve_unknown_fields.clear();
+        for matcher in paths {
+            self.preserve_unknown_fields
+                .insert(matcher.as_ref().to_string(), ());
+        }
+        self
+    }
+
+    /// Retain unknown values of enum fields.
+    ///
+    /// Matching enum fields are generated as `EnumValue<T>`, numeric values which are unknown
+    /// to the enum definition are kept as `EnumValue::Unknown` and encoded back as is. By
+    /// default unknown values are decoded as the first enum variant.
+    ///
+    /// # Arguments
+    ///
+    /// **`paths`** - paths to specific fields, messages, or packages. Paths are matched the same
+    /// way as in `map_field_type`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # let mut config = ntex_prost_build::Config::new();
+    /// // Retain unknown values of all enum fields.
+    /// config.open_enums(&["."]);
+    ///
+    /// // Retain unknown values of a specific field.
+    /// config.open_enums(&[".my_messages.MyMessageType.my_enum_field"]);
+    /// ```
+    pub fn open_enums<I, S>(&mut self, paths: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.open_enums.clear();
+        for matcher in paths {
+            self.open_enums.insert(matcher.as_ref().to_string(), ());
+        }
+        self
+    }
+
     /// Declare an externally provided Protobuf package or type.
     ///
     /// `extern_path` allows `prost` types in external crates to be referenced in generated code.
@@ -491,7 +564,7 @@ impl Config {
     /// a type, and the Rust path should correspondingly refer to a Rust module or type.
     ///
     /// ```rust
-    /// # let mut config = prost_build::Config::new();
+    /// # let mut config = ntex_prost_build::Config::new();
     /// // Declare the `uuid` Protobuf package and all nested packages and types as externally
     /// // provided by the `uuid` crate.
     /// config.extern_path(".uuid", "::uuid");
@@ -527,7 +600,7 @@ impl Config {
     /// ```rust
     /// # use std::env;
     /// # use std::path::PathBuf;
-    /// # let mut config = prost_build::Config::new();
+    /// # let mut config = ntex_prost_build::Config::new();
     /// config.file_descriptor_set_path(
     ///     PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR environment variable not set"))
     ///         .join("file_descriptor_set.bin"));
@@ -547,6 +620,16 @@ impl Config {
         self
     }
 
+    /// Embed encoded `FileDescriptorSet` into each generated file.
+    ///
+    /// Generated files contain `FILE_DESCRIPTOR_SET` constant with the encoded descriptor set
+    /// of all compiled `.proto` files and their imports. The constant could be registered in
+    /// server reflection service.
+    pub fn embed_file_descriptor_set(&mut self) -> &mut Self {
+        self.embed_file_descriptor_set = true;
+        self
+    }
+
     /// In combination with with `file_descriptor_set_path`, this can be used to provide a file
     /// descriptor set as an input file, rather than having prost-build generate the file by calling
     /// protoc.  Prost-build does require that the descriptor set was generated with
@@ -555,7 +638,7 @@ impl Config {
     /// In `build.rs`:
     ///
     /// ```rust
-    /// # let mut config = prost_build::Config::new();
+    /// # let mut config = ntex_prost_build::Config::new();
     /// config.file_descriptor_set_path("path/from/build/system")
     ///     .skip_protoc_run()
     ///     .compile_protos(&["src/items.proto"], &["src/"]);
@@ -605,7 +688,7 @@ impl Config {
     /// ```rust,no_run
     /// # use std::io::Result;
     /// fn main() -> Result<()> {
-    ///   let mut prost_build = prost_build::Config::new();
+    ///   let mut prost_build = ntex_prost_build::Config::new();
     ///   // Enable a protoc experimental feature.
     ///   prost_build.protoc_arg("--experimental_allow_proto3_optional");
     ///   prost_build.compile_protos(&["src/frontend.proto", "src/backend.proto"], &["src"])?;
@@ -656,7 +739,7 @@ impl Config {
     /// Compile `.proto` files into Rust files during a Cargo build with additional code generator
     /// configuration options.
     ///
-    /// This method is like the `prost_build::compile_protos` function, with the added ability to
+    /// This method is like the `ntex_prost_build::compile_protos` function, with the added ability to
     /// specify non-default code generation options. See that function for more information about
     /// the arguments and generated outputs.
     ///
@@ -667,8 +750,8 @@ impl Config {
     /// ```rust,no_run
     /// # use std::io::Result;
     /// fn main() -> Result<()> {
-    ///   let mut prost_build = prost_build::Config::new();
-    ///   prost_build.btree_map(&["."]);
+    ///   let mut prost_build = ntex_prost_build::Config::new();
+    ///   prost_build.retain_enum_prefix();
     ///   prost_build.compile_protos(&["src/frontend.proto", "src/backend.proto"], &["src"])?;
     ///   Ok(())
     /// }
@@ -753,6 +836,8 @@ impl Config {
             )
         })?;
 
+        self.encoded_options = encoded_options(&buf);
+
         let requests = file_descriptor_set
             .file
             .into_iter()
@@ -774,7 +859,13 @@ impl Config {
             })
             .collect::<HashMap<Module, String>>();
 
-        let modules = self.generate(requests)?;
+        let mut modules = self.generate(requests)?;
+        if self.embed_file_descriptor_set {
+            let descriptor_set = file_descriptor_set_const(&buf);
+            for content in modules.values_mut() {
+                content.push_str(&descriptor_set);
+            }
+        }
         for (module, content) in &modules {
             let file_name = file_names
                 .get(module)
@@ -884,6 +975,7 @@ impl Config {
 
         let extern_paths = ExternPaths::new(&self.extern_paths, self.prost_types)
             .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+        let message_graph = MessageGraph::new(requests.iter().map(|(_, file)| file));
 
         for request in requests {
             // Only record packages that have services
@@ -895,13 +987,13 @@ impl Config {
                 let mut buf = String::new();
                 buf.insert_str(
                     0,
-                    "#![allow(dead_code, unused_mut, unused_variables, clippy::identity_op, clippy::too_many_lines, clippy::derivable_impls, clippy::unit_arg, clippy::derive_partial_eq_without_eq, clippy::manual_range_patterns, clippy::default_trait_access, clippy::semicolon_if_nothing_returned, clippy::doc_markdown, clippy::wildcard_imports)]\n//! DO NOT MODIFY. Auto-generated file\n\n",
+                    "#![allow(async_fn_in_trait, dead_code, unused_mut, unused_variables, clippy::identity_op, clippy::too_many_lines, clippy::derivable_impls, clippy::unit_arg, clippy::derive_partial_eq_without_eq, clippy::manual_range_patterns, clippy::default_trait_access, clippy::semicolon_if_nothing_returned, clippy::doc_markdown, clippy::wildcard_imports)]\n//! DO NOT MODIFY. Auto-generated file\n\n",
                 );
                 modules.insert(request.0.clone(), buf);
             }
             let buf = modules.get_mut(&request.0).unwrap();
 
-            CodeGenerator::generate(self, &extern_paths, request.1, buf);
+            CodeGenerator::generate(self, &extern_paths, &message_graph, request.1, buf);
         }
 
         if let Some(ref mut service_generator) = self.service_generator {
@@ -919,6 +1011,7 @@ impl default::Default for Config {
     fn default() -> Config {
         Config {
             file_descriptor_set_path: None,
+            embed_file_descriptor_set: false,
             service_generator: None,
             types_map: PathMap::default(),
             type_attributes: PathMap::default(),
@@ -930,6 +1023,9 @@ impl default::Default for Config {
             default_package_filename: "_".to_string(),
             protoc_args: Vec::new(),
             disable_comments: PathMap::default(),
+            preserve_unknown_fields: PathMap::default(),
+            open_enums: PathMap::default(),
+            encoded_options: HashMap::new(),
             skip_protoc_run: false,
             include_file: None,
         }
@@ -940,6 +1036,7 @@ impl fmt::Debug for Config {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct("Config")
             .field("file_descriptor_set_path", &self.file_descriptor_set_path)
+            .field("embed_file_descriptor_set", &self.embed_file_descriptor_set)
             .field("service_generator", &self.service_generator.is_some())
             .field("types_map", &self.types_map)
             .field("type_attributes", &self.type_attributes)
@@ -951,10 +1048,106 @@ impl fmt::Debug for Config {
             .field("default_package_filename", &self.default_package_filename)
             .field("protoc_args", &self.protoc_args)
             .field("disable_comments", &self.disable_comments)
+            .field("preserve_unknown_fields", &self.preserve_unknown_fields)
+            .field("open_enums", &self.open_enums)
             .finish()
     }
 }
 
+/// Generates constant with encoded `FileDescriptorSet` as a byte string literal.
+fn file_descriptor_set_const(buf: &[u8]) -> String {
+    const LINE_WIDTH: usize = 92;
+
+    let mut out = String::from(
+        "\n/// Encoded `FileDescriptorSet` of the compiled protos\npub const FILE_DESCRIPTOR_SET: &[u8] = b\"\\\n",
+    );
+    let mut width = 0;
+    for byte in buf {
+        if width >= LINE_WIDTH {
+            out.push_str("\\\n");
+            width = 0;
+        }
+        if byte.is_ascii_alphanumeric() || b"._/-".contains(byte) {
+            out.push(*byte as char);
+            width += 1;
+        } else {
+            out.push_str(&format!("\\x{byte:02x}"));
+            width += 4;
+        }
+    }
+    out.push_str("\";\n");
+    out
+}
+
+/// Collects encoded service and method options by fully qualified name.
+///
+/// Custom options are extension fields, `prost_types` drops them on decoding.
+fn encoded_options(buf: &[u8]) -> HashMap<String, Vec<u8>> {
+    let mut options = HashMap::new();
+    for file in length_delimited_fields(buf, 1) {
+        let package = length_delimited_fields(file, 2)
+            .first()
+            .map(|pkg| String::from_utf8_lossy(pkg).to_string())
+            .unwrap_or_default();
+        for service in length_delimited_fields(file, 6) {
+            let mut service_name = if package.is_empty() {
+                String::new()
+            } else {
+                format!(".{package}")
+            };
+            for name in length_delimited_fields(service, 1) {
+                service_name.push('.');
+                service_name.push_str(&String::from_utf8_lossy(name));
+            }
+            options.insert(
+                service_name.clone(),
+                length_delimited_fields(service, 3).concat(),
+            );
+
+            for method in length_delimited_fields(service, 2) {
+                for name in length_delimited_fields(method, 1) {
+                    options.insert(
+                        format!("{service_name}.{}", String::from_utf8_lossy(name)),
+                        length_delimited_fields(method, 4).concat(),
+                    );
+                }
+            }
+        }
+    }
+    options
+}
+
+/// Values of length delimited fields with the tag, decoding stops on malformed input.
+fn length_delimited_fields(mut buf: &[u8], tag: u32) -> Vec<&[u8]> {
+    use prost::encoding::{decode_key, decode_varint, skip_field, DecodeContext, WireType};
+
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let Ok((field_tag, wire_type)) = decode_key(&mut buf) else {
+            break;
+        };
+        if wire_type == WireType::LengthDelimited {
+            let Ok(len) = decode_varint(&mut buf) else {
+                break;
+            };
+            let Some((value, rest)) = usize::try_from(len)
+                .ok()
+                .filter(|len| *len <= buf.len())
+                .map(|len| buf.split_at(len))
+            else {
+                break;
+            };
+            if field_tag == tag {
+                fields.push(value);
+            }
+            buf = rest;
+        } else if skip_field(wire_type, field_tag, &mut buf, DecodeContext::default()).is_err() {
+            break;
+        }
+    }
+    fields
+}
+
 /// A Rust module path for a Protobuf package.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Module {
@@ -1077,7 +1270,7 @@ impl fmt::Display for Module {
 /// ```rust,no_run
 /// # use std::io::Result;
 /// fn main() -> Result<()> {
-///   prost_build::compile_protos(&["src/frontend.proto", "src/backend.proto"], &["src"])?;
+///   ntex_prost_build::compile_protos(&["src/frontend.proto", "src/backend.proto"], &["src"])?;
 ///   Ok(())
 /// }
 /// ```
@@ -1105,3 +1298,71 @@ pub fn protoc_include() -> PathBuf {
         None => PathBuf::from(env!("PROTOC_INCLUDE")),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoded_options() {
+        use prost::encoding::{encode_key, encode_varint, WireType};
+
+        fn field(tag: u32, value: &[u8]) -> Vec<u8> {
+            let mut buf = Vec::new();
+            encode_key(tag, WireType::LengthDelimited, &mut buf);
+            encode_varint(value.len() as u64, &mut buf);
+            buf.extend_from_slice(value);
+            buf
+        }
+
+        // deprecated = true, custom string option 50001 = "admin"
+        let mut options = vec![0x88, 0x02, 0x01];
+        options.extend(field(50001, b"admin"));
+
+        let method = [field(1, b"Get"), field(4, &options)].concat();
+        let mut service = [field(1, b"Svc"), field(2, &method)].concat();
+        encode_key(99, WireType::Varint, &mut service);
+        encode_varint(1, &mut service);
+        let file = [field(2, b"pkg"), field(6, &service)].concat();
+        let buf = field(1, &file);
+
+        let options_map = encoded_options(&buf);
+        assert_eq!(options_map[".pkg.Svc.Get"], options);
+        assert!(options_map[".pkg.Svc"].is_empty());
+
+        // malformed input
+        assert!(encoded_options(&buf[..buf.len() - 2]).is_empty());
+    }
+
+    #[test]
+    fn test_file_descriptor_set_const() {
+        let buf: Vec<u8> = (0..=255)
+            .chain(b"package.Service".iter().copied())
+            .collect();
+        let out = file_descriptor_set_const(&buf);
+        assert!(out.contains("pub const FILE_DESCRIPTOR_SET: &[u8]"));
+        assert!(out.lines().all(|line| line.len() <= 100));
+
+        let literal = out
+            .split_once("b\"")
+            .and_then(|(_, lit)| lit.strip_suffix("\";\n"))
+            .unwrap();
+        let mut decoded = Vec::new();
+        let mut bytes = literal.bytes();
+        while let Some(b) = bytes.next() {
+            match b {
+                b'\\' => match bytes.next() {
+                    Some(b'\n') => (),
+                    Some(b'x') => {
+                        let hex = [bytes.next().unwrap(), bytes.next().unwrap()];
+                        let hex = std::str::from_utf8(&hex).unwrap();
+                        decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                    }
+                    b => panic!("unexpected escape: {b:?}"),
+                },
+                b => decoded.push(b),
+            }
+        }
+        assert_eq!(decoded, buf);
+    }
+}
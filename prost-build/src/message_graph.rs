@@ -0,0 +1,64 @@
+use std::collections::{HashMap, HashSet};
+
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FileDescriptorProto};
+
+/// `MessageGraph` builds a graph of messages whose edges correspond to singular
+/// message fields, it is used to detect recursive message types.
+#[derive(Debug, Default)]
+pub struct MessageGraph {
+    edges: HashMap<String, Vec<String>>,
+}
+
+impl MessageGraph {
+    pub fn new<'a>(files: impl Iterator<Item = &'a FileDescriptorProto>) -> MessageGraph {
+        let mut graph = MessageGraph::default();
+        for file in files {
+            let package = if file.package().is_empty() {
+                String::new()
+            } else {
+                format!(".{}", file.package())
+            };
+            for msg in &file.message_type {
+                graph.add_message(&package, msg);
+            }
+        }
+        graph
+    }
+
+    fn add_message(&mut self, package: &str, msg: &DescriptorProto) {
+        let msg_name = format!("{package}.{}", msg.name());
+        let edges = msg
+            .field
+            .iter()
+            .filter(|field| {
+                field.label() != Label::Repeated
+                    && matches!(field.r#type(), Type::Message | Type::Group)
+            })
+            .map(|field| field.type_name().to_string())
+            .collect();
+        self.edges.insert(msg_name.clone(), edges);
+
+        for nested in &msg.nested_type {
+            self.add_message(&msg_name, nested);
+        }
+    }
+
+    /// Returns `true` if the `outer` message is reachable from the `inner` message
+    /// through singular message fields.
+    pub fn is_nested(&self, inner: &str, outer: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![inner];
+        while let Some(name) = stack.pop() {
+            if name == outer {
+                return true;
+            }
+            if visited.insert(name) {
+                if let Some(edges) = self.edges.get(name) {
+                    stack.extend(edges.iter().map(String::as_str));
+                }
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,142 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Msg {
+    pub name: Option<::ntex_grpc::ByteString>,
+    /// Encoded fields unknown to the message definition
+    pub unknown_fields: ::ntex_grpc::Bytes,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Other {}
+
+/// Nested message and enum types in `Other`.
+pub mod other {
+    pub const TAG: ::ntex_grpc::types::Extension<super::Msg, i32> =
+        ::ntex_grpc::types::Extension::new("test.Other.tag", "test.Msg", 100);
+}
+
+pub const SCOPES: ::ntex_grpc::types::Extension<Msg, Vec<::ntex_grpc::ByteString>> =
+    ::ntex_grpc::types::Extension::new("test.scopes", "test.Msg", 101);
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Msg {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.name,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            dst.extend_from_slice(&self.unknown_fields);
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+            let mut unknown_fields = ::ntex_grpc::BytesMut::from(&self.unknown_fields[..]);
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.name, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "name"))?,
+                    _ => ::ntex_grpc::encoding::skip_unknown_field(
+                        wire_type,
+                        tag,
+                        src,
+                        &mut unknown_fields,
+                    )?,
+                }
+            }
+            self.unknown_fields = unknown_fields.freeze();
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.name,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + self.unknown_fields.len()
+        }
+    }
+
+    impl ::std::default::Default for Msg {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: ::core::default::Default::default(),
+                unknown_fields: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::types::Extendable for Msg {
+        const NAME: &'static str = "test.Msg";
+
+        #[inline]
+        fn unknown_fields(&self) -> &::ntex_grpc::Bytes {
+            &self.unknown_fields
+        }
+
+        #[inline]
+        fn unknown_fields_mut(&mut self) -> &mut ::ntex_grpc::Bytes {
+            &mut self.unknown_fields
+        }
+    }
+
+    impl ::ntex_grpc::Message for Other {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {}
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Other";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?;
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0
+        }
+    }
+
+    impl ::std::default::Default for Other {
+        #[inline]
+        fn default() -> Self {
+            Self {}
+        }
+    }
+}
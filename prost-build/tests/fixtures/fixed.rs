@@ -0,0 +1,108 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Msg {
+    pub a: ::ntex_grpc::types::Fixed32,
+    pub b: ::ntex_grpc::types::Fixed64,
+    pub c: ::ntex_grpc::types::SFixed32,
+    pub d: ::ntex_grpc::types::SFixed64,
+}
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Msg {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.a,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.b,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.c,
+                3,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.d,
+                4,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.a, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "a"))?,
+                    2 => ::ntex_grpc::NativeType::deserialize(&mut self.b, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "b"))?,
+                    3 => ::ntex_grpc::NativeType::deserialize(&mut self.c, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "c"))?,
+                    4 => ::ntex_grpc::NativeType::deserialize(&mut self.d, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "d"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.a,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.b,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.c,
+                3,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.d,
+                4,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Msg {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                a: ::core::default::Default::default(),
+                b: ::core::default::Default::default(),
+                c: ::core::default::Default::default(),
+                d: ::core::default::Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Msg {
+    pub result: Vec<::ntex_grpc::types::Group<msg::Result>>,
+    pub header: ::ntex_grpc::types::Group<msg::Header>,
+}
+
+/// Nested message and enum types in `Msg`.
+pub mod msg {
+    #[derive(Clone, PartialEq, Debug)]
+    pub struct Result {
+        pub url: Option<::ntex_grpc::ByteString>,
+    }
+
+    #[derive(Clone, PartialEq, Debug)]
+    pub struct Header {
+        pub id: Option<i32>,
+    }
+}
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Msg {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.result,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.header,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.result, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "result"))?
+                    }
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.header, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "header"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.result,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.header,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Msg {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                result: ::core::default::Default::default(),
+                header: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::Message for msg::Result {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.url,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Result";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.url, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "url"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.url,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for msg::Result {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                url: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::Message for msg::Header {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.id,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Header";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.id, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "id"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.id,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for msg::Header {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                id: ::core::default::Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,293 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Msg {
+    pub id: i32,
+    pub tags: Vec<::ntex_grpc::ByteString>,
+    pub inner: Inner,
+    pub kind: Option<msg::Kind>,
+}
+
+/// Nested message and enum types in `Msg`.
+pub mod msg {
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Kind {
+        First(super::Inner),
+        Name(::ntex_grpc::ByteString),
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Inner {
+    pub a: i32,
+    pub b: i32,
+}
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Msg {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.id,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.tags,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.inner,
+                3,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.kind,
+                0,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.id, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "id"))?,
+                    2 => ::ntex_grpc::NativeType::deserialize(&mut self.tags, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "tags"))?,
+                    3 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.inner, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "inner"))?
+                    }
+                    4 | 5 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.kind, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "kind"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.id,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.tags,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.inner,
+                3,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.kind,
+                0,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Msg {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                id: ::core::default::Default::default(),
+                tags: ::core::default::Default::default(),
+                inner: ::core::default::Default::default(),
+                kind: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::NativeType for msg::Kind {
+        const TYPE: ::ntex_grpc::WireType = ::ntex_grpc::WireType::LengthDelimited;
+
+        fn merge(
+            &mut self,
+            _: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            panic!("Not supported")
+        }
+
+        fn encode_value(&self, _: &mut ::ntex_grpc::BytesMut) {
+            panic!("Not supported")
+        }
+
+        #[inline]
+        /// Encodes the message to a buffer.
+        fn serialize(
+            &self,
+            _: u32,
+            _: ::ntex_grpc::types::DefaultValue<&Self>,
+            dst: &mut ::ntex_grpc::BytesMut,
+        ) {
+            match *self {
+                msg::Kind::First(ref value) => ::ntex_grpc::NativeType::serialize(
+                    value,
+                    4,
+                    ::ntex_grpc::types::DefaultValue::Unknown,
+                    dst,
+                ),
+                msg::Kind::Name(ref value) => ::ntex_grpc::NativeType::serialize(
+                    value,
+                    5,
+                    ::ntex_grpc::types::DefaultValue::Unknown,
+                    dst,
+                ),
+            }
+        }
+
+        #[inline]
+        /// Decodes an instance of the message from a buffer, and merges it into self.
+        fn deserialize(
+            &mut self,
+            tag: u32,
+            wire_type: ::ntex_grpc::WireType,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            match tag {
+                4 => {
+                    if let msg::Kind::First(value) = self {
+                        ::ntex_grpc::NativeType::deserialize(value, 4, wire_type, src)?;
+                    } else {
+                        *self = msg::Kind::First(::ntex_grpc::NativeType::deserialize_default(
+                            4, wire_type, src,
+                        )?);
+                    }
+                }
+                5 => {
+                    if let msg::Kind::Name(value) = self {
+                        ::ntex_grpc::NativeType::deserialize(value, 5, wire_type, src)?;
+                    } else {
+                        *self = msg::Kind::Name(::ntex_grpc::NativeType::deserialize_default(
+                            5, wire_type, src,
+                        )?);
+                    }
+                }
+                _ => unreachable!("invalid Kind, tag: {}", tag),
+            }
+            Ok(())
+        }
+
+        #[inline]
+        /// Returns the encoded length of the message without a length delimiter.
+        fn serialized_len(&self, _: u32, _: ::ntex_grpc::types::DefaultValue<&Self>) -> usize {
+            match *self {
+                msg::Kind::First(ref value) => ::ntex_grpc::NativeType::serialized_len(
+                    value,
+                    4,
+                    ::ntex_grpc::types::DefaultValue::Unknown,
+                ),
+                msg::Kind::Name(ref value) => ::ntex_grpc::NativeType::serialized_len(
+                    value,
+                    5,
+                    ::ntex_grpc::types::DefaultValue::Unknown,
+                ),
+            }
+        }
+    }
+
+    impl ::std::default::Default for msg::Kind {
+        #[inline]
+        fn default() -> Self {
+            msg::Kind::First(::std::default::Default::default())
+        }
+    }
+
+    impl ::ntex_grpc::Message for Inner {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.a,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.b,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Inner";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.a, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "a"))?,
+                    2 => ::ntex_grpc::NativeType::deserialize(&mut self.b, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "b"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.a,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.b,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Inner {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                a: ::core::default::Default::default(),
+                b: ::core::default::Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Msg {
+    pub ids: Vec<i32>,
+    pub packed_ids: Vec<i32>,
+}
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Msg {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::types::serialize_unpacked(&self.ids, 1, dst);
+            ::ntex_grpc::NativeType::serialize(
+                &self.packed_ids,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.ids, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "ids"))?,
+                    2 => ::ntex_grpc::NativeType::deserialize(
+                        &mut self.packed_ids,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "packed_ids"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::types::serialized_len_unpacked(&self.ids, 1)
+                + ::ntex_grpc::NativeType::serialized_len(
+                    &self.packed_ids,
+                    2,
+                    ::ntex_grpc::types::DefaultValue::Default,
+                )
+        }
+    }
+
+    impl ::std::default::Default for Msg {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                ids: ::core::default::Default::default(),
+                packed_ids: ::core::default::Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Msg {
+    pub name: ::ntex_grpc::ByteString,
+    pub count: i32,
+    pub ratio: f32,
+    pub data: ::ntex_grpc::Bytes,
+    pub title: ::ntex_grpc::ByteString,
+    pub id: ::ntex_grpc::types::Fixed32,
+    pub opt: Option<i32>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Partial {
+    pub count: Option<i32>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Outer {
+    pub inner: Msg,
+    pub items: Vec<Msg>,
+}
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Msg {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.name,
+                1,
+                ::ntex_grpc::types::DefaultValue::Unknown,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.count,
+                2,
+                ::ntex_grpc::types::DefaultValue::Value(&-5i32),
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.ratio,
+                3,
+                ::ntex_grpc::types::DefaultValue::Value(&f32::INFINITY),
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.data,
+                4,
+                ::ntex_grpc::types::DefaultValue::Value(&::ntex_grpc::Bytes::from_static(
+                    b"a\x01\"",
+                )),
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.title,
+                5,
+                ::ntex_grpc::types::DefaultValue::Value(&::ntex_grpc::ByteString::from_static(
+                    "say \"hi\"",
+                )),
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.id,
+                6,
+                ::ntex_grpc::types::DefaultValue::Value(&::ntex_grpc::types::Fixed32(7)),
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.opt,
+                7,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge_checked(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.name, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "name"))?,
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.count, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "count"))?
+                    }
+                    3 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.ratio, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "ratio"))?
+                    }
+                    4 => ::ntex_grpc::NativeType::deserialize(&mut self.data, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "data"))?,
+                    5 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.title, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "title"))?
+                    }
+                    6 => ::ntex_grpc::NativeType::deserialize(&mut self.id, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "id"))?,
+                    7 => ::ntex_grpc::NativeType::deserialize(&mut self.opt, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "opt"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn merge_checked(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Msg";
+            let mut required_1 = false;
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.name, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "name"))?;
+                        required_1 = true;
+                    }
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.count, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "count"))?
+                    }
+                    3 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.ratio, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "ratio"))?
+                    }
+                    4 => ::ntex_grpc::NativeType::deserialize(&mut self.data, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "data"))?,
+                    5 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.title, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "title"))?
+                    }
+                    6 => ::ntex_grpc::NativeType::deserialize(&mut self.id, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "id"))?,
+                    7 => ::ntex_grpc::NativeType::deserialize(&mut self.opt, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "opt"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+            if !required_1 {
+                return Err(::ntex_grpc::DecodeError::new("missing required field")
+                    .push(STRUCT_NAME, "name"));
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.name,
+                1,
+                ::ntex_grpc::types::DefaultValue::Unknown,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.count,
+                2,
+                ::ntex_grpc::types::DefaultValue::Value(&-5i32),
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.ratio,
+                3,
+                ::ntex_grpc::types::DefaultValue::Value(&f32::INFINITY),
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.data,
+                4,
+                ::ntex_grpc::types::DefaultValue::Value(&::ntex_grpc::Bytes::from_static(
+                    b"a\x01\"",
+                )),
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.title,
+                5,
+                ::ntex_grpc::types::DefaultValue::Value(&::ntex_grpc::ByteString::from_static(
+                    "say \"hi\"",
+                )),
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.id,
+                6,
+                ::ntex_grpc::types::DefaultValue::Value(&::ntex_grpc::types::Fixed32(7)),
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.opt,
+                7,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Msg {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: ::core::default::Default::default(),
+                count: -5i32,
+                ratio: f32::INFINITY,
+                data: ::ntex_grpc::Bytes::from_static(b"a\x01\""),
+                title: ::ntex_grpc::ByteString::from_static("say \"hi\""),
+                id: ::ntex_grpc::types::Fixed32(7),
+                opt: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::Message for Partial {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.count,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Partial";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.count, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "count"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.count,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Partial {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                count: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::Message for Outer {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.inner,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.items,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge_checked(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Outer";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.inner, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "inner"))?
+                    }
+                    2 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.items, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "items"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn merge_checked(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Outer";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize_checked(
+                        &mut self.inner,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "inner"))?,
+                    2 => ::ntex_grpc::NativeType::deserialize_checked(
+                        &mut self.items,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "items"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.inner,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.items,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Outer {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                inner: ::core::default::Default::default(),
+                items: ::core::default::Default::default(),
+            }
+        }
+    }
+}
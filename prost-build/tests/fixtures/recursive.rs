@@ -0,0 +1,386 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct Node {
+    pub id: i32,
+    pub left: Option<Box<Node>>,
+    pub children: Vec<Node>,
+    pub kind: Option<node::Kind>,
+}
+
+/// Nested message and enum types in `Node`.
+pub mod node {
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Kind {
+        Next(Box<super::Node>),
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct A {
+    pub value: Option<Box<B>>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct B {
+    pub value: Option<Box<A>>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct C {
+    pub value: Node,
+}
+
+mod _priv_impl_test {
+    use super::*;
+
+    impl ::ntex_grpc::Message for Node {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.id,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.left,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.children,
+                3,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+            ::ntex_grpc::NativeType::serialize(
+                &self.kind,
+                0,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "Node";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => ::ntex_grpc::NativeType::deserialize(&mut self.id, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "id"))?,
+                    2 => ::ntex_grpc::NativeType::deserialize(&mut self.left, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "left"))?,
+                    3 => ::ntex_grpc::NativeType::deserialize(
+                        &mut self.children,
+                        tag,
+                        wire_type,
+                        src,
+                    )
+                    .map_err(|err| err.push(STRUCT_NAME, "children"))?,
+                    4 => ::ntex_grpc::NativeType::deserialize(&mut self.kind, tag, wire_type, src)
+                        .map_err(|err| err.push(STRUCT_NAME, "kind"))?,
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.id,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.left,
+                2,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.children,
+                3,
+                ::ntex_grpc::types::DefaultValue::Default,
+            ) + ::ntex_grpc::NativeType::serialized_len(
+                &self.kind,
+                0,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for Node {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                id: ::core::default::Default::default(),
+                left: ::core::default::Default::default(),
+                children: ::core::default::Default::default(),
+                kind: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::NativeType for node::Kind {
+        const TYPE: ::ntex_grpc::WireType = ::ntex_grpc::WireType::LengthDelimited;
+
+        fn merge(
+            &mut self,
+            _: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            panic!("Not supported")
+        }
+
+        fn encode_value(&self, _: &mut ::ntex_grpc::BytesMut) {
+            panic!("Not supported")
+        }
+
+        #[inline]
+        /// Encodes the message to a buffer.
+        fn serialize(
+            &self,
+            _: u32,
+            _: ::ntex_grpc::types::DefaultValue<&Self>,
+            dst: &mut ::ntex_grpc::BytesMut,
+        ) {
+            match *self {
+                node::Kind::Next(ref value) => ::ntex_grpc::NativeType::serialize(
+                    value,
+                    4,
+                    ::ntex_grpc::types::DefaultValue::Unknown,
+                    dst,
+                ),
+            }
+        }
+
+        #[inline]
+        /// Decodes an instance of the message from a buffer, and merges it into self.
+        fn deserialize(
+            &mut self,
+            tag: u32,
+            wire_type: ::ntex_grpc::WireType,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            match tag {
+                4 => {
+                    let node::Kind::Next(value) = self;
+                    ::ntex_grpc::NativeType::deserialize(value, 4, wire_type, src)?;
+                }
+                _ => unreachable!("invalid Kind, tag: {}", tag),
+            }
+            Ok(())
+        }
+
+        #[inline]
+        /// Returns the encoded length of the message without a length delimiter.
+        fn serialized_len(&self, _: u32, _: ::ntex_grpc::types::DefaultValue<&Self>) -> usize {
+            match *self {
+                node::Kind::Next(ref value) => ::ntex_grpc::NativeType::serialized_len(
+                    value,
+                    4,
+                    ::ntex_grpc::types::DefaultValue::Unknown,
+                ),
+            }
+        }
+    }
+
+    impl ::std::default::Default for node::Kind {
+        #[inline]
+        fn default() -> Self {
+            node::Kind::Next(::std::default::Default::default())
+        }
+    }
+
+    impl ::ntex_grpc::Message for A {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.value,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "A";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.value, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "value"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.value,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for A {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                value: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::Message for B {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.value,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "B";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.value, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "value"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.value,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for B {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                value: ::core::default::Default::default(),
+            }
+        }
+    }
+
+    impl ::ntex_grpc::Message for C {
+        #[inline]
+        fn write(&self, dst: &mut ::ntex_grpc::BytesMut) {
+            ::ntex_grpc::NativeType::serialize(
+                &self.value,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+                dst,
+            );
+        }
+
+        #[inline]
+        fn read(
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<Self, ::ntex_grpc::DecodeError> {
+            let mut msg = Self::default();
+            ::ntex_grpc::Message::merge(&mut msg, src)?;
+            Ok(msg)
+        }
+
+        #[inline]
+        fn merge(
+            &mut self,
+            src: &mut ::ntex_grpc::Bytes,
+        ) -> ::std::result::Result<(), ::ntex_grpc::DecodeError> {
+            const STRUCT_NAME: &str = "C";
+
+            while !src.is_empty() {
+                let (tag, wire_type) = ::ntex_grpc::encoding::decode_key(src)?;
+                match tag {
+                    1 => {
+                        ::ntex_grpc::NativeType::deserialize(&mut self.value, tag, wire_type, src)
+                            .map_err(|err| err.push(STRUCT_NAME, "value"))?
+                    }
+                    _ => ::ntex_grpc::encoding::skip_field(wire_type, tag, src)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::ntex_grpc::NativeType::serialized_len(
+                &self.value,
+                1,
+                ::ntex_grpc::types::DefaultValue::Default,
+            )
+        }
+    }
+
+    impl ::std::default::Default for C {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                value: ::core::default::Default::default(),
+            }
+        }
+    }
+}